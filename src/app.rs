@@ -1,16 +1,31 @@
+use std::{collections::HashMap, future::Future, sync::Arc};
+
 use rayon::prelude::*;
-use tokio::join;
+use tokio::{sync::mpsc::UnboundedSender, task::JoinHandle};
 
 use crate::{
-    http::{fetch_external, Http},
+    endpoints::Endpoints,
+    error::{FetchError, FetchResult},
+    event::{AppEvent, FetchedData},
+    fetcher::{Fetcher, ResourceLists},
     models::{
-        ExtendedPokemonInfo, NamedApiResource, Pokemon, PokemonEncounter, PokemonMoveExt,
-        PokemonSpecies,
+        damage_calculator::{DAMAGE_SETTINGS_COUNT, LATEST_GENERATION},
+        pokemon_comparison::MAX_COMPARED_POKEMON,
+        pokemon_form::FormChoice,
+        pokemon_type::ATTACKING_TYPES,
+        stat_spread::STAT_NAMES,
+        team::get_free_team_name,
+        DamageCalculator, DamageMove, DamagePokemon, ExtendedItemInfo, ExtendedLocationInfo,
+        ExtendedMoveInfo, ExtendedPokemonInfo, NamedApiResource, PokemonAbilityExt,
+        PokemonComparison, PokemonTypeExt, SpriteVariant, StatSpread, Team, TeamMember, TeamMove,
+        VersionGroup,
     },
-    models::{ListWrapper, PokemonAbilityExt},
+    source::{FetchPolicy, FetchedResources, PokeApiSource},
+    sprite::Sprite,
     stateful_list::StatefulList,
     switchable_table_state::SwitchableTableState,
-    DEFAULT_LIST_QUERY_PARAMS, POKEAPI_DEFAULT_URL,
+    team_store::TeamStore,
+    utils::PrepareForDisplay,
 };
 
 pub type TestStatefulList = StatefulList<NamedApiResource>;
@@ -20,132 +35,566 @@ pub enum SelectedPart {
     Main,
 }
 
+/// What the list pane shows, Tab on the list switches between them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ListMode {
+    Pokemon,
+    Types,
+    Items,
+    Locations,
+    Teams,
+}
+
 pub enum CurrentMainPageState {
     BasicInfo,
     VersionGroupSelection,
+    StatCalculator,
+    Comparison,
+    DamageCalculator,
+}
+
+/// The column of the stat calculator that "+" and "-" change.
+pub enum StatCalculatorFocus {
+    Ivs,
+    Evs,
+}
+
+/// The table on the BasicInfo page that Up, Down and Enter act on.
+pub enum BasicInfoFocus {
+    Moves,
+    EvolutionChain,
+    Abilities,
+    HeldItems,
+    Encounters,
+    Forms,
+}
+
+/// The table on the Type page that Up, Down and Enter act on.
+pub enum TypeFocus {
+    Pokemon,
+    Moves,
+}
+
+/// The table on the Location page that Up, Down and Enter act on.
+pub enum LocationFocus {
+    Areas,
+    Encounters,
+}
+
+/// What to run again when the user asks to retry a failed fetch.
+pub enum RetryAction {
+    Init,
+    PokemonSelection(NamedApiResource),
+    FormSelection(NamedApiResource),
+    Comparison(Vec<NamedApiResource>),
+    DamageCalculator,
+    TeamTypes,
+    MovesAndAbilities,
+    TypeSelection(NamedApiResource),
+    MoveSelection(NamedApiResource),
+    AbilitySelection(NamedApiResource),
+    ItemSelection(NamedApiResource),
+    RegionSelection(NamedApiResource),
+    LocationSelection(NamedApiResource),
+    LocationAreaSelection(NamedApiResource),
+}
+
+pub struct FailedFetch {
+    pub error: FetchError,
+    pub retry: RetryAction,
+}
+
+/// The one background fetch whose result the app is still waiting for. Anything that
+/// arrives with another `request_id` was superseded or cancelled and is dropped.
+struct PendingFetch {
+    request_id: u64,
+    handle: JoinHandle<()>,
 }
 
 pub struct App {
-    http: Http,
+    fetcher: Fetcher,
+    events: UnboundedSender<AppEvent>,
+    pending_fetch: Option<PendingFetch>,
+    /// Sprites load next to everything else, so they have a slot of their own.
+    pending_sprite_fetch: Option<PendingFetch>,
+    /// So do the startup lists, which nothing else may cancel, not even Esc.
+    pending_lists_fetch: Option<PendingFetch>,
+    /// And the types of the team coverage, which shouldn't cancel a Pokemon loading meanwhile.
+    pending_team_types_fetch: Option<PendingFetch>,
+    last_request_id: u64,
     pub search: String,
     pub loading: bool,
+    pub list_mode: ListMode,
     pub pokemon_list: TestStatefulList,
+    pub type_list: TestStatefulList,
+    pub item_list: TestStatefulList,
+    /// The locations of `current_region`, empty until one is opened.
+    pub location_list: TestStatefulList,
+    pub region_list: TestStatefulList,
     pub selected_part: SelectedPart,
     pub version_groups: TestStatefulList,
+    /// Versions of every version group by its name, fetched once on startup.
+    pub version_group_details: HashMap<String, VersionGroup>,
     pub current_pokemon: Option<ExtendedPokemonInfo>,
-    pub selected_version_group: Option<NamedApiResource>,
+    pub selected_version_group: Option<VersionGroup>,
     pub rendered_moves_count: Option<usize>,
     pub current_main_page_state: CurrentMainPageState,
     pub pokemon_moves_list_state: SwitchableTableState,
+    pub basic_info_focus: BasicInfoFocus,
+    pub evolution_chain_state: SwitchableTableState,
+    pub pokemon_abilities_state: SwitchableTableState,
+    pub pokemon_held_items_state: SwitchableTableState,
+    pub pokemon_encounters_state: SwitchableTableState,
+    pub pokemon_forms_state: SwitchableTableState,
+    pub stat_spread: StatSpread,
+    pub stat_calculator_focus: StatCalculatorFocus,
+    pub stat_calculator_state: SwitchableTableState,
+    /// Kept while browsing other Pokemon, so Pokemon can be marked one page after another.
+    pub marked_pokemon: Vec<NamedApiResource>,
+    pub comparison: Option<PokemonComparison>,
+    pub comparison_moves_state: SwitchableTableState,
+    /// The highlighted move against the first other marked Pokemon, the page's Pokemon attacking.
+    pub damage_calculator: Option<DamageCalculator>,
+    pub damage_settings_state: SwitchableTableState,
+    /// Saving is turned off when the teams file couldn't be read, so it isn't overwritten.
+    team_store: Option<TeamStore>,
+    pub teams: Vec<Team>,
+    pub team_list: TestStatefulList,
+    /// Index into `teams` of the team being built, Pokemon pages add to and edit it.
+    pub current_team: Option<usize>,
+    pub team_members_state: SwitchableTableState,
+    /// Every type, fetched the first time a team is opened.
+    pub team_types: Vec<PokemonTypeExt>,
+    /// Why the last team change didn't go through, or why teams can't be saved.
+    pub team_status: Option<String>,
+    pub sprite: Option<Sprite>,
+    pub sprite_variant: SpriteVariant,
+    pub current_type: Option<PokemonTypeExt>,
+    pub type_focus: TypeFocus,
+    pub type_pokemon_state: SwitchableTableState,
+    pub type_moves_state: SwitchableTableState,
+    pub current_item: Option<ExtendedItemInfo>,
+    pub item_holders_state: SwitchableTableState,
+    /// The region the location list is in, without one the list shows the regions.
+    pub current_region: Option<NamedApiResource>,
+    pub current_location: Option<ExtendedLocationInfo>,
+    pub location_focus: LocationFocus,
+    pub location_areas_state: SwitchableTableState,
+    pub location_encounters_state: SwitchableTableState,
+    /// Move and ability pages cover the Pokemon or Type page until they're closed.
+    pub current_move: Option<ExtendedMoveInfo>,
+    pub move_learners_state: SwitchableTableState,
+    pub current_ability: Option<PokemonAbilityExt>,
+    pub ability_pokemon_state: SwitchableTableState,
+    pub failed_fetch: Option<FailedFetch>,
 }
 
 impl App {
-    pub fn new() -> Self {
-        Self {
-            http: Http::new(),
+    pub fn new(
+        source: Arc<dyn PokeApiSource>,
+        endpoints: Endpoints,
+        fetch_policy: FetchPolicy,
+        events: UnboundedSender<AppEvent>,
+        team_store: Option<TeamStore>,
+    ) -> Self {
+        let (team_store, teams, team_status) = match team_store.as_ref().map(TeamStore::load) {
+            Some(Ok(teams)) => (team_store, teams, None),
+            Some(Err(err)) => (None, vec![], Some(format!("Teams won't be saved: {}", err))),
+            None => (None, vec![], None),
+        };
+
+        let mut app = Self {
+            fetcher: Fetcher::new(source, endpoints, fetch_policy),
+            events,
+            pending_fetch: None,
+            pending_sprite_fetch: None,
+            pending_lists_fetch: None,
+            pending_team_types_fetch: None,
+            last_request_id: 0,
             search: String::new(),
             loading: false,
+            list_mode: ListMode::Pokemon,
             pokemon_list: StatefulList::with_items(vec![]),
+            type_list: StatefulList::with_items(vec![]),
+            item_list: StatefulList::with_items(vec![]),
+            location_list: StatefulList::with_items(vec![]),
+            region_list: StatefulList::with_items(vec![]),
             selected_part: SelectedPart::List,
             version_groups: StatefulList::with_items(vec![]),
+            version_group_details: HashMap::new(),
             current_pokemon: None,
             selected_version_group: None,
             rendered_moves_count: None,
             current_main_page_state: CurrentMainPageState::VersionGroupSelection,
             pokemon_moves_list_state: SwitchableTableState::new(),
+            basic_info_focus: BasicInfoFocus::Moves,
+            evolution_chain_state: SwitchableTableState::new(),
+            pokemon_abilities_state: SwitchableTableState::new(),
+            pokemon_held_items_state: SwitchableTableState::new(),
+            pokemon_encounters_state: SwitchableTableState::new(),
+            pokemon_forms_state: SwitchableTableState::new(),
+            stat_spread: StatSpread::default(),
+            stat_calculator_focus: StatCalculatorFocus::Ivs,
+            stat_calculator_state: SwitchableTableState::new(),
+            marked_pokemon: vec![],
+            comparison: None,
+            comparison_moves_state: SwitchableTableState::new(),
+            damage_calculator: None,
+            damage_settings_state: SwitchableTableState::new(),
+            team_store,
+            teams,
+            team_list: StatefulList::with_items(vec![]),
+            current_team: None,
+            team_members_state: SwitchableTableState::new(),
+            team_types: vec![],
+            team_status,
+            sprite: None,
+            sprite_variant: SpriteVariant::FrontDefault,
+            current_type: None,
+            type_focus: TypeFocus::Pokemon,
+            type_pokemon_state: SwitchableTableState::new(),
+            type_moves_state: SwitchableTableState::new(),
+            current_item: None,
+            item_holders_state: SwitchableTableState::new(),
+            current_region: None,
+            current_location: None,
+            location_focus: LocationFocus::Areas,
+            location_areas_state: SwitchableTableState::new(),
+            location_encounters_state: SwitchableTableState::new(),
+            current_move: None,
+            move_learners_state: SwitchableTableState::new(),
+            current_ability: None,
+            ability_pokemon_state: SwitchableTableState::new(),
+            failed_fetch: None,
+        };
+        app.set_team_list();
+
+        app
+    }
+
+    pub fn init(&mut self) {
+        let fetcher = self.fetcher.clone();
+        if let Some(pending_lists_fetch) = self.pending_lists_fetch.take() {
+            pending_lists_fetch.handle.abort();
         }
+        self.pending_lists_fetch =
+            Some(self.spawn_tracked_fetch(async move {
+                FetchedData::Lists(fetcher.fetch_lists().await)
+            }));
     }
 
-    pub async fn init(&mut self) {
-        let (pokemon_list, version_groups) =
-            join!(self.fetch_list("pokemon"), self.fetch_list("version-group"));
-        self.set_pokemon_list_and_version_groups(pokemon_list, version_groups);
-    }
-
-    pub async fn fetch_list(&self, list_name: &str) -> Vec<NamedApiResource> {
-        let uri = format!(
-            "{}{}{}",
-            POKEAPI_DEFAULT_URL, list_name, DEFAULT_LIST_QUERY_PARAMS
-        );
-        let results: Option<ListWrapper> = self.http.get_as_object(&uri).await;
-
-        results
-            .and_then(|list_wrapper| list_wrapper.results)
-            .unwrap_or(vec![])
-    }
-
-    pub async fn fetch_abilities_and_moves(&mut self) {
-        if let Some(current_pokemon) = self.current_pokemon.as_mut() {
-            let empty_abilities = vec![];
-            let empty_moves = vec![];
-            let fetch_url = |api_resource: &NamedApiResource| {
-                api_resource
-                    .url
-                    .as_ref()
-                    .unwrap_or(&"".to_string())
-                    .to_string()
-            };
-
-            let (abilities, moves) = (
-                current_pokemon
-                    .pokemon
-                    .abilities
-                    .as_ref()
-                    .unwrap_or(&empty_abilities),
-                current_pokemon
-                    .pokemon
-                    .moves
-                    .as_ref()
-                    .unwrap_or(&empty_moves),
-            );
+    /// Runs `fetch` on the runtime and posts its result back through the event channel,
+    /// replacing whatever fetch was pending before.
+    fn spawn_fetch<Fut>(&mut self, fetch: Fut)
+    where
+        Fut: Future<Output = FetchedData> + Send + 'static,
+    {
+        self.cancel_pending_fetch();
+        self.pending_fetch = Some(self.spawn_tracked_fetch(fetch));
+        self.loading = true;
+    }
 
-            let (abilities, moves): (Vec<PokemonAbilityExt>, Vec<PokemonMoveExt>) = join!(
-                fetch_external(abilities.as_slice(), |ability| {
-                    // TODO: replace unwrap with something better
-                    fetch_url(ability.ability.as_ref().unwrap())
-                }),
-                fetch_external(moves.as_slice(), |mv| {
-                    // TODO: replace unwrap with something better
-                    fetch_url(mv.de_move.as_ref().unwrap())
-                }),
-            );
+    fn spawn_sprite_fetch<Fut>(&mut self, fetch: Fut)
+    where
+        Fut: Future<Output = FetchedData> + Send + 'static,
+    {
+        self.cancel_pending_sprite_fetch();
+        self.pending_sprite_fetch = Some(self.spawn_tracked_fetch(fetch));
+    }
+
+    fn spawn_tracked_fetch<Fut>(&mut self, fetch: Fut) -> PendingFetch
+    where
+        Fut: Future<Output = FetchedData> + Send + 'static,
+    {
+        self.last_request_id += 1;
+
+        let request_id = self.last_request_id;
+        let events = self.events.clone();
+        let handle = tokio::spawn(async move {
+            let data = fetch.await;
+            // The receiver only disappears when the app is shutting down
+            let _ = events.send(AppEvent::Fetched { request_id, data });
+        });
+
+        PendingFetch { request_id, handle }
+    }
 
-            current_pokemon.abilities = abilities;
-            current_pokemon.moves = moves;
+    pub fn cancel_pending_fetch(&mut self) {
+        if let Some(pending_fetch) = self.pending_fetch.take() {
+            pending_fetch.handle.abort();
         }
+
+        self.loading = false;
     }
 
-    pub async fn fetch_pokemon_with_info(&mut self, pokemon: &NamedApiResource) {
-        let uri = pokemon.url.as_ref().unwrap_or(&"".to_string()).to_string();
-        if uri.is_empty() {
-            return;
+    fn cancel_pending_sprite_fetch(&mut self) {
+        if let Some(pending_sprite_fetch) = self.pending_sprite_fetch.take() {
+            pending_sprite_fetch.handle.abort();
         }
+    }
 
-        let pokemon: Option<Pokemon> = self.http.get_as_object(&uri).await;
+    pub fn is_sprite_loading(&self) -> bool {
+        self.pending_sprite_fetch.is_some()
+    }
 
-        if let Some(pokemon) = pokemon {
-            let species_url = pokemon
-                .species
-                .as_ref()
-                .and_then(|species| species.url.as_ref());
-            let encounters_url = format!(
-                "{}pokemon/{}/encounters",
-                POKEAPI_DEFAULT_URL,
-                pokemon.id.unwrap()
-            );
+    pub fn is_lists_loading(&self) -> bool {
+        self.pending_lists_fetch.is_some()
+    }
 
-            let (species, encounters): (Option<PokemonSpecies>, Option<Vec<PokemonEncounter>>) = join!(
-                self.http.get_as_object(&species_url.unwrap()),
-                self.http.get_as_object(&encounters_url)
-            );
+    pub fn on_fetched(&mut self, request_id: u64, data: FetchedData) {
+        let data = match data {
+            FetchedData::Sprite(result) => return self.on_sprite_fetched(request_id, result),
+            FetchedData::Lists(result) => return self.on_lists_fetched(request_id, result),
+            FetchedData::TeamTypes(result) => {
+                return self.on_team_types_fetched(request_id, result)
+            }
+            data => data,
+        };
+
+        match self.pending_fetch.as_ref() {
+            Some(pending_fetch) if pending_fetch.request_id == request_id => {}
+            _ => return,
+        }
+
+        self.pending_fetch = None;
+        self.loading = false;
 
-            self.current_pokemon = Some(ExtendedPokemonInfo {
+        match data {
+            FetchedData::Pokemon {
+                result: Ok(pokemon),
+                ..
+            } => {
+                self.current_pokemon = Some(*pokemon);
+                self.selected_part = SelectedPart::Main;
+                self.fetch_sprite();
+
+                // Only set when jumping along an evolution chain, there's no need to ask again
+                if self.selected_version_group.is_some() {
+                    self.current_main_page_state = CurrentMainPageState::BasicInfo;
+                    self.on_moves_and_abilities_open();
+                }
+            }
+            FetchedData::Pokemon {
+                pokemon,
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::PokemonSelection(pokemon)),
+            FetchedData::PokemonForm {
+                result: Ok(form), ..
+            } => {
+                if let Some(current_pokemon) = self.current_pokemon.as_mut() {
+                    current_pokemon.form = Some(*form);
+                    self.sprite_variant = SpriteVariant::FrontDefault;
+                    self.fetch_sprite();
+                }
+            }
+            FetchedData::PokemonForm {
+                form,
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::FormSelection(form)),
+            FetchedData::Comparison {
+                result: Ok(comparison),
+                ..
+            } => {
+                self.comparison = Some(*comparison);
+                self.comparison_moves_state = SwitchableTableState::new();
+                self.current_main_page_state = CurrentMainPageState::Comparison;
+            }
+            FetchedData::Comparison {
                 pokemon,
-                abilities: vec![],
-                moves: vec![],
-                species: species.unwrap(),
-                encounters: encounters.unwrap(),
-            });
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::Comparison(pokemon)),
+            FetchedData::DamageCalculator(Ok(damage_calculator)) => {
+                self.damage_calculator = Some(*damage_calculator);
+                self.damage_settings_state = SwitchableTableState::new();
+                self.damage_settings_state.next(Some(DAMAGE_SETTINGS_COUNT));
+                self.current_main_page_state = CurrentMainPageState::DamageCalculator;
+            }
+            FetchedData::DamageCalculator(Err(error)) => {
+                self.on_fetch_failed(error, RetryAction::DamageCalculator)
+            }
+            FetchedData::MovesAndAbilities(abilities, moves) => {
+                if let Some(current_pokemon) = self.current_pokemon.as_mut() {
+                    current_pokemon.set_abilities(abilities.resources);
+                    current_pokemon.set_moves(moves.resources);
+                }
+
+                let failures: Vec<FetchError> = abilities
+                    .failures
+                    .into_iter()
+                    .chain(moves.failures)
+                    .collect();
+                if !failures.is_empty() {
+                    self.on_fetch_failed(
+                        FetchError::Incomplete { failures },
+                        RetryAction::MovesAndAbilities,
+                    );
+                }
+            }
+            FetchedData::Sprite(_) | FetchedData::Lists(_) | FetchedData::TeamTypes(_) => {}
+            FetchedData::Type {
+                result: Ok(pokemon_type),
+                ..
+            } => {
+                self.current_type = Some(*pokemon_type);
+                self.selected_part = SelectedPart::Main;
+            }
+            FetchedData::Type {
+                pokemon_type,
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::TypeSelection(pokemon_type)),
+            FetchedData::Move {
+                result: Ok(pokemon_move),
+                ..
+            } => {
+                self.current_move = Some(*pokemon_move);
+                self.selected_part = SelectedPart::Main;
+            }
+            FetchedData::Move {
+                pokemon_move,
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::MoveSelection(pokemon_move)),
+            FetchedData::Ability {
+                result: Ok(ability),
+                ..
+            } => {
+                self.current_ability = Some(*ability);
+                self.selected_part = SelectedPart::Main;
+            }
+            FetchedData::Ability {
+                ability,
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::AbilitySelection(ability)),
+            FetchedData::Item {
+                result: Ok(item), ..
+            } => {
+                self.current_item = Some(*item);
+                self.selected_part = SelectedPart::Main;
+            }
+            FetchedData::Item {
+                item,
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::ItemSelection(item)),
+            FetchedData::RegionLocations {
+                region,
+                result: Ok(locations),
+            } => self.set_location_list(region, locations),
+            FetchedData::RegionLocations {
+                region,
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::RegionSelection(region)),
+            FetchedData::Location {
+                result: Ok(location),
+                ..
+            }
+            | FetchedData::LocationArea {
+                result: Ok(location),
+                ..
+            } => {
+                self.current_location = Some(*location);
+                self.selected_part = SelectedPart::Main;
+            }
+            FetchedData::Location {
+                location,
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::LocationSelection(location)),
+            FetchedData::LocationArea {
+                location_area,
+                result: Err(error),
+            } => self.on_fetch_failed(error, RetryAction::LocationAreaSelection(location_area)),
+        }
+    }
+
+    fn on_lists_fetched(&mut self, request_id: u64, result: FetchResult<ResourceLists>) {
+        match self.pending_lists_fetch.as_ref() {
+            Some(pending_lists_fetch) if pending_lists_fetch.request_id == request_id => {}
+            _ => return,
+        }
+
+        self.pending_lists_fetch = None;
+        match result {
+            Ok(lists) => {
+                self.set_pokemon_list_and_version_groups(lists.pokemon, lists.version_groups);
+                self.set_version_group_details(lists.version_group_details);
+                self.set_type_list(lists.types);
+                self.set_item_list(lists.items);
+                self.set_region_list(lists.regions);
+            }
+            Err(error) => self.on_fetch_failed(error, RetryAction::Init),
+        }
+    }
+
+    fn on_team_types_fetched(
+        &mut self,
+        request_id: u64,
+        result: FetchResult<FetchedResources<PokemonTypeExt>>,
+    ) {
+        match self.pending_team_types_fetch.as_ref() {
+            Some(pending_team_types_fetch) if pending_team_types_fetch.request_id == request_id => {
+            }
+            _ => return,
+        }
+
+        self.pending_team_types_fetch = None;
+        match result {
+            Ok(types) => {
+                self.team_types = types.resources;
+                if !types.failures.is_empty() {
+                    self.on_fetch_failed(
+                        FetchError::Incomplete {
+                            failures: types.failures,
+                        },
+                        RetryAction::TeamTypes,
+                    );
+                }
+            }
+            Err(error) => self.on_fetch_failed(error, RetryAction::TeamTypes),
+        }
+    }
+
+    /// A missing or broken sprite isn't worth a popup, the panel just stays empty.
+    fn on_sprite_fetched(&mut self, request_id: u64, result: FetchResult<Sprite>) {
+        match self.pending_sprite_fetch.as_ref() {
+            Some(pending_sprite_fetch) if pending_sprite_fetch.request_id == request_id => {}
+            _ => return,
+        }
+
+        self.pending_sprite_fetch = None;
+        self.sprite = result.ok();
+    }
+
+    fn fetch_sprite(&mut self) {
+        self.sprite = None;
+
+        let sprite_url = self.current_pokemon.as_ref().and_then(|current_pokemon| {
+            current_pokemon
+                .get_sprites()?
+                .get_url(self.sprite_variant)
+                .cloned()
+        });
+
+        match sprite_url {
+            Some(sprite_url) => {
+                let fetcher = self.fetcher.clone();
+                self.spawn_sprite_fetch(async move {
+                    FetchedData::Sprite(fetcher.fetch_sprite(&sprite_url).await)
+                });
+            }
+            None => self.cancel_pending_sprite_fetch(),
+        }
+    }
+
+    pub fn on_sprite_variant_switch(&mut self) {
+        let next_variant = self
+            .current_pokemon
+            .as_ref()
+            .and_then(|current_pokemon| current_pokemon.get_sprites())
+            .map(|sprites| sprites.get_next_variant(self.sprite_variant));
+
+        if let Some(next_variant) = next_variant {
+            if next_variant != self.sprite_variant {
+                self.sprite_variant = next_variant;
+                self.fetch_sprite();
+            }
         }
     }
 
@@ -159,82 +608,2729 @@ impl App {
         self.version_groups.items_to_render.extend(version_groups);
     }
 
-    pub fn reset_current_pokemon(&mut self) {
-        self.current_pokemon = None;
-        self.current_main_page_state = CurrentMainPageState::VersionGroupSelection;
+    pub fn set_version_group_details(&mut self, version_group_details: Vec<VersionGroup>) {
+        self.version_group_details.extend(
+            version_group_details
+                .into_iter()
+                .map(|version_group| (version_group.get_name_or_stub(), version_group)),
+        );
+    }
+
+    /// PokéAPI also lists types no Pokemon or move has, like `unknown` and `shadow`.
+    pub fn set_type_list(&mut self, types: Vec<NamedApiResource>) {
+        let types: Vec<NamedApiResource> = types
+            .into_iter()
+            .filter(|pokemon_type| {
+                ATTACKING_TYPES.contains(&pokemon_type.get_name_or_stub().as_str())
+            })
+            .collect();
+
+        self.type_list.items.extend(types.clone());
+        self.type_list.items_to_render.extend(types);
+    }
+
+    pub fn set_item_list(&mut self, items: Vec<NamedApiResource>) {
+        self.item_list.items.extend(items.clone());
+        self.item_list.items_to_render.extend(items);
+    }
+
+    pub fn set_region_list(&mut self, regions: Vec<NamedApiResource>) {
+        self.region_list.items.extend(regions.clone());
+        self.region_list.items_to_render.extend(regions);
+    }
+
+    /// Swaps the regions on the list for the locations of one of them.
+    pub fn set_location_list(
+        &mut self,
+        region: NamedApiResource,
+        locations: Vec<NamedApiResource>,
+    ) {
+        self.search.clear();
+        self.filter_list();
+        self.current_region = Some(region);
+        self.location_list = StatefulList::with_items(locations);
+    }
+
+    /// Goes back from the locations of a region to the regions.
+    pub fn leave_region(&mut self) {
+        self.search.clear();
+        self.current_region = None;
+        self.location_list = StatefulList::with_items(vec![]);
+        self.filter_list();
+    }
+
+    pub fn get_active_list(&self) -> &TestStatefulList {
+        match self.list_mode {
+            ListMode::Pokemon => &self.pokemon_list,
+            ListMode::Types => &self.type_list,
+            ListMode::Items => &self.item_list,
+            ListMode::Locations if self.current_region.is_none() => &self.region_list,
+            ListMode::Locations => &self.location_list,
+            ListMode::Teams => &self.team_list,
+        }
+    }
+
+    pub fn get_active_list_mut(&mut self) -> &mut TestStatefulList {
+        match self.list_mode {
+            ListMode::Pokemon => &mut self.pokemon_list,
+            ListMode::Types => &mut self.type_list,
+            ListMode::Items => &mut self.item_list,
+            ListMode::Locations if self.current_region.is_none() => &mut self.region_list,
+            ListMode::Locations => &mut self.location_list,
+            ListMode::Teams => &mut self.team_list,
+        }
+    }
+
+    /// Whether the main pane has something to show for the current list.
+    pub fn has_current_item(&self) -> bool {
+        if self.has_detail_page() {
+            return true;
+        }
+
+        match self.list_mode {
+            ListMode::Pokemon => self.current_pokemon.is_some(),
+            ListMode::Types => self.current_type.is_some(),
+            ListMode::Items => self.current_item.is_some(),
+            ListMode::Locations => self.current_location.is_some(),
+            // Without a team the page is where a new one is made
+            ListMode::Teams => true,
+        }
+    }
+
+    /// The search only ever filters the list on screen, so it starts over on every switch.
+    pub fn on_list_mode_switch(&mut self) {
+        self.set_list_mode(match self.list_mode {
+            ListMode::Pokemon => ListMode::Types,
+            ListMode::Types => ListMode::Items,
+            ListMode::Items => ListMode::Locations,
+            ListMode::Locations => ListMode::Teams,
+            ListMode::Teams => ListMode::Pokemon,
+        });
+    }
+
+    fn set_list_mode(&mut self, list_mode: ListMode) {
+        if self.list_mode == list_mode {
+            return;
+        }
+
+        self.search.clear();
+        self.filter_list();
+        self.list_mode = list_mode;
+        self.filter_list();
+    }
+
+    pub fn on_list_item_selected(&mut self) {
+        match self.list_mode {
+            ListMode::Pokemon => self.on_pokemon_selected(),
+            ListMode::Types => self.on_type_selected(),
+            ListMode::Items => self.on_item_selected(),
+            ListMode::Locations if self.current_region.is_none() => self.on_region_selected(),
+            ListMode::Locations => self.on_location_selected(),
+            ListMode::Teams => self.on_team_selected(),
+        }
+    }
+
+    pub fn on_item_selected(&mut self) {
+        let item = self.item_list.get_selected().cloned();
+        if let Some(item) = item {
+            self.select_item(item);
+        }
+    }
+
+    fn select_item(&mut self, item: NamedApiResource) {
+        self.reset_current_item();
+
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher.fetch_item(&item).await.map(Box::new);
+
+            FetchedData::Item { item, result }
+        });
+    }
+
+    pub fn reset_current_item(&mut self) {
+        self.close_detail_pages();
+        self.current_item = None;
         self.selected_part = SelectedPart::List;
-        self.selected_version_group = None;
+        self.item_holders_state = SwitchableTableState::new();
     }
 
-    pub fn filter_list(&mut self) {
-        self.pokemon_list.items_to_render = self
-            .pokemon_list
-            .items
-            .par_iter()
-            .filter_map(|pokemon| {
-                let should_be_included = pokemon
-                    .get_name_or_stub()
-                    .to_lowercase()
-                    .contains(&self.search.to_lowercase());
+    pub fn get_item_holders_count(&self) -> Option<usize> {
+        self.current_item
+            .as_ref()
+            .map(|current_item| current_item.item.get_holders_count())
+    }
 
-                if should_be_included {
-                    return Some(pokemon.clone());
-                }
+    /// Opens the highlighted Pokemon that may hold the item on the Pokemon page.
+    pub fn on_item_holder_selected(&mut self) {
+        let pokemon = self
+            .current_item
+            .as_ref()
+            .and_then(|current_item| {
+                current_item
+                    .item
+                    .get_holder(self.item_holders_state.0.selected()?)
+            })
+            .cloned();
 
-                None
+        if let Some(pokemon) = pokemon {
+            self.set_list_mode(ListMode::Pokemon);
+            self.select_pokemon(pokemon);
+        }
+    }
+
+    pub fn get_pokemon_held_items_count(&self) -> Option<usize> {
+        let selected_version_group = self.selected_version_group.as_ref()?;
+
+        self.current_pokemon.as_ref().map(|current_pokemon| {
+            current_pokemon
+                .pokemon
+                .get_renderable_held_items(selected_version_group)
+                .len()
+        })
+    }
+
+    /// Opens the highlighted row of the Pokemon's held items table on the item page.
+    pub fn on_pokemon_held_item_selected(&mut self) {
+        let item = self
+            .current_pokemon
+            .as_ref()
+            .zip(self.selected_version_group.as_ref())
+            .and_then(|(current_pokemon, selected_version_group)| {
+                current_pokemon.pokemon.get_held_item(
+                    selected_version_group,
+                    self.pokemon_held_items_state.0.selected()?,
+                )
             })
-            .collect();
+            .cloned();
 
-        self.pokemon_list.state.select(Some(0));
+        if let Some(item) = item {
+            self.set_list_mode(ListMode::Items);
+            self.select_item(item);
+        }
     }
 
-    pub async fn on_pokemon_selected<F>(&mut self, redraw: F)
-    where
-        F: FnOnce(&mut Self),
-    {
-        let pokemon = self.pokemon_list.get_selected().cloned();
+    pub fn on_region_selected(&mut self) {
+        let region = self.region_list.get_selected().cloned();
+        if let Some(region) = region {
+            self.select_region(region);
+        }
+    }
+
+    fn select_region(&mut self, region: NamedApiResource) {
+        self.reset_current_location();
+
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher.fetch_region_locations(&region).await;
+
+            FetchedData::RegionLocations { region, result }
+        });
+    }
+
+    pub fn on_location_selected(&mut self) {
+        let location = self.location_list.get_selected().cloned();
+        if let Some(location) = location {
+            self.select_location(location);
+        }
+    }
+
+    fn select_location(&mut self, location: NamedApiResource) {
+        self.reset_current_location();
+
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher.fetch_location(&location).await.map(Box::new);
+
+            FetchedData::Location { location, result }
+        });
+    }
+
+    /// Keeps the location on screen while another of its areas loads.
+    fn select_location_area(&mut self, location_area: NamedApiResource) {
+        self.close_detail_pages();
+        self.location_encounters_state = SwitchableTableState::new();
+
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher
+                .fetch_location_area(&location_area)
+                .await
+                .map(Box::new);
+
+            FetchedData::LocationArea {
+                location_area,
+                result,
+            }
+        });
+    }
+
+    pub fn reset_current_location(&mut self) {
+        self.close_detail_pages();
+        self.current_location = None;
+        self.selected_part = SelectedPart::List;
+        self.location_focus = LocationFocus::Areas;
+        self.location_areas_state = SwitchableTableState::new();
+        self.location_encounters_state = SwitchableTableState::new();
+    }
+
+    pub fn on_location_focus_switch(&mut self) {
+        self.location_focus = match self.location_focus {
+            LocationFocus::Areas => LocationFocus::Encounters,
+            LocationFocus::Encounters => LocationFocus::Areas,
+        };
+    }
+
+    pub fn get_location_areas_count(&self) -> Option<usize> {
+        self.current_location
+            .as_ref()
+            .map(|current_location| current_location.get_areas_count())
+    }
+
+    pub fn get_location_encounters_count(&self) -> Option<usize> {
+        self.current_location
+            .as_ref()
+            .map(|current_location| current_location.get_encounters_count())
+    }
+
+    /// Shows the encounters of the highlighted area of the location.
+    pub fn on_location_area_selected(&mut self) {
+        let location_area = self
+            .current_location
+            .as_ref()
+            .and_then(|current_location| {
+                current_location.get_area(self.location_areas_state.0.selected()?)
+            })
+            .cloned();
+
+        if let Some(location_area) = location_area {
+            self.select_location_area(location_area);
+        }
+    }
+
+    /// Opens the highlighted Pokemon that can be found in the area on the Pokemon page.
+    pub fn on_location_encounter_selected(&mut self) {
+        let pokemon = self
+            .current_location
+            .as_ref()
+            .and_then(|current_location| {
+                current_location.get_encounter_pokemon(self.location_encounters_state.0.selected()?)
+            })
+            .cloned();
+
         if let Some(pokemon) = pokemon {
-            self.reset_current_pokemon();
-            self.loading = true;
-            redraw(self);
-            self.fetch_pokemon_with_info(&pokemon).await;
-            self.loading = false;
-            self.selected_part = SelectedPart::Main;
+            self.set_list_mode(ListMode::Pokemon);
+            self.select_pokemon(pokemon);
         }
     }
 
-    pub fn on_version_group_selected(&mut self) {
-        self.selected_version_group = self
-            .version_groups
-            .get_selected()
-            .and_then(|t| Some(t.clone()));
+    pub fn get_pokemon_encounters_count(&self) -> Option<usize> {
+        let selected_version_group = self.selected_version_group.as_ref()?;
 
-        self.current_main_page_state = CurrentMainPageState::BasicInfo;
+        self.current_pokemon.as_ref().map(|current_pokemon| {
+            current_pokemon
+                .get_renderable_encounters(selected_version_group)
+                .len()
+        })
     }
 
-    pub async fn on_moves_and_abilities_open<F>(&mut self, redraw: F)
-    where
-        F: FnOnce(&mut Self),
-    {
-        self.pokemon_moves_list_state = SwitchableTableState::new();
-        if let Some(current_pokemon) = self.current_pokemon.as_ref() {
-            if current_pokemon.abilities.is_empty() && current_pokemon.moves.is_empty() {
-                self.loading = true;
-                self.rendered_moves_count = None;
-                redraw(self);
-                self.fetch_abilities_and_moves().await;
-                self.loading = false;
+    /// Opens the area of the highlighted row of the Pokemon's encounters table on the location page.
+    pub fn on_pokemon_encounter_selected(&mut self) {
+        let location_area = self
+            .current_pokemon
+            .as_ref()
+            .zip(self.selected_version_group.as_ref())
+            .and_then(|(current_pokemon, selected_version_group)| {
+                current_pokemon.get_encounter_location_area(
+                    selected_version_group,
+                    self.pokemon_encounters_state.0.selected()?,
+                )
+            })
+            .cloned();
+
+        if let Some(location_area) = location_area {
+            self.set_list_mode(ListMode::Locations);
+            self.reset_current_location();
+            self.select_location_area(location_area);
+        }
+    }
+
+    pub fn on_type_selected(&mut self) {
+        let pokemon_type = self.type_list.get_selected().cloned();
+        if let Some(pokemon_type) = pokemon_type {
+            self.select_type(pokemon_type);
+        }
+    }
+
+    fn select_type(&mut self, pokemon_type: NamedApiResource) {
+        self.reset_current_type();
+
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher.fetch_type(&pokemon_type).await.map(Box::new);
+
+            FetchedData::Type {
+                pokemon_type,
+                result,
             }
+        });
+    }
+
+    pub fn reset_current_type(&mut self) {
+        self.close_detail_pages();
+        self.current_type = None;
+        self.selected_part = SelectedPart::List;
+        self.type_focus = TypeFocus::Pokemon;
+        self.type_pokemon_state = SwitchableTableState::new();
+        self.type_moves_state = SwitchableTableState::new();
+    }
+
+    pub fn on_type_focus_switch(&mut self) {
+        self.type_focus = match self.type_focus {
+            TypeFocus::Pokemon => TypeFocus::Moves,
+            TypeFocus::Moves => TypeFocus::Pokemon,
+        };
+    }
+
+    pub fn get_type_pokemon_count(&self) -> Option<usize> {
+        self.current_type
+            .as_ref()
+            .map(|current_type| current_type.pokemon.as_ref().map_or(0, Vec::len))
+    }
+
+    pub fn get_type_moves_count(&self) -> Option<usize> {
+        self.current_type
+            .as_ref()
+            .map(|current_type| current_type.moves.as_ref().map_or(0, Vec::len))
+    }
+
+    /// Opens the highlighted Pokemon of the type on the Pokemon page.
+    pub fn on_type_pokemon_selected(&mut self) {
+        let pokemon = self
+            .current_type
+            .as_ref()
+            .and_then(|current_type| {
+                current_type.get_pokemon(self.type_pokemon_state.0.selected()?)
+            })
+            .cloned();
+
+        if let Some(pokemon) = pokemon {
+            self.set_list_mode(ListMode::Pokemon);
+            self.select_pokemon(pokemon);
         }
     }
 
-    pub fn on_search_append(&mut self, character: char) {
-        self.search.push(character);
-        self.filter_list();
+    /// Opens the highlighted move of the type on the move page.
+    pub fn on_type_move_selected(&mut self) {
+        let pokemon_move = self
+            .current_type
+            .as_ref()
+            .and_then(|current_type| current_type.get_move(self.type_moves_state.0.selected()?))
+            .cloned();
+
+        if let Some(pokemon_move) = pokemon_move {
+            self.select_move(pokemon_move);
+        }
     }
 
-    pub fn on_search_remove(&mut self) {
-        self.search.pop();
-        self.filter_list();
+    /// Opens the highlighted row of the Pokemon's moves table on the move page.
+    pub fn on_pokemon_move_selected(&mut self) {
+        let pokemon_move = self
+            .current_pokemon
+            .as_ref()
+            .zip(self.selected_version_group.as_ref())
+            .and_then(|(current_pokemon, selected_version_group)| {
+                current_pokemon.get_move(
+                    &selected_version_group.get_name_or_stub(),
+                    self.pokemon_moves_list_state.0.selected()?,
+                )
+            })
+            .cloned();
+
+        if let Some(pokemon_move) = pokemon_move {
+            self.select_move(pokemon_move);
+        }
+    }
+
+    /// The page underneath stays as it is, so closing the move page goes right back to it.
+    fn select_move(&mut self, pokemon_move: NamedApiResource) {
+        self.close_detail_pages();
+
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher.fetch_move(&pokemon_move).await.map(Box::new);
+
+            FetchedData::Move {
+                pokemon_move,
+                result,
+            }
+        });
+    }
+
+    /// Whether a move or ability page covers the current page.
+    pub fn has_detail_page(&self) -> bool {
+        self.current_move.is_some() || self.current_ability.is_some()
+    }
+
+    pub fn close_detail_pages(&mut self) {
+        self.current_move = None;
+        self.move_learners_state = SwitchableTableState::new();
+        self.current_ability = None;
+        self.ability_pokemon_state = SwitchableTableState::new();
+    }
+
+    pub fn get_move_learners_count(&self) -> Option<usize> {
+        self.current_move
+            .as_ref()
+            .map(|current_move| current_move.get_learners_count())
+    }
+
+    /// Opens the highlighted Pokemon that learns the move on the Pokemon page.
+    pub fn on_move_learner_selected(&mut self) {
+        let pokemon = self
+            .current_move
+            .as_ref()
+            .and_then(|current_move| {
+                current_move.get_learner(self.move_learners_state.0.selected()?)
+            })
+            .cloned();
+
+        if let Some(pokemon) = pokemon {
+            self.set_list_mode(ListMode::Pokemon);
+            self.select_pokemon(pokemon);
+        }
+    }
+
+    pub fn get_pokemon_abilities_count(&self) -> Option<usize> {
+        self.current_pokemon
+            .as_ref()
+            .map(|current_pokemon| current_pokemon.abilities.len())
+    }
+
+    /// Opens the highlighted row of the Pokemon's abilities table on the ability page.
+    pub fn on_pokemon_ability_selected(&mut self) {
+        let ability = self
+            .current_pokemon
+            .as_ref()
+            .and_then(|current_pokemon| {
+                current_pokemon.get_ability(self.pokemon_abilities_state.0.selected()?)
+            })
+            .cloned();
+
+        if let Some(ability) = ability {
+            self.select_ability(ability);
+        }
+    }
+
+    fn select_ability(&mut self, ability: NamedApiResource) {
+        self.close_detail_pages();
+
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher.fetch_ability(&ability).await.map(Box::new);
+
+            FetchedData::Ability { ability, result }
+        });
+    }
+
+    pub fn get_ability_pokemon_count(&self) -> Option<usize> {
+        self.current_ability
+            .as_ref()
+            .map(|current_ability| current_ability.get_pokemon_count())
+    }
+
+    /// Opens the highlighted Pokemon that has the ability on the Pokemon page.
+    pub fn on_ability_pokemon_selected(&mut self) {
+        let pokemon = self
+            .current_ability
+            .as_ref()
+            .and_then(|current_ability| {
+                current_ability.get_pokemon(self.ability_pokemon_state.0.selected()?)
+            })
+            .cloned();
+
+        if let Some(pokemon) = pokemon {
+            self.set_list_mode(ListMode::Pokemon);
+            self.select_pokemon(pokemon);
+        }
+    }
+
+    pub fn reset_current_pokemon(&mut self) {
+        self.close_detail_pages();
+        self.current_pokemon = None;
+        self.current_main_page_state = CurrentMainPageState::VersionGroupSelection;
+        self.selected_part = SelectedPart::List;
+        self.selected_version_group = None;
+        self.basic_info_focus = BasicInfoFocus::Moves;
+        self.evolution_chain_state = SwitchableTableState::new();
+        self.pokemon_abilities_state = SwitchableTableState::new();
+        self.pokemon_held_items_state = SwitchableTableState::new();
+        self.pokemon_encounters_state = SwitchableTableState::new();
+        self.pokemon_forms_state = SwitchableTableState::new();
+        self.stat_spread = StatSpread::default();
+        self.stat_calculator_focus = StatCalculatorFocus::Ivs;
+        self.stat_calculator_state = SwitchableTableState::new();
+        self.comparison = None;
+        self.comparison_moves_state = SwitchableTableState::new();
+        self.damage_calculator = None;
+        self.damage_settings_state = SwitchableTableState::new();
+        self.cancel_pending_sprite_fetch();
+        self.sprite = None;
+        self.sprite_variant = SpriteVariant::FrontDefault;
+    }
+
+    /// Esc first abandons a pending load and only then leaves the current page.
+    pub fn on_escape(&mut self) {
+        if self.loading {
+            self.cancel_pending_fetch();
+        } else if self.has_detail_page() {
+            self.close_detail_pages();
+        } else if let (
+            ListMode::Pokemon,
+            CurrentMainPageState::StatCalculator
+            | CurrentMainPageState::Comparison
+            | CurrentMainPageState::DamageCalculator,
+        ) = (self.list_mode, &self.current_main_page_state)
+        {
+            self.current_main_page_state = CurrentMainPageState::BasicInfo;
+            self.comparison = None;
+            self.damage_calculator = None;
+        } else {
+            match self.list_mode {
+                ListMode::Pokemon => self.reset_current_pokemon(),
+                ListMode::Types => self.reset_current_type(),
+                ListMode::Items => self.reset_current_item(),
+                ListMode::Locations
+                    if self.current_location.is_none() && self.current_region.is_some() =>
+                {
+                    self.leave_region()
+                }
+                ListMode::Locations => self.reset_current_location(),
+                ListMode::Teams => self.reset_current_team(),
+            }
+        }
+    }
+
+    pub fn filter_list(&mut self) {
+        let search = self.search.to_lowercase();
+        let list = self.get_active_list_mut();
+        list.items_to_render = list
+            .items
+            .par_iter()
+            .filter_map(|item| {
+                let should_be_included = item.get_name_or_stub().to_lowercase().contains(&search);
+
+                if should_be_included {
+                    return Some(item.clone());
+                }
+
+                None
+            })
+            .collect();
+
+        list.state.select(Some(0));
+    }
+
+    pub fn on_pokemon_selected(&mut self) {
+        let pokemon = self.pokemon_list.get_selected().cloned();
+        if let Some(pokemon) = pokemon {
+            self.select_pokemon(pokemon);
+        }
+    }
+
+    fn select_pokemon(&mut self, pokemon: NamedApiResource) {
+        self.reset_current_pokemon();
+        self.fetch_pokemon(pokemon);
+    }
+
+    fn fetch_pokemon(&mut self, pokemon: NamedApiResource) {
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher
+                .fetch_pokemon_with_info(&pokemon)
+                .await
+                .map(Box::new);
+
+            FetchedData::Pokemon { pokemon, result }
+        });
+    }
+
+    pub fn on_basic_info_focus_switch(&mut self) {
+        self.basic_info_focus = match self.basic_info_focus {
+            BasicInfoFocus::Moves => BasicInfoFocus::EvolutionChain,
+            BasicInfoFocus::EvolutionChain => BasicInfoFocus::Abilities,
+            BasicInfoFocus::Abilities => BasicInfoFocus::HeldItems,
+            BasicInfoFocus::HeldItems => BasicInfoFocus::Encounters,
+            BasicInfoFocus::Encounters => BasicInfoFocus::Forms,
+            BasicInfoFocus::Forms => BasicInfoFocus::Moves,
+        };
+    }
+
+    pub fn get_evolution_stages_count(&self) -> Option<usize> {
+        self.current_pokemon
+            .as_ref()
+            .map(|current_pokemon| current_pokemon.get_evolution_stages().len())
+    }
+
+    /// Opens the highlighted stage of the evolution chain in the same version group.
+    pub fn on_evolution_stage_selected(&mut self) {
+        let pokemon = self.current_pokemon.as_ref().and_then(|current_pokemon| {
+            let stages = current_pokemon.get_evolution_stages();
+            let species = stages
+                .get(self.evolution_chain_state.0.selected()?)?
+                .get_species()?;
+
+            self.fetcher.get_default_pokemon(species)
+        });
+
+        if let Some(pokemon) = pokemon {
+            self.switch_pokemon(pokemon);
+        }
+    }
+
+    /// Opens another Pokemon without asking for the version group again.
+    fn switch_pokemon(&mut self, pokemon: NamedApiResource) {
+        let selected_version_group = self.selected_version_group.take();
+        self.reset_current_pokemon();
+        self.selected_version_group = selected_version_group;
+        self.fetch_pokemon(pokemon);
+    }
+
+    pub fn get_pokemon_forms_count(&self) -> Option<usize> {
+        self.current_pokemon
+            .as_ref()
+            .map(|current_pokemon| current_pokemon.get_renderable_form_choices().len())
+    }
+
+    /// A variety is a Pokemon of its own, so it replaces the page. A form only changes
+    /// the look, so the Pokemon stays and just gets the form's types and sprites.
+    pub fn on_pokemon_form_selected(&mut self) {
+        let form_choice = self.current_pokemon.as_ref().and_then(|current_pokemon| {
+            current_pokemon.get_form_choice(self.pokemon_forms_state.0.selected()?)
+        });
+
+        match form_choice {
+            Some(FormChoice::Variety(pokemon)) => self.switch_pokemon(pokemon),
+            Some(FormChoice::Form(form)) => self.select_form(form),
+            None => {}
+        }
+    }
+
+    fn select_form(&mut self, form: NamedApiResource) {
+        self.close_detail_pages();
+
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher.fetch_pokemon_form(&form).await.map(Box::new);
+
+            FetchedData::PokemonForm { form, result }
+        });
+    }
+
+    pub fn on_version_group_selected(&mut self) {
+        self.selected_version_group = self
+            .version_groups
+            .get_selected()
+            .and_then(|version_group| {
+                self.version_group_details
+                    .get(&version_group.get_name_or_stub())
+            })
+            .cloned();
+
+        if self.selected_version_group.is_some() {
+            self.current_main_page_state = CurrentMainPageState::BasicInfo;
+        }
+    }
+
+    pub fn on_moves_and_abilities_open(&mut self) {
+        self.pokemon_moves_list_state = SwitchableTableState::new();
+        if let Some(current_pokemon) = self.current_pokemon.as_ref() {
+            if current_pokemon.abilities.is_empty() && current_pokemon.moves.is_empty() {
+                self.rendered_moves_count = None;
+
+                let fetcher = self.fetcher.clone();
+                let (ability_urls, move_urls) =
+                    fetcher.get_abilities_and_moves_urls(&current_pokemon.pokemon);
+                self.spawn_fetch(async move {
+                    let (abilities, moves) = fetcher
+                        .fetch_abilities_and_moves(ability_urls, move_urls)
+                        .await;
+
+                    FetchedData::MovesAndAbilities(abilities, moves)
+                });
+            }
+        }
+    }
+
+    /// The calculator covers the Pokemon page and starts on its first stat.
+    pub fn on_stat_calculator_open(&mut self) {
+        if let CurrentMainPageState::BasicInfo = self.current_main_page_state {
+            self.current_main_page_state = CurrentMainPageState::StatCalculator;
+            if let Some(member) = self.get_current_team_member() {
+                self.stat_spread = member.stat_spread.clone();
+            }
+            if self.stat_calculator_state.0.selected().is_none() {
+                self.stat_calculator_state.next(Some(STAT_NAMES.len()));
+            }
+        }
+    }
+
+    pub fn on_stat_calculator_focus_switch(&mut self) {
+        self.stat_calculator_focus = match self.stat_calculator_focus {
+            StatCalculatorFocus::Ivs => StatCalculatorFocus::Evs,
+            StatCalculatorFocus::Evs => StatCalculatorFocus::Ivs,
+        };
+    }
+
+    /// Only every fourth EV counts, so EVs change four at a time.
+    pub fn on_stat_change(&mut self, delta: i32) {
+        if let Some(stat_index) = self.stat_calculator_state.0.selected() {
+            match self.stat_calculator_focus {
+                StatCalculatorFocus::Ivs => self.stat_spread.change_iv(stat_index, delta),
+                StatCalculatorFocus::Evs => self.stat_spread.change_ev(stat_index, delta * 4),
+            }
+            self.save_team_stat_spread();
+        }
+    }
+
+    pub fn on_level_change(&mut self, delta: i32) {
+        self.stat_spread.change_level(delta);
+        self.save_team_stat_spread();
+    }
+
+    pub fn on_nature_switch(&mut self) {
+        self.stat_spread.nature = self.stat_spread.nature.next();
+        self.save_team_stat_spread();
+    }
+
+    /// The calculator doubles as the spread editor of a Pokemon that's on the current team.
+    fn save_team_stat_spread(&mut self) {
+        let stat_spread = self.stat_spread.clone();
+        if let Some(member) = self.get_current_team_member_mut() {
+            member.stat_spread = stat_spread;
+            self.save_teams();
+        }
+    }
+
+    pub fn is_marked(&self, pokemon_name: &str) -> bool {
+        self.marked_pokemon
+            .iter()
+            .any(|pokemon| pokemon.name.as_deref() == Some(pokemon_name))
+    }
+
+    /// Marks the Pokemon on the page for comparison, or unmarks it when it already is.
+    pub fn on_pokemon_mark(&mut self) {
+        let pokemon = self.current_pokemon.as_ref().and_then(|current_pokemon| {
+            self.fetcher.get_pokemon_resource(&current_pokemon.pokemon)
+        });
+
+        if let Some(pokemon) = pokemon {
+            if self.is_marked(&pokemon.get_name_or_stub()) {
+                self.marked_pokemon
+                    .retain(|marked| marked.name != pokemon.name);
+            } else if self.marked_pokemon.len() < MAX_COMPARED_POKEMON {
+                self.marked_pokemon.push(pokemon);
+            }
+        }
+    }
+
+    /// Comparing needs at least two Pokemon, and the moves need the page's version group.
+    pub fn on_comparison_open(&mut self) {
+        if self.marked_pokemon.len() < 2 || self.selected_version_group.is_none() {
+            return;
+        }
+
+        self.compare_pokemon(self.marked_pokemon.clone());
+    }
+
+    fn compare_pokemon(&mut self, pokemon: Vec<NamedApiResource>) {
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher
+                .fetch_pokemon_comparison(&pokemon)
+                .await
+                .map(Box::new);
+
+            FetchedData::Comparison { pokemon, result }
+        });
+    }
+
+    pub fn get_comparison_moves_count(&self) -> Option<usize> {
+        let selected_version_group = self.selected_version_group.as_ref()?;
+
+        self.comparison.as_ref().map(|comparison| {
+            comparison
+                .get_renderable_unique_moves(&selected_version_group.get_name_or_stub())
+                .len()
+        })
+    }
+
+    /// The page's Pokemon uses the highlighted move on the first other marked Pokemon, both
+    /// sides can then be picked from all of them. Spreads and items of Pokemon on the current
+    /// team are where the settings start.
+    pub fn on_damage_calculator_open(&mut self) {
+        let (current_pokemon, selected_version_group) = match (
+            self.current_pokemon.as_ref(),
+            self.selected_version_group.as_ref(),
+        ) {
+            (Some(current_pokemon), Some(selected_version_group)) => {
+                (current_pokemon, selected_version_group)
+            }
+            _ => return,
+        };
+        let version_group = selected_version_group.get_name_or_stub();
+        let damage_move = match self.basic_info_focus {
+            BasicInfoFocus::Moves => self
+                .pokemon_moves_list_state
+                .0
+                .selected()
+                .and_then(|index| current_pokemon.get_move(&version_group, index))
+                .and_then(|pokemon_move| current_pokemon.moves.get(pokemon_move.name.as_ref()?))
+                .and_then(DamageMove::from_move),
+            _ => None,
+        };
+        let marked_pokemon: Vec<NamedApiResource> = self
+            .marked_pokemon
+            .iter()
+            .filter(|pokemon| pokemon.name != current_pokemon.pokemon.name)
+            .cloned()
+            .collect();
+        let damage_move = match damage_move {
+            Some(damage_move) if !marked_pokemon.is_empty() => damage_move,
+            _ => return,
+        };
+        let generation = selected_version_group
+            .get_generation()
+            .unwrap_or(LATEST_GENERATION);
+
+        let mut damage_pokemon = DamagePokemon::new(current_pokemon, &version_group, generation);
+        match self.get_current_team_member() {
+            Some(member) => damage_pokemon.set_team_member(member),
+            None => damage_pokemon.stat_spread = self.stat_spread.clone(),
+        }
+        let team = self.get_current_team().cloned();
+
+        let fetcher = self.fetcher.clone();
+        self.spawn_fetch(async move {
+            let result = fetcher
+                .fetch_damage_calculator(
+                    damage_pokemon,
+                    marked_pokemon,
+                    team,
+                    damage_move.name,
+                    version_group,
+                    generation,
+                )
+                .await
+                .map(Box::new);
+
+            FetchedData::DamageCalculator(result)
+        });
+    }
+
+    pub fn on_damage_setting_change(&mut self, delta: i32) {
+        if let (Some(damage_calculator), Some(index)) = (
+            self.damage_calculator.as_mut(),
+            self.damage_settings_state.0.selected(),
+        ) {
+            damage_calculator.change_setting(index, delta);
+        }
+    }
+
+    fn set_team_list(&mut self) {
+        let teams: Vec<NamedApiResource> = self
+            .teams
+            .iter()
+            .map(|team| NamedApiResource {
+                name: Some(team.name.clone()),
+                url: None,
+            })
+            .collect();
+
+        self.team_list.items = teams;
+        if self.list_mode == ListMode::Teams {
+            self.filter_list();
+        } else {
+            self.team_list.items_to_render = self.team_list.items.clone();
+        }
+    }
+
+    fn save_teams(&mut self) {
+        self.set_team_list();
+
+        if let Some(team_store) = self.team_store.as_ref() {
+            if let Err(err) = team_store.save(&self.teams) {
+                self.team_status = Some(format!(
+                    "Couldn't save teams to {}: {}",
+                    team_store.path().display(),
+                    err
+                ));
+            }
+        }
+    }
+
+    pub fn get_current_team(&self) -> Option<&Team> {
+        self.teams.get(self.current_team?)
+    }
+
+    fn get_current_team_mut(&mut self) -> Option<&mut Team> {
+        self.teams.get_mut(self.current_team?)
+    }
+
+    /// The Pokemon on the page, when it's on the current team.
+    pub fn get_current_team_member(&self) -> Option<&TeamMember> {
+        let pokemon_name = self.current_pokemon.as_ref()?.pokemon.name.as_ref()?;
+        let team = self.get_current_team()?;
+
+        team.members.get(team.get_member_index(pokemon_name)?)
+    }
+
+    fn get_current_team_member_mut(&mut self) -> Option<&mut TeamMember> {
+        let pokemon_name = self.current_pokemon.as_ref()?.pokemon.name.clone()?;
+        let team = self.get_current_team_mut()?;
+        let member_index = team.get_member_index(&pokemon_name)?;
+
+        team.members.get_mut(member_index)
+    }
+
+    pub fn on_team_selected(&mut self) {
+        let team_name = self
+            .team_list
+            .get_selected()
+            .map(|team| team.get_name_or_stub());
+        let team_index = team_name
+            .and_then(|team_name| self.teams.iter().position(|team| team.name == team_name));
+
+        if let Some(team_index) = team_index {
+            self.select_team(team_index);
+        }
+    }
+
+    fn select_team(&mut self, team_index: usize) {
+        self.close_detail_pages();
+        self.current_team = Some(team_index);
+        self.team_members_state = SwitchableTableState::new();
+        self.selected_part = SelectedPart::Main;
+        self.fetch_team_types();
+    }
+
+    /// The types only change with the game data, so they're fetched once for every team.
+    fn fetch_team_types(&mut self) {
+        if !self.team_types.is_empty() || self.pending_team_types_fetch.is_some() {
+            return;
+        }
+
+        let fetcher = self.fetcher.clone();
+        self.pending_team_types_fetch = Some(self.spawn_tracked_fetch(async move {
+            FetchedData::TeamTypes(fetcher.fetch_all_types().await)
+        }));
+    }
+
+    pub fn reset_current_team(&mut self) {
+        self.close_detail_pages();
+        self.current_team = None;
+        self.selected_part = SelectedPart::List;
+        self.team_members_state = SwitchableTableState::new();
+    }
+
+    fn create_team(&mut self) -> usize {
+        self.teams.push(Team::new(get_free_team_name(&self.teams)));
+        self.save_teams();
+
+        self.teams.len() - 1
+    }
+
+    pub fn on_team_create(&mut self) {
+        self.team_status = None;
+
+        let team_index = self.create_team();
+        self.select_team(team_index);
+    }
+
+    pub fn on_team_delete(&mut self) {
+        if let Some(team_index) = self.current_team {
+            self.team_status = None;
+            self.teams.remove(team_index);
+            self.reset_current_team();
+            self.save_teams();
+        }
+    }
+
+    pub fn get_team_members_count(&self) -> Option<usize> {
+        self.get_current_team().map(|team| team.members.len())
+    }
+
+    pub fn on_team_member_remove(&mut self) {
+        let member_index = self.team_members_state.0.selected();
+        let removed = match (self.get_current_team_mut(), member_index) {
+            (Some(team), Some(member_index)) if member_index < team.members.len() => {
+                team.members.remove(member_index);
+                true
+            }
+            _ => false,
+        };
+
+        if removed {
+            self.team_status = None;
+            self.team_members_state = SwitchableTableState::new();
+            self.save_teams();
+        }
+    }
+
+    /// Opens the highlighted member on the Pokemon page, in the version group the team is for.
+    pub fn on_team_member_selected(&mut self) {
+        let member_and_version_group = self.get_current_team().and_then(|team| {
+            let member = team.members.get(self.team_members_state.0.selected()?)?;
+            let version_group = self
+                .version_group_details
+                .get(team.version_group.as_ref()?)?;
+
+            Some((member.pokemon.clone(), version_group.clone()))
+        });
+
+        if let Some((pokemon, version_group)) = member_and_version_group {
+            self.set_list_mode(ListMode::Pokemon);
+            self.reset_current_pokemon();
+            self.selected_version_group = Some(version_group);
+            self.fetch_pokemon(pokemon);
+        }
+    }
+
+    /// Adds the Pokemon on the page to the current team, or takes it off when it's already
+    /// on it. The first Pokemon starts a team when there's none, and picks its version group.
+    pub fn on_team_pokemon_toggle(&mut self) {
+        let version_group = match self.selected_version_group.as_ref() {
+            Some(version_group) => version_group.get_name_or_stub(),
+            None => return,
+        };
+        let member = self.current_pokemon.as_ref().and_then(|current_pokemon| {
+            let pokemon = self
+                .fetcher
+                .get_pokemon_resource(&current_pokemon.pokemon)?;
+            let types = current_pokemon
+                .get_types()
+                .iter()
+                .filter_map(|de_type| de_type.name.clone())
+                .collect();
+            let ability = current_pokemon
+                .pokemon
+                .abilities
+                .iter()
+                .flatten()
+                .find_map(|ability| ability.ability.as_ref()?.name.clone());
+
+            Some(TeamMember::new(pokemon, types, ability))
+        });
+        let member = match member {
+            Some(member) => member,
+            None => return,
+        };
+
+        self.team_status = None;
+        let team_index = match self.current_team {
+            Some(team_index) => team_index,
+            None => self.create_team(),
+        };
+        self.current_team = Some(team_index);
+
+        let team = &mut self.teams[team_index];
+        let team_version_group = team.version_group.get_or_insert(version_group.clone());
+        if *team_version_group != version_group {
+            self.team_status = Some(format!(
+                "{} is a team for {}",
+                team.name,
+                team_version_group.clone().split_capitalize()
+            ));
+            return;
+        }
+
+        match team.get_member_index(&member.get_name()) {
+            Some(member_index) => {
+                team.members.remove(member_index);
+            }
+            None if !team.add_member(member) => {
+                self.team_status = Some(format!("{} is full", team.name));
+                return;
+            }
+            None => {}
+        }
+
+        self.save_teams();
+    }
+
+    /// Teaches the highlighted move or gives the highlighted ability to the Pokemon on the
+    /// page, which has to be on the current team.
+    pub fn on_team_member_assign(&mut self) {
+        let selected_version_group = match self.selected_version_group.as_ref() {
+            Some(version_group) => version_group.get_name_or_stub(),
+            None => return,
+        };
+        let current_pokemon = match self.current_pokemon.as_ref() {
+            Some(current_pokemon) => current_pokemon,
+            None => return,
+        };
+        if self.get_current_team_member().is_none() {
+            self.team_status = Some(String::from("Press t to add the Pokemon to a team first"));
+            return;
+        }
+
+        let team_move = match self.basic_info_focus {
+            BasicInfoFocus::Moves => self
+                .pokemon_moves_list_state
+                .0
+                .selected()
+                .and_then(|index| current_pokemon.get_move(&selected_version_group, index))
+                .and_then(|pokemon_move| current_pokemon.moves.get(pokemon_move.name.as_ref()?))
+                .and_then(TeamMove::from_move),
+            _ => None,
+        };
+        let ability = match self.basic_info_focus {
+            BasicInfoFocus::Abilities => self
+                .pokemon_abilities_state
+                .0
+                .selected()
+                .and_then(|index| current_pokemon.get_ability(index))
+                .and_then(|ability| ability.name.clone()),
+            _ => None,
+        };
+
+        self.team_status = None;
+        let is_changed = match (self.get_current_team_member_mut(), team_move, ability) {
+            (Some(member), Some(team_move), _) => Some(member.toggle_move(team_move)),
+            (Some(member), _, Some(ability)) => {
+                member.ability = Some(ability);
+                Some(true)
+            }
+            _ => None,
+        };
+
+        match is_changed {
+            Some(true) => self.save_teams(),
+            Some(false) => {
+                self.team_status = Some(String::from("Forget a move first, it knows four"))
+            }
+            None => {}
+        }
+    }
+
+    /// Gives the item on the page to the member highlighted on the current team, or takes
+    /// it back when the member already holds it.
+    pub fn on_team_item_assign(&mut self) {
+        let item = self
+            .current_item
+            .as_ref()
+            .and_then(|current_item| current_item.item.name.clone());
+        let member_index = self.team_members_state.0.selected();
+        let member = self
+            .get_current_team_mut()
+            .zip(member_index)
+            .and_then(|(team, member_index)| team.members.get_mut(member_index));
+
+        match (member, item) {
+            (Some(member), Some(item)) => {
+                member.item = match member.item.as_ref() == Some(&item) {
+                    true => None,
+                    false => Some(item),
+                };
+                self.team_status = None;
+                self.save_teams();
+            }
+            _ => {
+                self.team_status = Some(String::from("Highlight a Pokemon on a team first"));
+            }
+        }
+    }
+
+    pub fn on_retry(&mut self) {
+        if let Some(failed_fetch) = self.failed_fetch.take() {
+            match failed_fetch.retry {
+                RetryAction::Init => self.init(),
+                RetryAction::PokemonSelection(pokemon) => self.select_pokemon(pokemon),
+                RetryAction::FormSelection(form) => self.select_form(form),
+                RetryAction::Comparison(pokemon) => self.compare_pokemon(pokemon),
+                RetryAction::DamageCalculator => self.on_damage_calculator_open(),
+                RetryAction::TeamTypes => {
+                    // Whatever did load is thrown away, the retry fetches every type again
+                    self.team_types.clear();
+                    self.fetch_team_types();
+                }
+                RetryAction::MovesAndAbilities => {
+                    // Whatever did load is thrown away, the retry fetches the full set again
+                    if let Some(current_pokemon) = self.current_pokemon.as_mut() {
+                        current_pokemon.abilities.clear();
+                        current_pokemon.moves.clear();
+                    }
+                    self.on_moves_and_abilities_open();
+                }
+                RetryAction::TypeSelection(pokemon_type) => self.select_type(pokemon_type),
+                RetryAction::MoveSelection(pokemon_move) => self.select_move(pokemon_move),
+                RetryAction::AbilitySelection(ability) => self.select_ability(ability),
+                RetryAction::ItemSelection(item) => self.select_item(item),
+                RetryAction::RegionSelection(region) => self.select_region(region),
+                RetryAction::LocationSelection(location) => self.select_location(location),
+                RetryAction::LocationAreaSelection(location_area) => {
+                    self.select_location_area(location_area)
+                }
+            }
+        }
+    }
+
+    fn on_fetch_failed(&mut self, error: FetchError, retry: RetryAction) {
+        self.failed_fetch = Some(FailedFetch { error, retry });
+    }
+
+    pub fn dismiss_failed_fetch(&mut self) {
+        self.failed_fetch = None;
+    }
+
+    pub fn on_search_append(&mut self, character: char) {
+        self.search.push(character);
+        self.filter_list();
+    }
+
+    pub fn on_search_remove(&mut self) {
+        self.search.pop();
+        self.filter_list();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use serde_json::json;
+    use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+    use crate::{
+        endpoints::Endpoints,
+        error::FetchError,
+        event::AppEvent,
+        models::{showdown::parse_team, NamedApiResource, Nature, SpriteVariant, Team},
+        source::{fixture::FixtureSource, FetchPolicy},
+        sprite::fixture::get_stub_png,
+    };
+
+    use super::{App, BasicInfoFocus, CurrentMainPageState, ListMode, RetryAction, SelectedPart};
+
+    fn get_stub_source() -> FixtureSource {
+        FixtureSource::default()
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon?limit=100000&offset=0",
+                json!({
+                    "count": 2,
+                    "results": [
+                        { "name": "bulbasaur", "url": "https://pokeapi.co/api/v2/pokemon/1/" },
+                        { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/version-group?limit=100000&offset=0",
+                json!({
+                    "count": 2,
+                    "results": [
+                        { "name": "red-blue", "url": "https://pokeapi.co/api/v2/version-group/1/" },
+                        { "name": "x-y", "url": "https://pokeapi.co/api/v2/version-group/15/" }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/version-group/1/",
+                json!({
+                    "id": 1,
+                    "name": "red-blue",
+                    "versions": [
+                        { "name": "red", "url": "https://pokeapi.co/api/v2/version/1/" },
+                        { "name": "blue", "url": "https://pokeapi.co/api/v2/version/2/" }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/version-group/15/",
+                json!({
+                    "id": 15,
+                    "name": "x-y",
+                    "generation": { "name": "generation-vi", "url": null },
+                    "versions": [
+                        { "name": "x", "url": "https://pokeapi.co/api/v2/version/23/" },
+                        { "name": "y", "url": "https://pokeapi.co/api/v2/version/24/" }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/type?limit=100000&offset=0",
+                json!({
+                    "count": 2,
+                    "results": [
+                        { "name": "electric", "url": "https://pokeapi.co/api/v2/type/13/" },
+                        { "name": "unknown", "url": "https://pokeapi.co/api/v2/type/10001/" }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/item?limit=100000&offset=0",
+                json!({
+                    "count": 1,
+                    "results": [
+                        { "name": "light-ball", "url": "https://pokeapi.co/api/v2/item/213/" }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/item/213/",
+                json!({
+                    "id": 213,
+                    "name": "light-ball",
+                    "held_by_pokemon": [{
+                        "pokemon": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" },
+                        "version_details": [{ "rarity": 5, "version": { "name": "x", "url": null } }]
+                    }]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon/25/",
+                json!({
+                    "id": 25,
+                    "name": "pikachu",
+                    "held_items": [{
+                        "item": { "name": "light-ball", "url": "https://pokeapi.co/api/v2/item/213/" },
+                        "version_details": [{ "rarity": 5, "version": { "name": "x", "url": null } }]
+                    }],
+                    "abilities": [{
+                        "is_hidden": false,
+                        "slot": 1,
+                        "ability": { "name": "static", "url": "https://pokeapi.co/api/v2/ability/9/" }
+                    }],
+                    "moves": [{
+                        "move": { "name": "thunder-shock", "url": "https://pokeapi.co/api/v2/move/84/" },
+                        "version_group_details": [{
+                            "level_learned_at": 1,
+                            "move_learn_method": { "name": "level-up", "url": null },
+                            "version_group": { "name": "x-y", "url": null }
+                        }]
+                    }],
+                    "species": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon-species/25/" },
+                    "types": [{
+                        "slot": 1,
+                        "type": { "name": "electric", "url": "https://pokeapi.co/api/v2/type/13/" }
+                    }]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon-species/25/",
+                json!({
+                    "capture_rate": 190,
+                    "base_happiness": 50,
+                    "evolution_chain": { "url": "https://pokeapi.co/api/v2/evolution-chain/10/" }
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/evolution-chain/10/",
+                json!({
+                    "id": 10,
+                    "chain": {
+                        "is_baby": true,
+                        "species": { "name": "pichu", "url": "https://pokeapi.co/api/v2/pokemon-species/172/" },
+                        "evolution_details": [],
+                        "evolves_to": [{
+                            "is_baby": false,
+                            "species": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon-species/25/" },
+                            "evolution_details": [{
+                                "trigger": { "name": "level-up", "url": null },
+                                "min_happiness": 220
+                            }],
+                            "evolves_to": []
+                        }]
+                    }
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon/172/",
+                json!({
+                    "id": 172,
+                    "name": "pichu",
+                    "species": { "name": "pichu", "url": "https://pokeapi.co/api/v2/pokemon-species/172/" }
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon-species/172/",
+                json!({ "capture_rate": 190 }),
+            )
+            .with_response("https://pokeapi.co/api/v2/pokemon/172/encounters", json!([]))
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon/25/encounters",
+                json!([{
+                    "location_area": {
+                        "name": "viridian-forest-area",
+                        "url": "https://pokeapi.co/api/v2/location-area/321/"
+                    },
+                    "version_details": [{
+                        "max_chance": 5,
+                        "version": { "name": "x", "url": null },
+                        "encounter_details": [{
+                            "chance": 5,
+                            "min_level": 3,
+                            "max_level": 5,
+                            "method": { "name": "walk", "url": null },
+                            "condition_values": []
+                        }]
+                    }]
+                }]),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/region?limit=100000&offset=0",
+                json!({
+                    "count": 2,
+                    "results": [
+                        { "name": "kanto", "url": "https://pokeapi.co/api/v2/region/1/" },
+                        { "name": "johto", "url": "https://pokeapi.co/api/v2/region/2/" }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/region/1/",
+                json!({
+                    "id": 1,
+                    "name": "kanto",
+                    "locations": [
+                        { "name": "pallet-town", "url": "https://pokeapi.co/api/v2/location/86/" },
+                        { "name": "viridian-forest", "url": "https://pokeapi.co/api/v2/location/155/" }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/location/155/",
+                json!({
+                    "id": 155,
+                    "name": "viridian-forest",
+                    "region": { "name": "kanto", "url": null },
+                    "areas": [{
+                        "name": "viridian-forest-area",
+                        "url": "https://pokeapi.co/api/v2/location-area/321/"
+                    }]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/location-area/321/",
+                json!({
+                    "id": 321,
+                    "name": "viridian-forest-area",
+                    "location": {
+                        "name": "viridian-forest",
+                        "url": "https://pokeapi.co/api/v2/location/155/"
+                    },
+                    "pokemon_encounters": [{
+                        "pokemon": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" },
+                        "version_details": [{
+                            "max_chance": 5,
+                            "version": { "name": "x", "url": null },
+                            "encounter_details": [{
+                                "chance": 5,
+                                "min_level": 3,
+                                "max_level": 5,
+                                "method": { "name": "walk", "url": null },
+                                "condition_values": [{ "name": "time-morning", "url": null }]
+                            }]
+                        }]
+                    }]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/type/13/",
+                json!({
+                    "id": 13,
+                    "name": "electric",
+                    "damage_relations": {
+                        "double_damage_from": [{ "name": "ground", "url": null }],
+                        "half_damage_from": [
+                            { "name": "flying", "url": null },
+                            { "name": "steel", "url": null },
+                            { "name": "electric", "url": null }
+                        ]
+                    },
+                    "pokemon": [{
+                        "slot": 1,
+                        "pokemon": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" }
+                    }],
+                    "moves": [{ "name": "thunder-shock", "url": "https://pokeapi.co/api/v2/move/84/" }]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/ability/9/",
+                json!({
+                    "id": 9,
+                    "name": "static",
+                    "pokemon": [
+                        {
+                            "is_hidden": false,
+                            "slot": 1,
+                            "pokemon": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" }
+                        },
+                        {
+                            "is_hidden": false,
+                            "slot": 1,
+                            "pokemon": { "name": "pichu", "url": "https://pokeapi.co/api/v2/pokemon/172/" }
+                        }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/move/84/",
+                json!({
+                    "id": 84,
+                    "name": "thunder-shock",
+                    "power": 40,
+                    "type": { "name": "electric", "url": "https://pokeapi.co/api/v2/type/13/" },
+                    "damage_class": { "name": "special", "url": null },
+                    "effect_chance": 10,
+                    "meta": {
+                        "ailment": { "name": "paralysis", "url": null },
+                        "ailment_chance": 10
+                    },
+                    "machines": [{
+                        "machine": { "url": "https://pokeapi.co/api/v2/machine/1/" },
+                        "version_group": { "name": "red-blue", "url": null }
+                    }],
+                    "contest_effect": { "url": "https://pokeapi.co/api/v2/contest-effect/1/" },
+                    "learned_by_pokemon": [
+                        { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/machine/1/",
+                json!({
+                    "id": 1,
+                    "item": { "name": "tm24", "url": null },
+                    "version_group": { "name": "red-blue", "url": null }
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/contest-effect/1/",
+                json!({ "id": 1, "appeal": 4, "jam": 0 }),
+            )
+    }
+
+    struct StubApp {
+        app: App,
+        events: UnboundedReceiver<AppEvent>,
+    }
+
+    impl StubApp {
+        fn new(source: FixtureSource) -> Self {
+            let (events_tx, events) = mpsc::unbounded_channel();
+
+            Self {
+                app: App::new(
+                    Arc::new(source),
+                    Endpoints::default(),
+                    FetchPolicy {
+                        retry_delay: Duration::ZERO,
+                        ..FetchPolicy::default()
+                    },
+                    events_tx,
+                    None,
+                ),
+                events,
+            }
+        }
+
+        async fn initialized() -> Self {
+            let mut stub = Self::new(get_stub_source());
+            stub.app.init();
+            stub.deliver_next_fetch().await;
+
+            stub
+        }
+
+        async fn deliver_next_fetch(&mut self) {
+            if let Some(AppEvent::Fetched { request_id, data }) = self.events.recv().await {
+                self.app.on_fetched(request_id, data);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn app_init_fetches_lists() {
+        let StubApp { app, .. } = StubApp::initialized().await;
+
+        assert_eq!(app.pokemon_list.items.len(), 2);
+        assert_eq!(app.pokemon_list.items_to_render.len(), 2);
+        assert_eq!(app.version_groups.items_to_render.len(), 2);
+        assert_eq!(app.version_group_details.len(), 2);
+        assert_eq!(app.type_list.items.len(), 1);
+        assert_eq!(app.item_list.items.len(), 1);
+        assert_eq!(app.region_list.items.len(), 2);
+        assert!(app.location_list.items.is_empty());
+        assert!(!app.loading);
+    }
+
+    #[tokio::test]
+    async fn app_on_list_mode_switch() {
+        let StubApp { mut app, .. } = StubApp::initialized().await;
+        app.on_search_append('p');
+
+        app.on_list_mode_switch();
+        assert_eq!(app.list_mode, ListMode::Types);
+        assert!(app.search.is_empty());
+        assert_eq!(app.get_active_list().items_to_render.len(), 1);
+
+        app.on_search_append('x');
+        assert!(app.get_active_list().items_to_render.is_empty());
+        assert_eq!(app.pokemon_list.items_to_render.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn app_on_type_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.on_list_mode_switch();
+
+        stub.app.on_list_item_selected();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+
+        let current_type = stub.app.current_type.as_ref().unwrap();
+        assert_eq!(current_type.name.as_deref(), Some("electric"));
+        assert_eq!(stub.app.get_type_pokemon_count(), Some(1));
+        assert_eq!(stub.app.get_type_moves_count(), Some(1));
+        assert!(stub.app.has_current_item());
+        assert!(matches!(stub.app.selected_part, SelectedPart::Main));
+
+        stub.app.on_escape();
+        assert!(stub.app.current_type.is_none());
+        assert!(matches!(stub.app.selected_part, SelectedPart::List));
+    }
+
+    #[tokio::test]
+    async fn app_on_type_pokemon_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.on_list_mode_switch();
+        stub.app.on_type_selected();
+        stub.deliver_next_fetch().await;
+
+        stub.app
+            .type_pokemon_state
+            .next(stub.app.get_type_pokemon_count());
+        stub.app.on_type_pokemon_selected();
+        stub.deliver_next_fetch().await;
+
+        assert_eq!(stub.app.list_mode, ListMode::Pokemon);
+        assert_eq!(
+            stub.app
+                .current_pokemon
+                .as_ref()
+                .and_then(|current_pokemon| current_pokemon.pokemon.name.as_deref()),
+            Some("pikachu")
+        );
+        assert!(stub.app.current_type.is_some());
+    }
+
+    #[tokio::test]
+    async fn app_on_type_move_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.on_list_mode_switch();
+        stub.app.on_type_selected();
+        stub.deliver_next_fetch().await;
+
+        stub.app
+            .type_moves_state
+            .next(stub.app.get_type_moves_count());
+        stub.app.on_type_move_selected();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+
+        let current_move = stub.app.current_move.as_ref().unwrap();
+        assert_eq!(current_move.get_renderable_name(), "\u{A0}Thunder Shock");
+        assert_eq!(current_move.machines.len(), 1);
+        assert_eq!(
+            current_move
+                .contest_effect
+                .as_ref()
+                .and_then(|contest_effect| contest_effect.appeal),
+            Some(4)
+        );
+        assert_eq!(stub.app.get_move_learners_count(), Some(1));
+
+        stub.app.on_escape();
+        assert!(stub.app.current_move.is_none());
+        assert!(stub.app.current_type.is_some());
+    }
+
+    #[tokio::test]
+    async fn app_on_move_learner_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+
+        stub.app.pokemon_moves_list_state.next(Some(1));
+        stub.app.on_pokemon_move_selected();
+        stub.deliver_next_fetch().await;
+        assert!(stub.app.current_move.is_some());
+        assert!(stub.app.has_current_item());
+
+        stub.app
+            .move_learners_state
+            .next(stub.app.get_move_learners_count());
+        stub.app.on_move_learner_selected();
+        assert!(stub.app.current_move.is_none());
+        stub.deliver_next_fetch().await;
+
+        assert_eq!(
+            stub.app
+                .current_pokemon
+                .as_ref()
+                .and_then(|current_pokemon| current_pokemon.pokemon.name.as_deref()),
+            Some("pikachu")
+        );
+    }
+
+    #[tokio::test]
+    async fn app_on_pokemon_ability_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+
+        stub.app.on_basic_info_focus_switch();
+        stub.app.on_basic_info_focus_switch();
+        assert!(matches!(
+            stub.app.basic_info_focus,
+            BasicInfoFocus::Abilities
+        ));
+        let abilities_count = stub.app.get_pokemon_abilities_count();
+        stub.app.pokemon_abilities_state.next(abilities_count);
+        stub.app.on_pokemon_ability_selected();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+
+        let current_ability = stub.app.current_ability.as_ref().unwrap();
+        assert_eq!(current_ability.name.as_deref(), Some("static"));
+        assert_eq!(stub.app.get_ability_pokemon_count(), Some(2));
+        assert!(stub.app.has_detail_page());
+
+        let ability_pokemon_count = stub.app.get_ability_pokemon_count();
+        stub.app.ability_pokemon_state.next(ability_pokemon_count);
+        stub.app.ability_pokemon_state.next(ability_pokemon_count);
+        stub.app.on_ability_pokemon_selected();
+        assert!(stub.app.current_ability.is_none());
+        stub.deliver_next_fetch().await;
+
+        assert_eq!(
+            stub.app
+                .current_pokemon
+                .as_ref()
+                .and_then(|current_pokemon| current_pokemon.pokemon.name.as_deref()),
+            Some("pichu")
+        );
+    }
+
+    #[tokio::test]
+    async fn app_on_item_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.on_list_mode_switch();
+        stub.app.on_list_mode_switch();
+        assert_eq!(stub.app.list_mode, ListMode::Items);
+
+        stub.app.on_list_item_selected();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+
+        let current_item = stub.app.current_item.as_ref().unwrap();
+        assert_eq!(current_item.item.name.as_deref(), Some("light-ball"));
+        assert!(current_item.machines.is_empty());
+        assert_eq!(stub.app.get_item_holders_count(), Some(1));
+        assert!(stub.app.has_current_item());
+
+        stub.app.on_escape();
+        assert!(stub.app.current_item.is_none());
+        assert!(matches!(stub.app.selected_part, SelectedPart::List));
+    }
+
+    #[tokio::test]
+    async fn app_on_pokemon_held_item_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+
+        let held_items_count = stub.app.get_pokemon_held_items_count();
+        assert_eq!(held_items_count, Some(1));
+        stub.app.pokemon_held_items_state.next(held_items_count);
+        stub.app.on_pokemon_held_item_selected();
+        assert_eq!(stub.app.list_mode, ListMode::Items);
+        stub.deliver_next_fetch().await;
+        assert!(stub.app.current_item.is_some());
+
+        let item_holders_count = stub.app.get_item_holders_count();
+        stub.app.item_holders_state.next(item_holders_count);
+        stub.app.on_item_holder_selected();
+        assert_eq!(stub.app.list_mode, ListMode::Pokemon);
+        stub.deliver_next_fetch().await;
+
+        assert_eq!(
+            stub.app
+                .current_pokemon
+                .as_ref()
+                .and_then(|current_pokemon| current_pokemon.pokemon.name.as_deref()),
+            Some("pikachu")
+        );
+        assert!(stub.app.current_item.is_some());
+    }
+
+    #[tokio::test]
+    async fn app_on_region_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.set_list_mode(ListMode::Locations);
+        assert_eq!(stub.app.get_active_list().items_to_render.len(), 2);
+
+        stub.app.search = String::from("kan");
+        stub.app.filter_list();
+        stub.app.on_list_item_selected();
+        stub.deliver_next_fetch().await;
+
+        assert_eq!(
+            stub.app
+                .current_region
+                .as_ref()
+                .map(|region| region.get_name_or_stub()),
+            Some(String::from("kanto"))
+        );
+        assert!(stub.app.search.is_empty());
+        assert_eq!(
+            stub.app
+                .get_active_list()
+                .items_to_render
+                .iter()
+                .map(|location| location.get_name_or_stub())
+                .collect::<Vec<String>>(),
+            vec!["pallet-town", "viridian-forest"]
+        );
+
+        stub.app.on_escape();
+        assert!(stub.app.current_region.is_none());
+        assert_eq!(stub.app.get_active_list().items_to_render.len(), 2);
+        assert!(stub.app.location_list.items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn app_on_region_selected_failure_retries() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.set_list_mode(ListMode::Locations);
+        stub.app.region_list.next();
+        stub.app.on_list_item_selected();
+        stub.deliver_next_fetch().await;
+
+        assert!(stub.app.current_region.is_none());
+        assert!(matches!(
+            stub.app.failed_fetch.as_ref().map(|failed_fetch| &failed_fetch.retry),
+            Some(RetryAction::RegionSelection(region)) if region.get_name_or_stub() == "johto"
+        ));
+    }
+
+    #[tokio::test]
+    async fn app_on_location_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.set_list_mode(ListMode::Locations);
+        stub.app.on_list_item_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.location_list.next();
+        stub.app.on_list_item_selected();
+        stub.deliver_next_fetch().await;
+
+        let current_location = stub.app.current_location.as_ref().unwrap();
+        assert_eq!(current_location.get_area_index(), Some(0));
+        assert_eq!(stub.app.get_location_encounters_count(), Some(1));
+        assert!(stub.app.has_current_item());
+
+        stub.app.on_location_focus_switch();
+        let encounters_count = stub.app.get_location_encounters_count();
+        stub.app.location_encounters_state.next(encounters_count);
+        stub.app.on_location_encounter_selected();
+        assert_eq!(stub.app.list_mode, ListMode::Pokemon);
+        stub.deliver_next_fetch().await;
+
+        assert_eq!(
+            stub.app
+                .current_pokemon
+                .as_ref()
+                .and_then(|current_pokemon| current_pokemon.pokemon.name.as_deref()),
+            Some("pikachu")
+        );
+    }
+
+    #[tokio::test]
+    async fn app_on_pokemon_encounter_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+
+        for _ in 0..4 {
+            stub.app.on_basic_info_focus_switch();
+        }
+        assert!(matches!(
+            stub.app.basic_info_focus,
+            BasicInfoFocus::Encounters
+        ));
+
+        let encounters_count = stub.app.get_pokemon_encounters_count();
+        assert_eq!(encounters_count, Some(1));
+        stub.app.pokemon_encounters_state.next(encounters_count);
+        stub.app.on_pokemon_encounter_selected();
+        assert_eq!(stub.app.list_mode, ListMode::Locations);
+        stub.deliver_next_fetch().await;
+
+        let current_location = stub.app.current_location.as_ref().unwrap();
+        assert_eq!(
+            current_location.location.name.as_deref(),
+            Some("viridian-forest")
+        );
+        assert_eq!(current_location.get_area_index(), Some(0));
+        assert_eq!(stub.app.get_location_areas_count(), Some(1));
+
+        stub.app.on_escape();
+        assert!(stub.app.current_location.is_none());
+    }
+
+    #[tokio::test]
+    async fn app_on_pokemon_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+
+        stub.app.on_pokemon_selected();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+
+        let app = stub.app;
+        let current_pokemon = app.current_pokemon.as_ref().unwrap();
+        assert_eq!(current_pokemon.pokemon.name.as_deref(), Some("pikachu"));
+        assert_eq!(current_pokemon.species.capture_rate, Some(190));
+        assert_eq!(current_pokemon.encounters.len(), 1);
+        assert_eq!(current_pokemon.get_evolution_stages().len(), 2);
+        assert_eq!(current_pokemon.types.len(), 1);
+        assert!(!app.loading);
+        assert!(matches!(app.selected_part, SelectedPart::Main));
+        assert!(matches!(
+            app.current_main_page_state,
+            CurrentMainPageState::VersionGroupSelection
+        ));
+    }
+
+    #[tokio::test]
+    async fn app_on_sprite_variant_switch() {
+        let front_sprite_url =
+            "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon/1.png";
+        let back_sprite_url =
+            "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon/back/1.png";
+        let source = get_stub_source()
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon/1/",
+                json!({
+                    "id": 1,
+                    "name": "bulbasaur",
+                    "species": { "name": "bulbasaur", "url": "https://pokeapi.co/api/v2/pokemon-species/1/" },
+                    "sprites": { "front_default": front_sprite_url, "back_default": back_sprite_url }
+                }),
+            )
+            .with_response("https://pokeapi.co/api/v2/pokemon-species/1/", json!({}))
+            .with_response("https://pokeapi.co/api/v2/pokemon/1/encounters", json!([]))
+            .with_bytes(
+                front_sprite_url,
+                get_stub_png(2, 1, &[[0, 255, 0, 255], [0, 0, 0, 0]]),
+            );
+        let mut stub = StubApp::new(source);
+        stub.app.init();
+        stub.deliver_next_fetch().await;
+
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        assert!(stub.app.is_sprite_loading());
+        stub.deliver_next_fetch().await;
+
+        assert!(!stub.app.is_sprite_loading());
+        assert_eq!(
+            stub.app
+                .sprite
+                .as_ref()
+                .map(|sprite| (sprite.width, sprite.height)),
+            Some((1, 1))
+        );
+
+        stub.app.on_sprite_variant_switch();
+        assert_eq!(stub.app.sprite_variant, SpriteVariant::BackDefault);
+        assert!(stub.app.sprite.is_none());
+        stub.deliver_next_fetch().await;
+
+        // The back sprite is missing from the fixture, which isn't reported as a failure
+        assert!(stub.app.sprite.is_none());
+        assert!(!stub.app.is_sprite_loading());
+        assert!(stub.app.failed_fetch.is_none());
+
+        stub.app.on_sprite_variant_switch();
+        assert_eq!(stub.app.sprite_variant, SpriteVariant::FrontDefault);
+
+        stub.app.reset_current_pokemon();
+        assert!(!stub.app.is_sprite_loading());
+    }
+
+    #[tokio::test]
+    async fn app_on_pokemon_selected_discards_stale_response() {
+        let mut stub = StubApp::initialized().await;
+
+        stub.app.on_pokemon_selected();
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+
+        let app = stub.app;
+        assert!(app.failed_fetch.is_none());
+        assert_eq!(
+            app.current_pokemon
+                .as_ref()
+                .and_then(|current_pokemon| current_pokemon.pokemon.name.as_deref()),
+            Some("pikachu")
+        );
+    }
+
+    #[tokio::test]
+    async fn app_on_escape_cancels_pending_fetch() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+
+        stub.app.on_pokemon_selected();
+        stub.app.on_escape();
+
+        assert!(!stub.app.loading);
+        tokio::task::yield_now().await;
+        assert!(stub.events.try_recv().is_err());
+        assert!(stub.app.current_pokemon.is_none());
+        assert!(matches!(stub.app.selected_part, SelectedPart::List));
+    }
+
+    #[tokio::test]
+    async fn app_on_escape_keeps_init_fetch() {
+        let mut stub = StubApp::new(get_stub_source());
+        stub.app.init();
+        assert!(stub.app.is_lists_loading());
+
+        stub.app.on_escape();
+        assert!(stub.app.is_lists_loading());
+        stub.deliver_next_fetch().await;
+
+        assert!(!stub.app.is_lists_loading());
+        assert_eq!(stub.app.pokemon_list.items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn app_on_version_group_selected() {
+        let StubApp { mut app, .. } = StubApp::initialized().await;
+        app.version_groups.next();
+
+        app.on_version_group_selected();
+
+        assert_eq!(
+            app.selected_version_group
+                .as_ref()
+                .and_then(|version_group| version_group.name.as_deref()),
+            Some("x-y")
+        );
+        assert!(app
+            .selected_version_group
+            .as_ref()
+            .is_some_and(|version_group| version_group.has_version("y")));
+        assert!(matches!(
+            app.current_main_page_state,
+            CurrentMainPageState::BasicInfo
+        ));
+    }
+
+    #[tokio::test]
+    async fn app_on_moves_and_abilities_open() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+
+        stub.app.on_moves_and_abilities_open();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+
+        let app = stub.app;
+        let current_pokemon = app.current_pokemon.as_ref().unwrap();
+        assert_eq!(current_pokemon.abilities.len(), 1);
+        assert_eq!(current_pokemon.moves.len(), 1);
+        assert_eq!(current_pokemon.get_renderable_moves("x-y").len(), 1);
+        assert!(!app.loading);
+    }
+
+    #[tokio::test]
+    async fn app_on_evolution_stage_selected() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+
+        stub.app.on_basic_info_focus_switch();
+        assert!(matches!(
+            stub.app.basic_info_focus,
+            BasicInfoFocus::EvolutionChain
+        ));
+        let evolution_stages_count = stub.app.get_evolution_stages_count();
+        stub.app.evolution_chain_state.next(evolution_stages_count);
+        stub.app.on_evolution_stage_selected();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+
+        let current_pokemon = stub.app.current_pokemon.as_ref().unwrap();
+        assert_eq!(current_pokemon.pokemon.name.as_deref(), Some("pichu"));
+        assert!(matches!(
+            stub.app.current_main_page_state,
+            CurrentMainPageState::BasicInfo
+        ));
+        assert!(matches!(stub.app.basic_info_focus, BasicInfoFocus::Moves));
+        assert_eq!(
+            stub.app
+                .selected_version_group
+                .as_ref()
+                .and_then(|version_group| version_group.name.as_deref()),
+            Some("x-y")
+        );
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+        assert!(!stub.app.loading);
+    }
+
+    #[tokio::test]
+    async fn app_on_pokemon_form_selected_switches_variety() {
+        let source = get_stub_source()
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon-species/25/",
+                json!({
+                    "capture_rate": 190,
+                    "varieties": [
+                        {
+                            "is_default": true,
+                            "pokemon": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" }
+                        },
+                        {
+                            "is_default": false,
+                            "pokemon": { "name": "pikachu-gmax", "url": "https://pokeapi.co/api/v2/pokemon/10199/" }
+                        }
+                    ]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon/10199/",
+                json!({
+                    "id": 10199,
+                    "name": "pikachu-gmax",
+                    "species": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon-species/25/" }
+                }),
+            )
+            .with_response("https://pokeapi.co/api/v2/pokemon/10199/encounters", json!([]));
+        let mut stub = StubApp::new(source);
+        stub.app.init();
+        stub.deliver_next_fetch().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+
+        for _ in 0..5 {
+            stub.app.on_basic_info_focus_switch();
+        }
+        assert!(matches!(stub.app.basic_info_focus, BasicInfoFocus::Forms));
+        let forms_count = stub.app.get_pokemon_forms_count();
+        assert_eq!(forms_count, Some(2));
+        stub.app.pokemon_forms_state.next(forms_count);
+        stub.app.pokemon_forms_state.next(forms_count);
+        stub.app.on_pokemon_form_selected();
+        stub.deliver_next_fetch().await;
+
+        assert_eq!(
+            stub.app
+                .current_pokemon
+                .as_ref()
+                .and_then(|current_pokemon| current_pokemon.pokemon.name.as_deref()),
+            Some("pikachu-gmax")
+        );
+        assert!(matches!(
+            stub.app.current_main_page_state,
+            CurrentMainPageState::BasicInfo
+        ));
+        assert!(stub.app.selected_version_group.is_some());
+    }
+
+    #[tokio::test]
+    async fn app_on_pokemon_form_selected_applies_form() {
+        let source = get_stub_source()
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon/172/",
+                json!({
+                    "id": 172,
+                    "name": "pichu",
+                    "forms": [
+                        { "name": "pichu", "url": "https://pokeapi.co/api/v2/pokemon-form/172/" },
+                        { "name": "pichu-spiky-eared", "url": "https://pokeapi.co/api/v2/pokemon-form/10077/" }
+                    ],
+                    "species": { "name": "pichu", "url": "https://pokeapi.co/api/v2/pokemon-species/172/" },
+                    "types": [{
+                        "slot": 1,
+                        "type": { "name": "electric", "url": "https://pokeapi.co/api/v2/type/13/" }
+                    }]
+                }),
+            )
+            .with_response(
+                "https://pokeapi.co/api/v2/pokemon-form/10077/",
+                json!({
+                    "id": 10077,
+                    "name": "pichu-spiky-eared",
+                    "form_name": "spiky-eared",
+                    "is_default": false,
+                    "types": [{
+                        "slot": 1,
+                        "type": { "name": "electric", "url": "https://pokeapi.co/api/v2/type/13/" }
+                    }]
+                }),
+            );
+        let mut stub = StubApp::new(source);
+        stub.app.init();
+        stub.deliver_next_fetch().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+        stub.app.on_basic_info_focus_switch();
+        let evolution_stages_count = stub.app.get_evolution_stages_count();
+        stub.app.evolution_chain_state.next(evolution_stages_count);
+        stub.app.on_evolution_stage_selected();
+        stub.deliver_next_fetch().await;
+        stub.deliver_next_fetch().await;
+
+        let forms_count = stub.app.get_pokemon_forms_count();
+        assert_eq!(forms_count, Some(2));
+        stub.app.pokemon_forms_state.next(forms_count);
+        stub.app.pokemon_forms_state.next(forms_count);
+        stub.app.on_pokemon_form_selected();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+
+        let current_pokemon = stub.app.current_pokemon.as_ref().unwrap();
+        assert_eq!(current_pokemon.pokemon.name.as_deref(), Some("pichu"));
+        let form = current_pokemon.form.as_ref().unwrap();
+        assert_eq!(form.form.name.as_deref(), Some("pichu-spiky-eared"));
+        assert_eq!(form.types.len(), 1);
+        assert!(stub.app.failed_fetch.is_none());
+    }
+
+    #[tokio::test]
+    async fn app_on_stat_calculator_open() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+
+        stub.app.on_stat_calculator_open();
+        assert!(matches!(
+            stub.app.current_main_page_state,
+            CurrentMainPageState::StatCalculator
+        ));
+        assert_eq!(stub.app.stat_calculator_state.0.selected(), Some(0));
+
+        stub.app.on_stat_change(-1);
+        stub.app.on_stat_calculator_focus_switch();
+        stub.app.on_stat_change(1);
+        stub.app.on_level_change(60);
+        stub.app.on_nature_switch();
+        assert_eq!(stub.app.stat_spread.ivs[0], 30);
+        assert_eq!(stub.app.stat_spread.evs[0], 4);
+        assert_eq!(stub.app.stat_spread.level, 100);
+        assert_eq!(stub.app.stat_spread.nature, Nature::Lonely);
+
+        stub.app.on_escape();
+        assert!(matches!(
+            stub.app.current_main_page_state,
+            CurrentMainPageState::BasicInfo
+        ));
+        assert!(stub.app.current_pokemon.is_some());
+    }
+
+    #[tokio::test]
+    async fn app_on_comparison_open() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+
+        stub.app.on_pokemon_mark();
+        assert!(stub.app.is_marked("pikachu"));
+        stub.app.on_comparison_open();
+        assert!(!stub.app.loading);
+
+        stub.app.on_basic_info_focus_switch();
+        let evolution_stages_count = stub.app.get_evolution_stages_count();
+        stub.app.evolution_chain_state.next(evolution_stages_count);
+        stub.app.on_evolution_stage_selected();
+        stub.deliver_next_fetch().await;
+        stub.deliver_next_fetch().await;
+        stub.app.on_pokemon_mark();
+        assert_eq!(stub.app.marked_pokemon.len(), 2);
+
+        stub.app.on_comparison_open();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+        assert!(matches!(
+            stub.app.current_main_page_state,
+            CurrentMainPageState::Comparison
+        ));
+        let compared_names: Vec<Option<&str>> = stub
+            .app
+            .comparison
+            .as_ref()
+            .unwrap()
+            .pokemon
+            .iter()
+            .map(|pokemon| pokemon.pokemon.name.as_deref())
+            .collect();
+        assert_eq!(compared_names, vec![Some("pikachu"), Some("pichu")]);
+        assert_eq!(stub.app.get_comparison_moves_count(), Some(1));
+
+        stub.app.on_escape();
+        assert!(matches!(
+            stub.app.current_main_page_state,
+            CurrentMainPageState::BasicInfo
+        ));
+        assert!(stub.app.comparison.is_none());
+
+        stub.app.on_pokemon_mark();
+        assert!(!stub.app.is_marked("pichu"));
+        assert_eq!(stub.app.marked_pokemon.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn app_on_damage_calculator_open() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+        stub.app.pokemon_moves_list_state.next(Some(1));
+
+        // The Pokemon can't be its own target
+        stub.app.on_pokemon_mark();
+        stub.app.on_damage_calculator_open();
+        assert!(!stub.app.loading);
+
+        stub.app.marked_pokemon.push(NamedApiResource {
+            name: Some(String::from("pichu")),
+            url: Some(String::from("https://pokeapi.co/api/v2/pokemon/172/")),
+        });
+        stub.app.stat_spread.level = 100;
+        stub.app.on_damage_calculator_open();
+        assert!(stub.app.loading);
+        stub.deliver_next_fetch().await;
+        assert!(matches!(
+            stub.app.current_main_page_state,
+            CurrentMainPageState::DamageCalculator
+        ));
+        let damage_calculator = stub.app.damage_calculator.as_ref().unwrap();
+        assert_eq!(damage_calculator.get_defender().name, "pichu");
+        assert_eq!(
+            damage_calculator
+                .get_move()
+                .map(|damage_move| damage_move.name.as_str()),
+            Some("thunder-shock")
+        );
+        assert_eq!(damage_calculator.generation, 6);
+        assert_eq!(damage_calculator.settings.attacker_spread.level, 100);
+
+        // Thunder Shock is special, so the Special Attack EVs are the ones on the table
+        let change_setting = |app: &mut App, index: usize, delta: i32| {
+            app.damage_settings_state.0.select(Some(index));
+            app.on_damage_setting_change(delta);
+        };
+        change_setting(&mut stub.app, 5, 1);
+        change_setting(&mut stub.app, 6, 1);
+        let damage_calculator = stub.app.damage_calculator.as_ref().unwrap();
+        assert_eq!(damage_calculator.settings.attacker_spread.evs[3], 4);
+        assert_eq!(damage_calculator.settings.attacker_stage, 1);
+
+        // Picking the defender as the attacker swaps the two and starts the attacker over
+        change_setting(&mut stub.app, 0, 1);
+        let damage_calculator = stub.app.damage_calculator.as_ref().unwrap();
+        assert_eq!(damage_calculator.get_attacker().name, "pichu");
+        assert_eq!(damage_calculator.get_defender().name, "pikachu");
+        assert_eq!(damage_calculator.settings.attacker_stage, 0);
+        assert_eq!(damage_calculator.settings.defender_spread.level, 100);
+        assert!(damage_calculator.calculate().is_none());
+
+        stub.app.on_escape();
+        assert!(matches!(
+            stub.app.current_main_page_state,
+            CurrentMainPageState::BasicInfo
+        ));
+        assert!(stub.app.damage_calculator.is_none());
+    }
+
+    #[tokio::test]
+    async fn app_team_builder() {
+        let mut stub = StubApp::initialized().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+        stub.app.version_groups.next();
+        stub.app.on_version_group_selected();
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+
+        stub.app.on_team_member_assign();
+        assert!(stub.app.team_status.is_some());
+
+        stub.app.on_team_pokemon_toggle();
+        let team = stub.app.get_current_team().unwrap();
+        assert_eq!(team.name, "team-1");
+        assert_eq!(team.version_group.as_deref(), Some("x-y"));
+        assert_eq!(team.members[0].types, vec![String::from("electric")]);
+        assert_eq!(team.members[0].ability.as_deref(), Some("static"));
+        assert_eq!(stub.app.team_list.items.len(), 1);
+        assert!(stub.app.team_status.is_none());
+
+        stub.app.pokemon_moves_list_state.next(Some(1));
+        stub.app.on_team_member_assign();
+        stub.app.on_stat_calculator_open();
+        stub.app.on_level_change(10);
+        let member = stub.app.get_current_team_member().unwrap();
+        assert_eq!(member.moves[0].name, "thunder-shock");
+        assert_eq!(member.stat_spread.level, 60);
+        stub.app.on_escape();
+
+        for _ in 0..4 {
+            stub.app.on_list_mode_switch();
+        }
+        assert_eq!(stub.app.list_mode, ListMode::Teams);
+        stub.app.on_list_item_selected();
+        assert!(!stub.app.loading);
+        stub.deliver_next_fetch().await;
+        assert_eq!(stub.app.team_types.len(), 1);
+
+        let team_members_count = stub.app.get_team_members_count();
+        stub.app.team_members_state.next(team_members_count);
+        stub.app.on_team_member_selected();
+        stub.deliver_next_fetch().await;
+        assert_eq!(stub.app.list_mode, ListMode::Pokemon);
+        assert!(matches!(
+            stub.app.current_main_page_state,
+            CurrentMainPageState::BasicInfo
+        ));
+
+        stub.app.on_team_member_remove();
+        assert_eq!(stub.app.get_team_members_count(), Some(0));
+        stub.app.on_team_delete();
+        assert!(stub.app.teams.is_empty());
+        assert!(stub.app.team_list.items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn fetcher_fetch_showdown_import() {
+        let stub = StubApp::new(get_stub_source());
+        let sets = parse_team(
+            "=== [gen6ou] Sparks ===\n\nPikachu @ Light Ball\nAbility: Static\n- Thunder Shock\n- Surf",
+        );
+
+        let import = stub
+            .app
+            .fetcher
+            .fetch_showdown_import(sets, Team::new(String::from("team-1")))
+            .await
+            .unwrap();
+
+        let member = &import.team.members[0];
+        assert_eq!(member.item.as_deref(), Some("light-ball"));
+        assert_eq!(member.moves.len(), 1);
+        assert_eq!(member.moves[0].name, "thunder-shock");
+        assert_eq!(member.moves[0].move_type.as_deref(), Some("electric"));
+        assert_eq!(member.moves[0].damage_class.as_deref(), Some("special"));
+        assert_eq!(
+            import.problems,
+            vec![String::from("Pikachu can't learn Surf")]
+        );
+    }
+
+    #[tokio::test]
+    async fn app_team_types_without_type_list() {
+        let source = get_stub_source().with_response(
+            "https://pokeapi.co/api/v2/type?limit=100000&offset=0",
+            json!({
+                "count": 3,
+                "results": [
+                    { "name": "electric", "url": "https://pokeapi.co/api/v2/type/13/" },
+                    { "name": "fire", "url": "https://pokeapi.co/api/v2/type/10/" },
+                    { "name": "unknown", "url": "https://pokeapi.co/api/v2/type/10001/" }
+                ]
+            }),
+        );
+        let mut stub = StubApp::new(source);
+        stub.app.init();
+        assert!(stub.app.type_list.items.is_empty());
+
+        // Teams come from disk, so one can be opened before the lists arrive
+        stub.app.on_team_create();
+        assert!(stub.app.is_lists_loading());
+        stub.deliver_next_fetch().await;
+        stub.deliver_next_fetch().await;
+
+        assert_eq!(stub.app.pokemon_list.items.len(), 2);
+        assert_eq!(stub.app.type_list.items.len(), 2);
+        assert_eq!(stub.app.team_types.len(), 1);
+        let failed_fetch = stub.app.failed_fetch.as_ref().unwrap();
+        assert!(matches!(failed_fetch.retry, RetryAction::TeamTypes));
+        assert!(matches!(
+            &failed_fetch.error,
+            FetchError::Incomplete { failures } if failures.len() == 1
+        ));
+    }
+
+    #[tokio::test]
+    async fn app_on_moves_and_abilities_open_reports_incomplete() {
+        let missing_move = FetchError::Status {
+            uri: String::from("https://pokeapi.co/api/v2/move/84/"),
+            status: 404,
+        };
+        let mut stub = StubApp::new(
+            get_stub_source()
+                .with_error("https://pokeapi.co/api/v2/move/84/", missing_move.clone()),
+        );
+        stub.app.init();
+        stub.deliver_next_fetch().await;
+        stub.app.pokemon_list.next();
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+
+        stub.app.on_moves_and_abilities_open();
+        stub.deliver_next_fetch().await;
+
+        let current_pokemon = stub.app.current_pokemon.as_ref().unwrap();
+        assert_eq!(current_pokemon.abilities.len(), 1);
+        assert!(current_pokemon.moves.is_empty());
+        let failed_fetch = stub.app.failed_fetch.as_ref().unwrap();
+        assert_eq!(
+            failed_fetch.error,
+            FetchError::Incomplete {
+                failures: vec![missing_move],
+            }
+        );
+        assert!(matches!(failed_fetch.retry, RetryAction::MovesAndAbilities));
+
+        stub.app.on_retry();
+        stub.deliver_next_fetch().await;
+
+        let current_pokemon = stub.app.current_pokemon.as_ref().unwrap();
+        assert_eq!(current_pokemon.abilities.len(), 1);
+        assert_eq!(current_pokemon.moves.len(), 1);
+        assert!(stub.app.failed_fetch.is_none());
+    }
+
+    #[tokio::test]
+    async fn app_on_pokemon_selected_reports_failure() {
+        let mut stub = StubApp::initialized().await;
+
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+
+        let failed_fetch = stub.app.failed_fetch.as_ref().unwrap();
+        assert_eq!(
+            failed_fetch.error,
+            FetchError::Status {
+                uri: String::from("https://pokeapi.co/api/v2/pokemon/1/"),
+                status: 404,
+            }
+        );
+        assert!(matches!(
+            &failed_fetch.retry,
+            RetryAction::PokemonSelection(pokemon) if pokemon.get_name_or_stub() == "bulbasaur"
+        ));
+        assert!(stub.app.current_pokemon.is_none());
+        assert!(!stub.app.loading);
+        assert!(matches!(stub.app.selected_part, SelectedPart::List));
+
+        stub.app.pokemon_list.next();
+        stub.app.on_retry();
+        assert!(stub.app.failed_fetch.is_none());
+        stub.deliver_next_fetch().await;
+
+        assert!(stub.app.failed_fetch.is_some());
+    }
+
+    #[tokio::test]
+    async fn app_on_pokemon_selected_reports_missing_species() {
+        let mut stub = StubApp::new(get_stub_source().with_response(
+            "https://pokeapi.co/api/v2/pokemon/1/",
+            json!({ "id": 1, "name": "bulbasaur" }),
+        ));
+        stub.app.init();
+        stub.deliver_next_fetch().await;
+
+        stub.app.on_pokemon_selected();
+        stub.deliver_next_fetch().await;
+
+        assert_eq!(
+            stub.app.failed_fetch.as_ref().unwrap().error,
+            FetchError::MissingField {
+                resource: String::from("bulbasaur"),
+                field: "species",
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn app_init_reports_failure() {
+        let mut stub = StubApp::new(FixtureSource::default());
+        stub.app.init();
+        stub.deliver_next_fetch().await;
+
+        assert!(matches!(
+            stub.app.failed_fetch.as_ref().unwrap().retry,
+            RetryAction::Init
+        ));
+
+        stub.app.dismiss_failed_fetch();
+
+        assert!(stub.app.failed_fetch.is_none());
     }
 }
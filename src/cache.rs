@@ -0,0 +1,307 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime},
+};
+
+use hyper::body::Bytes;
+
+const CACHE_DIR_NAME: &str = "pokerust-tui";
+const DEFAULT_TTL: Duration = Duration::from_secs(60 * 60 * 24 * 7);
+const DEFAULT_MAX_SIZE: u64 = 256 * 1024 * 1024;
+/// Every extension `set` names files with, so `get` knows where to look.
+const EXTENSIONS: [&str; 3] = ["json", "png", "bin"];
+
+/// Response cache stored as one file per URL, which starts with the URL on a line of its own so a
+/// hash collision reads as a miss. Entries older than `ttl` are treated as missing, and the oldest
+/// entries are evicted once the directory grows past `max_size` bytes.
+pub struct DiskCache {
+    dir: PathBuf,
+    ttl: Duration,
+    max_size: u64,
+    /// What's on disk as far as this cache knows, worked out again on every prune.
+    size: AtomicU64,
+}
+
+impl DiskCache {
+    pub fn new(dir: PathBuf, ttl: Duration, max_size: u64) -> Self {
+        let cache = Self {
+            dir,
+            ttl,
+            max_size,
+            size: AtomicU64::new(0),
+        };
+        cache.prune(max_size);
+
+        cache
+    }
+
+    pub fn with_default_location() -> Option<Self> {
+        Self::default_dir().map(|dir| Self::new(dir, DEFAULT_TTL, DEFAULT_MAX_SIZE))
+    }
+
+    pub fn default_dir() -> Option<PathBuf> {
+        env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .map(|cache_home| cache_home.join(CACHE_DIR_NAME))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn get(&self, uri: &str) -> Option<Bytes> {
+        let (path, modified) = EXTENSIONS.iter().find_map(|extension| {
+            let path = self.path_for(uri, extension);
+            let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok()?;
+
+            Some((path, modified))
+        })?;
+
+        if self.is_expired(modified) {
+            return None;
+        }
+
+        let contents = fs::read(path).ok()?;
+        let separator = contents.iter().position(|byte| *byte == b'\n')?;
+        let (stored_uri, bytes) = contents.split_at(separator);
+
+        (stored_uri == uri.as_bytes()).then(|| Bytes::copy_from_slice(&bytes[1..]))
+    }
+
+    /// Named after `content_type`, so sprites don't end up in `.json` files. Once the directory
+    /// goes past the size cap, it's pruned down to three quarters of it, so the next few writes
+    /// don't have to go over the whole directory again.
+    pub fn set(&self, uri: &str, content_type: Option<&str>, bytes: &[u8]) {
+        if fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+
+        let path = self.path_for(uri, get_extension(content_type));
+        let replaced_size = fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0);
+        let contents = [uri.as_bytes(), b"\n", bytes].concat();
+
+        // A failed write only means the next launch goes to the network again
+        if fs::write(path, &contents).is_ok() {
+            let get_new_size =
+                |size: u64| (size + contents.len() as u64).saturating_sub(replaced_size);
+            let size = self
+                .size
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |size| {
+                    Some(get_new_size(size))
+                })
+                .map_or(0, get_new_size);
+
+            if size > self.max_size {
+                self.prune(self.max_size / 4 * 3);
+            }
+        }
+    }
+
+    pub fn clear(&self) -> std::io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    fn is_expired(&self, modified: SystemTime) -> bool {
+        SystemTime::now()
+            .duration_since(modified)
+            .map(|age| age > self.ttl)
+            .unwrap_or(false)
+    }
+
+    fn path_for(&self, uri: &str, extension: &str) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.{}", fnv1a(uri.as_bytes()), extension))
+    }
+
+    /// Drops expired entries, then the oldest ones until what's left fits in `target_size`.
+    fn prune(&self, target_size: u64) {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        let mut files: Vec<(PathBuf, SystemTime, u64)> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let meta = entry.metadata().ok()?;
+                let modified = meta.modified().ok()?;
+
+                meta.is_file().then(|| (entry.path(), modified, meta.len()))
+            })
+            .collect();
+
+        files.retain(|(path, modified, _)| {
+            if self.is_expired(*modified) {
+                let _ = fs::remove_file(path);
+                return false;
+            }
+
+            true
+        });
+
+        let mut total_size: u64 = files.iter().map(|(.., size)| size).sum();
+        files.sort_by_key(|(_, modified, _)| *modified);
+
+        for (path, _, size) in files {
+            if total_size <= target_size {
+                break;
+            }
+
+            if fs::remove_file(path).is_ok() {
+                total_size -= size;
+            }
+        }
+
+        self.size.store(total_size, Ordering::Relaxed);
+    }
+}
+
+/// Responses without a known content type are kept as they are, under `.bin`.
+fn get_extension(content_type: Option<&str>) -> &'static str {
+    let mime_type = content_type
+        .and_then(|content_type| content_type.split(';').next())
+        .map(str::trim);
+
+    match mime_type {
+        Some("application/json") => "json",
+        Some("image/png") => "png",
+        _ => "bin",
+    }
+}
+
+/// Stable across builds and platforms, unlike `DefaultHasher`, so cache file names survive upgrades.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, time::Duration};
+
+    use super::DiskCache;
+
+    fn get_stub_cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("pokerust-tui-cache-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn disk_cache_get_returns_stored_value() {
+        let cache = DiskCache::new(get_stub_cache_dir("get"), Duration::from_secs(60), 1024);
+        cache.set(
+            "https://pokeapi.co/api/v2/pokemon/25/",
+            Some("application/json; charset=utf-8"),
+            b"{\"id\":25}",
+        );
+
+        assert_eq!(
+            cache
+                .get("https://pokeapi.co/api/v2/pokemon/25/")
+                .as_deref(),
+            Some(&b"{\"id\":25}"[..])
+        );
+        assert_eq!(cache.get("https://pokeapi.co/api/v2/pokemon/26/"), None);
+    }
+
+    #[test]
+    fn disk_cache_get_ignores_expired_entries() {
+        let cache = DiskCache::new(get_stub_cache_dir("ttl"), Duration::ZERO, 1024);
+        cache.set("https://pokeapi.co/api/v2/pokemon/25/", None, b"{}");
+        std::thread::sleep(Duration::from_millis(10));
+
+        assert_eq!(cache.get("https://pokeapi.co/api/v2/pokemon/25/"), None);
+    }
+
+    #[test]
+    fn disk_cache_prune_evicts_oldest_entries_over_size_limit() {
+        let dir = get_stub_cache_dir("size");
+        let cache = DiskCache::new(dir.clone(), Duration::from_secs(60), 2048);
+        cache.set("first", None, &[0; 600]);
+        std::thread::sleep(Duration::from_millis(10));
+        cache.set("second", None, &[0; 600]);
+
+        let cache = DiskCache::new(dir, Duration::from_secs(60), 1024);
+
+        assert_eq!(cache.get("first"), None);
+        assert!(cache.get("second").is_some());
+    }
+
+    #[test]
+    fn disk_cache_set_evicts_oldest_entries_over_size_limit() {
+        let cache = DiskCache::new(
+            get_stub_cache_dir("set-size"),
+            Duration::from_secs(60),
+            1024,
+        );
+        cache.set("first", None, &[0; 400]);
+        std::thread::sleep(Duration::from_millis(10));
+        cache.set("second", None, &[0; 400]);
+        assert!(cache.get("first").is_some());
+
+        std::thread::sleep(Duration::from_millis(10));
+        cache.set("third", None, &[0; 400]);
+
+        assert_eq!(cache.get("first"), None);
+        assert_eq!(cache.get("second"), None);
+        assert!(cache.get("third").is_some());
+
+        // Replacing an entry doesn't count it twice
+        cache.set("third", None, &[0; 400]);
+        cache.set("third", None, &[0; 400]);
+        assert!(cache.get("third").is_some());
+    }
+
+    #[test]
+    fn disk_cache_get_ignores_entries_of_other_urls() {
+        let dir = get_stub_cache_dir("collision");
+        let cache = DiskCache::new(dir.clone(), Duration::from_secs(60), 1024);
+        cache.set("first", Some("application/json"), b"{}");
+
+        // Another URL whose name hashes the same would find this file
+        let path = fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+        fs::write(&path, b"second\n{}").unwrap();
+
+        assert_eq!(cache.get("first"), None);
+    }
+
+    #[test]
+    fn disk_cache_set_names_files_by_content_type() {
+        let dir = get_stub_cache_dir("extension");
+        let cache = DiskCache::new(dir.clone(), Duration::from_secs(60), 1024);
+        cache.set("sprite", Some("image/png"), b"\x89PNG");
+        cache.set("pokemon", Some("application/json"), b"{}");
+
+        let mut extensions: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                path.extension().unwrap().to_string_lossy().to_string()
+            })
+            .collect();
+        extensions.sort();
+
+        assert_eq!(extensions, vec!["json", "png"]);
+        assert_eq!(cache.get("sprite").as_deref(), Some(&b"\x89PNG"[..]));
+    }
+
+    #[test]
+    fn disk_cache_clear_removes_everything() {
+        let cache = DiskCache::new(get_stub_cache_dir("clear"), Duration::from_secs(60), 1024);
+        cache.set("first", None, b"{}");
+
+        assert!(cache.clear().is_ok());
+        assert_eq!(cache.get("first"), None);
+        assert!(cache.clear().is_ok());
+    }
+}
@@ -0,0 +1,241 @@
+use std::{
+    env, fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+};
+
+use serde::Deserialize;
+
+use crate::{endpoints::POKEAPI_DEFAULT_URL, source::DEFAULT_MAX_CONCURRENT_REQUESTS};
+
+const CONFIG_DIR_NAME: &str = "pokerust-tui";
+const CONFIG_FILE_NAME: &str = "config.toml";
+const BASE_URL_ENV_VAR: &str = "POKERUST_BASE_URL";
+
+#[derive(Deserialize, Debug, Default)]
+pub struct FileConfig {
+    pub base_url: Option<String>,
+    pub offline: Option<PathBuf>,
+    pub max_concurrent_requests: Option<usize>,
+}
+
+/// Settings resolved from, in increasing priority: built-in defaults, the config file,
+/// environment variables and command line flags.
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub base_url: String,
+    pub offline: Option<PathBuf>,
+    pub max_concurrent_requests: usize,
+    pub clear_cache: bool,
+    /// A Showdown team file to add to the saved teams, instead of starting the app.
+    pub import_team: Option<PathBuf>,
+    /// The name of a saved team to print in the Showdown format, instead of starting the app.
+    pub export_team: Option<String>,
+    /// What an imported team is for, its moves have to be learnable there.
+    pub version_group: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> io::Result<Self> {
+        let file_config = match Self::default_path() {
+            Some(path) => Self::read_file(path)?,
+            None => FileConfig::default(),
+        };
+        let args: Vec<String> = env::args().skip(1).collect();
+
+        Self::from_sources(file_config, env::var(BASE_URL_ENV_VAR).ok(), &args)
+    }
+
+    pub fn default_path() -> Option<PathBuf> {
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .map(|config_home| config_home.join(CONFIG_DIR_NAME).join(CONFIG_FILE_NAME))
+    }
+
+    fn read_file(path: PathBuf) -> io::Result<FileConfig> {
+        match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|err| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid config file {}: {}", path.display(), err),
+                )
+            }),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(FileConfig::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn from_sources(
+        file_config: FileConfig,
+        env_base_url: Option<String>,
+        args: &[String],
+    ) -> io::Result<Self> {
+        let base_url = get_arg_value(args, "--base-url")?
+            .or(env_base_url)
+            .or(file_config.base_url)
+            .unwrap_or_else(|| POKEAPI_DEFAULT_URL.to_string());
+        let offline = get_arg_value(args, "--offline")?
+            .map(PathBuf::from)
+            .or(file_config.offline);
+        let max_concurrent_requests = get_arg_value(args, "--max-concurrent-requests")?
+            .and_then(|value| value.parse().ok())
+            .or(file_config.max_concurrent_requests)
+            .filter(|max_concurrent_requests| *max_concurrent_requests > 0)
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_REQUESTS);
+
+        Ok(Self {
+            base_url,
+            offline,
+            max_concurrent_requests,
+            clear_cache: args.iter().any(|arg| arg == "--clear-cache"),
+            import_team: get_arg_value(args, "--import-team")?.map(PathBuf::from),
+            export_team: get_arg_value(args, "--export-team")?,
+            version_group: get_arg_value(args, "--version-group")?,
+        })
+    }
+}
+
+/// The argument after the flag `name`, when it's given. Another flag there means the value
+/// was left out, rather than being the value.
+fn get_arg_value(args: &[String], name: &str) -> io::Result<Option<String>> {
+    let mut rest = args.iter().skip_while(|arg| *arg != name);
+    if rest.next().is_none() {
+        return Ok(None);
+    }
+
+    match rest.next() {
+        Some(value) if !value.starts_with("--") => Ok(Some(value.to_string())),
+        _ => Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("Missing value for {}", name),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{Config, FileConfig};
+
+    fn get_stub_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn config_from_sources_defaults() {
+        assert_eq!(
+            Config::from_sources(FileConfig::default(), None, &[]).unwrap(),
+            Config {
+                base_url: String::from("https://pokeapi.co/api/v2/"),
+                offline: None,
+                max_concurrent_requests: 8,
+                clear_cache: false,
+                import_team: None,
+                export_team: None,
+                version_group: None,
+            }
+        );
+    }
+
+    #[test]
+    fn config_from_sources_precedence() {
+        let file_config = || FileConfig {
+            base_url: Some(String::from("http://file/api/v2/")),
+            offline: Some(PathBuf::from("/data")),
+            max_concurrent_requests: Some(4),
+        };
+        let env_base_url = || Some(String::from("http://env/api/v2/"));
+
+        assert_eq!(
+            Config::from_sources(file_config(), None, &[])
+                .unwrap()
+                .base_url,
+            "http://file/api/v2/"
+        );
+        assert_eq!(
+            Config::from_sources(file_config(), env_base_url(), &[])
+                .unwrap()
+                .base_url,
+            "http://env/api/v2/"
+        );
+
+        let config = Config::from_sources(
+            file_config(),
+            env_base_url(),
+            &get_stub_args(&["--base-url", "http://cli/api/v2/", "--clear-cache"]),
+        )
+        .unwrap();
+        assert_eq!(config.base_url, "http://cli/api/v2/");
+        assert_eq!(config.max_concurrent_requests, 4);
+        assert_eq!(config.offline, Some(PathBuf::from("/data")));
+        assert!(config.clear_cache);
+    }
+
+    #[test]
+    fn config_from_sources_team_flags() {
+        let config = Config::from_sources(
+            FileConfig::default(),
+            None,
+            &get_stub_args(&["--import-team", "team.txt", "--version-group", "x-y"]),
+        )
+        .unwrap();
+
+        assert_eq!(config.import_team, Some(PathBuf::from("team.txt")));
+        assert_eq!(config.version_group, Some(String::from("x-y")));
+        assert_eq!(config.export_team, None);
+    }
+
+    #[test]
+    fn config_from_sources_flag_without_value() {
+        let config =
+            |args: &[&str]| Config::from_sources(FileConfig::default(), None, &get_stub_args(args));
+
+        assert_eq!(
+            config(&["--offline"]).unwrap_err().to_string(),
+            "Missing value for --offline"
+        );
+        assert_eq!(
+            config(&["--offline", "--base-url", "http://cli/api/v2/"])
+                .unwrap_err()
+                .to_string(),
+            "Missing value for --offline"
+        );
+    }
+
+    #[test]
+    fn config_from_sources_max_concurrent_requests() {
+        let config = |args: &[&str]| {
+            Config::from_sources(FileConfig::default(), None, &get_stub_args(args)).unwrap()
+        };
+
+        assert_eq!(
+            config(&["--max-concurrent-requests", "2"]).max_concurrent_requests,
+            2
+        );
+        assert_eq!(
+            config(&["--max-concurrent-requests", "0"]).max_concurrent_requests,
+            8
+        );
+        assert_eq!(
+            config(&["--max-concurrent-requests", "many"]).max_concurrent_requests,
+            8
+        );
+    }
+
+    #[test]
+    fn file_config_parses_toml() {
+        let file_config: FileConfig =
+            toml::from_str("base_url = \"http://mirror/api/v2/\"\noffline = \"/data\"\nmax_concurrent_requests = 4")
+                .unwrap();
+
+        assert_eq!(
+            file_config.base_url,
+            Some(String::from("http://mirror/api/v2/"))
+        );
+        assert_eq!(file_config.offline, Some(PathBuf::from("/data")));
+        assert_eq!(file_config.max_concurrent_requests, Some(4));
+    }
+}
@@ -0,0 +1,101 @@
+pub const POKEAPI_DEFAULT_URL: &str = "https://pokeapi.co/api/v2/";
+const DEFAULT_LIST_QUERY_PARAMS: &str = "?limit=100000&offset=0";
+
+/// Single place where PokéAPI URLs are built, so a mirror or a mock server only needs a base URL.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Endpoints {
+    base_url: String,
+}
+
+impl Endpoints {
+    pub fn new(base_url: &str) -> Self {
+        let base_url = if base_url.ends_with('/') {
+            base_url.to_string()
+        } else {
+            format!("{}/", base_url)
+        };
+
+        Self { base_url }
+    }
+
+    pub fn list(&self, list_name: &str) -> String {
+        format!(
+            "{}{}{}",
+            self.base_url, list_name, DEFAULT_LIST_QUERY_PARAMS
+        )
+    }
+
+    pub fn pokemon(&self, pokemon_id: i32) -> String {
+        format!("{}pokemon/{}/", self.base_url, pokemon_id)
+    }
+
+    pub fn pokemon_encounters(&self, pokemon_id: i32) -> String {
+        format!("{}pokemon/{}/encounters", self.base_url, pokemon_id)
+    }
+
+    /// PokéAPI embeds absolute links to its public host in every payload. Those are rewritten
+    /// onto the configured base URL so following them never leaves the mirror.
+    pub fn resolve(&self, url: &str) -> String {
+        match url.strip_prefix(POKEAPI_DEFAULT_URL) {
+            Some(path) => format!("{}{}", self.base_url, path),
+            None => url.to_string(),
+        }
+    }
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self::new(POKEAPI_DEFAULT_URL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Endpoints;
+
+    #[test]
+    fn endpoints_new_appends_trailing_slash() {
+        assert_eq!(
+            Endpoints::new("http://localhost:8000/api/v2"),
+            Endpoints::new("http://localhost:8000/api/v2/")
+        );
+    }
+
+    #[test]
+    fn endpoints_list() {
+        assert_eq!(
+            Endpoints::default().list("pokemon"),
+            "https://pokeapi.co/api/v2/pokemon?limit=100000&offset=0"
+        );
+    }
+
+    #[test]
+    fn endpoints_pokemon() {
+        assert_eq!(
+            Endpoints::new("http://mirror/api/v2").pokemon(133),
+            "http://mirror/api/v2/pokemon/133/"
+        );
+    }
+
+    #[test]
+    fn endpoints_pokemon_encounters() {
+        assert_eq!(
+            Endpoints::new("http://mirror/api/v2").pokemon_encounters(25),
+            "http://mirror/api/v2/pokemon/25/encounters"
+        );
+    }
+
+    #[test]
+    fn endpoints_resolve() {
+        let endpoints = Endpoints::new("http://mirror/api/v2/");
+
+        assert_eq!(
+            endpoints.resolve("https://pokeapi.co/api/v2/pokemon-species/25/"),
+            "http://mirror/api/v2/pokemon-species/25/"
+        );
+        assert_eq!(
+            endpoints.resolve("http://elsewhere/api/v2/move/1/"),
+            "http://elsewhere/api/v2/move/1/"
+        );
+    }
+}
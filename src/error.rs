@@ -0,0 +1,145 @@
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+pub type FetchResult<T> = Result<T, FetchError>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchError {
+    /// The request never produced a response: bad URL, DNS, TLS, connection reset, I/O.
+    Network { uri: String, message: String },
+    /// A response arrived, but not with `200 OK`.
+    Status { uri: String, status: u16 },
+    /// `429 Too Many Requests`, with the delay the server asked for in `Retry-After`.
+    RateLimited {
+        uri: String,
+        retry_after: Option<Duration>,
+    },
+    /// The body wasn't the JSON shape the model expects.
+    Decode { uri: String, message: String },
+    /// The payload decoded, but a field the app can't work without is absent.
+    MissingField {
+        resource: String,
+        field: &'static str,
+    },
+    /// Some resources of a batch were fetched, these ones weren't.
+    Incomplete { failures: Vec<FetchError> },
+}
+
+impl FetchError {
+    /// Whether trying the same request again later has a chance of succeeding.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            FetchError::Network { .. } | FetchError::RateLimited { .. } => true,
+            FetchError::Status { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            FetchError::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Network { uri, message } => {
+                write!(f, "Could not reach {}: {}", uri, message)
+            }
+            FetchError::Status { uri, status } => {
+                write!(f, "{} responded with HTTP {}", uri, status)
+            }
+            FetchError::RateLimited { uri, .. } => {
+                write!(f, "{} is rate limiting requests, try again later", uri)
+            }
+            FetchError::Decode { uri, message } => {
+                write!(f, "Unexpected data from {}: {}", uri, message)
+            }
+            FetchError::MissingField { resource, field } => {
+                write!(f, "{} has no {}", resource, field)
+            }
+            FetchError::Incomplete { failures } => match failures.first() {
+                Some(first) if failures.len() > 1 => write!(
+                    f,
+                    "{} resources could not be loaded, the first one: {}",
+                    failures.len(),
+                    first
+                ),
+                Some(first) => write!(f, "{}", first),
+                None => write!(f, "Nothing failed"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::FetchError;
+
+    #[test]
+    fn fetch_error_display() {
+        assert_eq!(
+            FetchError::Status {
+                uri: String::from("https://pokeapi.co/api/v2/pokemon/25/"),
+                status: 502,
+            }
+            .to_string(),
+            "https://pokeapi.co/api/v2/pokemon/25/ responded with HTTP 502"
+        );
+        assert_eq!(
+            FetchError::MissingField {
+                resource: String::from("pikachu"),
+                field: "species",
+            }
+            .to_string(),
+            "pikachu has no species"
+        );
+        assert_eq!(
+            FetchError::Incomplete {
+                failures: vec![
+                    FetchError::Status {
+                        uri: String::from("https://pokeapi.co/api/v2/move/1/"),
+                        status: 404,
+                    },
+                    FetchError::Status {
+                        uri: String::from("https://pokeapi.co/api/v2/move/2/"),
+                        status: 404,
+                    },
+                ],
+            }
+            .to_string(),
+            "2 resources could not be loaded, the first one: https://pokeapi.co/api/v2/move/1/ responded with HTTP 404"
+        );
+    }
+
+    #[test]
+    fn fetch_error_is_transient() {
+        let status = |status| FetchError::Status {
+            uri: String::from("https://pokeapi.co/api/v2/pokemon/25/"),
+            status,
+        };
+        let rate_limited = FetchError::RateLimited {
+            uri: String::from("https://pokeapi.co/api/v2/pokemon/25/"),
+            retry_after: Some(Duration::from_secs(2)),
+        };
+
+        assert!(status(503).is_transient());
+        assert!(!status(404).is_transient());
+        assert!(rate_limited.is_transient());
+        assert_eq!(rate_limited.retry_after(), Some(Duration::from_secs(2)));
+        assert!(!FetchError::MissingField {
+            resource: String::from("pikachu"),
+            field: "species",
+        }
+        .is_transient());
+    }
+}
@@ -0,0 +1,85 @@
+use std::thread;
+
+use crossterm::event::{self, Event};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    error::FetchResult,
+    fetcher::ResourceLists,
+    models::{
+        DamageCalculator, ExtendedItemInfo, ExtendedLocationInfo, ExtendedMoveInfo,
+        ExtendedPokemonForm, ExtendedPokemonInfo, NamedApiResource, PokemonAbilityExt,
+        PokemonComparison, PokemonMoveExt, PokemonTypeExt,
+    },
+    source::FetchedResources,
+    sprite::Sprite,
+};
+
+/// Everything the main loop reacts to. Terminal input and finished background fetches
+/// share one channel, so the UI never blocks on either.
+pub enum AppEvent {
+    Input(Event),
+    Fetched { request_id: u64, data: FetchedData },
+}
+
+pub enum FetchedData {
+    Lists(FetchResult<ResourceLists>),
+    Pokemon {
+        pokemon: NamedApiResource,
+        result: FetchResult<Box<ExtendedPokemonInfo>>,
+    },
+    PokemonForm {
+        form: NamedApiResource,
+        result: FetchResult<Box<ExtendedPokemonForm>>,
+    },
+    Comparison {
+        pokemon: Vec<NamedApiResource>,
+        result: FetchResult<Box<PokemonComparison>>,
+    },
+    DamageCalculator(FetchResult<Box<DamageCalculator>>),
+    TeamTypes(FetchResult<FetchedResources<PokemonTypeExt>>),
+    MovesAndAbilities(
+        FetchedResources<PokemonAbilityExt>,
+        FetchedResources<PokemonMoveExt>,
+    ),
+    Sprite(FetchResult<Sprite>),
+    Type {
+        pokemon_type: NamedApiResource,
+        result: FetchResult<Box<PokemonTypeExt>>,
+    },
+    Move {
+        pokemon_move: NamedApiResource,
+        result: FetchResult<Box<ExtendedMoveInfo>>,
+    },
+    Ability {
+        ability: NamedApiResource,
+        result: FetchResult<Box<PokemonAbilityExt>>,
+    },
+    Item {
+        item: NamedApiResource,
+        result: FetchResult<Box<ExtendedItemInfo>>,
+    },
+    RegionLocations {
+        region: NamedApiResource,
+        result: FetchResult<Vec<NamedApiResource>>,
+    },
+    Location {
+        location: NamedApiResource,
+        result: FetchResult<Box<ExtendedLocationInfo>>,
+    },
+    LocationArea {
+        location_area: NamedApiResource,
+        result: FetchResult<Box<ExtendedLocationInfo>>,
+    },
+}
+
+/// Crossterm only offers a blocking reader, so it gets a thread of its own.
+pub fn spawn_input_listener(events: UnboundedSender<AppEvent>) {
+    thread::spawn(move || {
+        while let Ok(event) = event::read() {
+            if events.send(AppEvent::Input(event)).is_err() {
+                break;
+            }
+        }
+    });
+}
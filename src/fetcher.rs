@@ -0,0 +1,714 @@
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::join;
+
+use crate::{
+    endpoints::Endpoints,
+    error::{FetchError, FetchResult},
+    models::{
+        pokemon_type::ATTACKING_TYPES,
+        showdown::{ShowdownImport, ShowdownSet},
+        DamageCalculator, DamagePokemon, EvolutionChain, ExtendedItemInfo, ExtendedLocationInfo,
+        ExtendedMoveInfo, ExtendedPokemonForm, ExtendedPokemonInfo, Machine, MachineVersionDetail,
+        NamedApiResource, Pokemon, PokemonAbilityExt, PokemonComparison, PokemonMoveExt,
+        PokemonSpecies, PokemonTypeExt, Team, VersionGroup,
+    },
+    source::{fetch_external, FetchPolicy, FetchedResources, PokeApiSource},
+    sprite::Sprite,
+};
+
+/// Everything the list pane can show, loaded once on startup.
+pub struct ResourceLists {
+    pub pokemon: Vec<NamedApiResource>,
+    pub version_groups: Vec<NamedApiResource>,
+    /// The versions of every version group, which their names can't be relied on for.
+    pub version_group_details: Vec<VersionGroup>,
+    pub types: Vec<NamedApiResource>,
+    pub items: Vec<NamedApiResource>,
+    /// Locations are listed by region, see `fetch_region_locations`.
+    pub regions: Vec<NamedApiResource>,
+}
+
+/// Owns everything a background fetch needs, so it can be cloned into a spawned task
+/// without borrowing the `App`.
+#[derive(Clone)]
+pub struct Fetcher {
+    source: Arc<dyn PokeApiSource>,
+    endpoints: Endpoints,
+    policy: FetchPolicy,
+}
+
+impl Fetcher {
+    pub fn new(source: Arc<dyn PokeApiSource>, endpoints: Endpoints, policy: FetchPolicy) -> Self {
+        Self {
+            source,
+            endpoints,
+            policy,
+        }
+    }
+
+    /// Where a resource from a list or another resource can be fetched from.
+    fn resolve_url(&self, resource: &NamedApiResource) -> FetchResult<String> {
+        resource
+            .url
+            .as_ref()
+            .map(|url| self.endpoints.resolve(url))
+            .ok_or_else(|| FetchError::MissingField {
+                resource: resource.get_name_or_stub(),
+                field: "url",
+            })
+    }
+
+    pub async fn fetch_list(&self, list_name: &str) -> FetchResult<Vec<NamedApiResource>> {
+        let uri = self.endpoints.list(list_name);
+        let list_wrapper = self.source.list(&uri).await?;
+
+        Ok(list_wrapper.results.unwrap_or_default())
+    }
+
+    pub async fn fetch_lists(&self) -> FetchResult<ResourceLists> {
+        let (pokemon, version_groups, types, items, regions) = join!(
+            self.fetch_list("pokemon"),
+            self.fetch_list("version-group"),
+            self.fetch_list("type"),
+            self.fetch_list("item"),
+            self.fetch_list("region")
+        );
+
+        let version_groups = version_groups?;
+        let version_group_details = self.fetch_version_groups(&version_groups).await?;
+
+        Ok(ResourceLists {
+            pokemon: pokemon?,
+            version_groups,
+            version_group_details,
+            types: types?,
+            items: items?,
+            regions: regions?,
+        })
+    }
+
+    /// Every screen filters by the selected version group, so a missing one fails them all.
+    async fn fetch_version_groups(
+        &self,
+        version_groups: &[NamedApiResource],
+    ) -> FetchResult<Vec<VersionGroup>> {
+        let version_group_urls: Vec<FetchResult<String>> = version_groups
+            .iter()
+            .map(|version_group| self.resolve_url(version_group))
+            .collect();
+
+        let version_groups = fetch_external(
+            &self.source,
+            version_group_urls.as_slice(),
+            self.policy,
+            |source, url| {
+                let url = url.clone();
+                async move { source.version_group(&url?).await }
+            },
+        )
+        .await;
+
+        if !version_groups.failures.is_empty() {
+            return Err(FetchError::Incomplete {
+                failures: version_groups.failures,
+            });
+        }
+
+        Ok(version_groups.resources)
+    }
+
+    pub async fn fetch_type(&self, pokemon_type: &NamedApiResource) -> FetchResult<PokemonTypeExt> {
+        let uri = self.resolve_url(pokemon_type)?;
+
+        self.source.pokemon_type(&uri).await
+    }
+
+    pub async fn fetch_ability(
+        &self,
+        ability: &NamedApiResource,
+    ) -> FetchResult<PokemonAbilityExt> {
+        let uri = self.resolve_url(ability)?;
+
+        self.source.ability(&uri).await
+    }
+
+    pub async fn fetch_move(
+        &self,
+        pokemon_move: &NamedApiResource,
+    ) -> FetchResult<ExtendedMoveInfo> {
+        let uri = self.resolve_url(pokemon_move)?;
+
+        let move_detail = self.source.move_detail(&uri).await?;
+        let machine_urls = self.get_machine_urls(pokemon_move, move_detail.machines.as_ref());
+        let contest_effect_url = move_detail
+            .contest_effect
+            .as_ref()
+            .map(|contest_effect| self.endpoints.resolve(&contest_effect.url));
+
+        let (machines, contest_effect) = join!(self.fetch_machines(machine_urls), async {
+            match contest_effect_url {
+                Some(contest_effect_url) => self
+                    .source
+                    .contest_effect(&contest_effect_url)
+                    .await
+                    .map(Some),
+                None => Ok(None),
+            }
+        });
+
+        Ok(ExtendedMoveInfo {
+            move_detail,
+            machines: machines?,
+            contest_effect: contest_effect?,
+        })
+    }
+
+    /// The sprite is a nice to have, so the item still loads when it's missing or broken.
+    pub async fn fetch_item(&self, item: &NamedApiResource) -> FetchResult<ExtendedItemInfo> {
+        let uri = self.resolve_url(item)?;
+
+        let extended_item = self.source.item(&uri).await?;
+        let machine_urls = self.get_machine_urls(item, extended_item.machines.as_ref());
+        let sprite_url = extended_item.get_sprite_url().cloned();
+
+        let (machines, sprite) = join!(self.fetch_machines(machine_urls), async {
+            match sprite_url {
+                Some(sprite_url) => self.fetch_sprite(&sprite_url).await.ok(),
+                None => None,
+            }
+        });
+
+        Ok(ExtendedItemInfo {
+            item: extended_item,
+            machines: machines?,
+            sprite,
+        })
+    }
+
+    pub async fn fetch_region_locations(
+        &self,
+        region: &NamedApiResource,
+    ) -> FetchResult<Vec<NamedApiResource>> {
+        let uri = self.resolve_url(region)?;
+
+        let region = self.source.region(&uri).await?;
+
+        Ok(region.locations.unwrap_or_default())
+    }
+
+    /// Opens the location on its first area, which is all most locations have.
+    pub async fn fetch_location(
+        &self,
+        location: &NamedApiResource,
+    ) -> FetchResult<ExtendedLocationInfo> {
+        let uri = self.resolve_url(location)?;
+
+        let location = self.source.location(&uri).await?;
+        let area_url = location
+            .areas
+            .as_ref()
+            .and_then(|areas| areas.first())
+            .and_then(|area| area.url.as_ref())
+            .map(|url| self.endpoints.resolve(url));
+
+        let area = match area_url {
+            Some(area_url) => Some(self.source.location_area(&area_url).await?),
+            None => None,
+        };
+
+        Ok(ExtendedLocationInfo { location, area })
+    }
+
+    /// Encounters point at areas, so the location around them is fetched afterwards.
+    pub async fn fetch_location_area(
+        &self,
+        location_area: &NamedApiResource,
+    ) -> FetchResult<ExtendedLocationInfo> {
+        let uri = self.resolve_url(location_area)?;
+
+        let area = self.source.location_area(&uri).await?;
+        let location_uri = area
+            .location
+            .as_ref()
+            .and_then(|location| location.url.as_ref())
+            .map(|url| self.endpoints.resolve(url))
+            .ok_or_else(|| FetchError::MissingField {
+                resource: location_area.get_name_or_stub(),
+                field: "location",
+            })?;
+
+        let location = self.source.location(&location_uri).await?;
+
+        Ok(ExtendedLocationInfo {
+            location,
+            area: Some(area),
+        })
+    }
+
+    fn get_machine_urls(
+        &self,
+        resource: &NamedApiResource,
+        machines: Option<&Vec<MachineVersionDetail>>,
+    ) -> Vec<FetchResult<String>> {
+        machines
+            .into_iter()
+            .flatten()
+            .map(|machine| {
+                machine
+                    .machine
+                    .as_ref()
+                    .map(|machine| self.endpoints.resolve(&machine.url))
+                    .ok_or_else(|| FetchError::MissingField {
+                        resource: resource.get_name_or_stub(),
+                        field: "machine url",
+                    })
+            })
+            .collect()
+    }
+
+    /// A page with some of its machines missing would look complete, so any failure fails them all.
+    async fn fetch_machines(
+        &self,
+        machine_urls: Vec<FetchResult<String>>,
+    ) -> FetchResult<Vec<Machine>> {
+        let machines = fetch_external(
+            &self.source,
+            machine_urls.as_slice(),
+            self.policy,
+            |source, url| {
+                let url = url.clone();
+                async move { source.machine(&url?).await }
+            },
+        )
+        .await;
+
+        if !machines.failures.is_empty() {
+            return Err(FetchError::Incomplete {
+                failures: machines.failures,
+            });
+        }
+
+        Ok(machines.resources)
+    }
+
+    pub async fn fetch_pokemon_with_info(
+        &self,
+        pokemon: &NamedApiResource,
+    ) -> FetchResult<ExtendedPokemonInfo> {
+        let uri = self.resolve_url(pokemon)?;
+
+        let pokemon = self.source.pokemon(&uri).await?;
+        let missing_field = |field| FetchError::MissingField {
+            resource: pokemon.name.clone().unwrap_or(uri.clone()),
+            field,
+        };
+
+        let species_url = pokemon
+            .species
+            .as_ref()
+            .and_then(|species| species.url.as_ref())
+            .map(|species_url| self.endpoints.resolve(species_url))
+            .ok_or_else(|| missing_field("species"))?;
+        let encounters_url = pokemon
+            .id
+            .map(|id| self.endpoints.pokemon_encounters(id))
+            .ok_or_else(|| missing_field("id"))?;
+        let type_urls: Vec<FetchResult<String>> = pokemon
+            .types
+            .iter()
+            .flatten()
+            .map(|pokemon_type| {
+                pokemon_type
+                    .de_type
+                    .as_ref()
+                    .and_then(|de_type| de_type.url.as_ref())
+                    .map(|type_url| self.endpoints.resolve(type_url))
+                    .ok_or_else(|| missing_field("type url"))
+            })
+            .collect();
+
+        let (species_with_evolution_chain, encounters, types) = join!(
+            self.fetch_species_with_evolution_chain(&species_url),
+            self.source.encounters(&encounters_url),
+            self.fetch_types(type_urls)
+        );
+        let (species, evolution_chain) = species_with_evolution_chain?;
+
+        Ok(ExtendedPokemonInfo {
+            pokemon,
+            abilities: HashMap::new(),
+            moves: HashMap::new(),
+            species,
+            encounters: encounters?,
+            evolution_chain,
+            types: types?,
+            form: None,
+        })
+    }
+
+    /// One Pokemon after the other, each of them already fetches its details side by side.
+    pub async fn fetch_pokemon_comparison(
+        &self,
+        pokemon: &[NamedApiResource],
+    ) -> FetchResult<PokemonComparison> {
+        let mut compared_pokemon = vec![];
+        for pokemon in pokemon {
+            compared_pokemon.push(self.fetch_pokemon_with_info(pokemon).await?);
+        }
+
+        Ok(PokemonComparison {
+            pokemon: compared_pokemon,
+        })
+    }
+
+    /// The page's Pokemon comes loaded already, the other marked ones are fetched one after the
+    /// other with the details of the moves they learn in the version group.
+    pub async fn fetch_damage_calculator(
+        &self,
+        current_pokemon: DamagePokemon,
+        marked_pokemon: Vec<NamedApiResource>,
+        team: Option<Team>,
+        move_name: String,
+        version_group: String,
+        generation: i32,
+    ) -> FetchResult<DamageCalculator> {
+        let mut pokemon = vec![current_pokemon];
+        for marked_pokemon in &marked_pokemon {
+            let mut pokemon_info = self.fetch_pokemon_with_info(marked_pokemon).await?;
+            let move_names = pokemon_info.pokemon.get_move_names(&version_group);
+            let move_urls: Vec<FetchResult<String>> = pokemon_info
+                .pokemon
+                .moves
+                .iter()
+                .flatten()
+                .filter_map(|pokemon_move| pokemon_move.de_move.as_ref())
+                .filter(|de_move| {
+                    de_move
+                        .name
+                        .as_ref()
+                        .is_some_and(|name| move_names.contains(name))
+                })
+                .map(|de_move| self.resolve_url(de_move))
+                .collect();
+            let moves = fetch_external(
+                &self.source,
+                move_urls.as_slice(),
+                self.policy,
+                |source, url| {
+                    let url = url.clone();
+                    async move { source.pokemon_move(&url?).await }
+                },
+            )
+            .await;
+            if !moves.failures.is_empty() {
+                return Err(FetchError::Incomplete {
+                    failures: moves.failures,
+                });
+            }
+            pokemon_info.set_moves(moves.resources);
+
+            let mut damage_pokemon = DamagePokemon::new(&pokemon_info, &version_group, generation);
+            if let Some(member) = team.as_ref().and_then(|team| {
+                team.members
+                    .get(team.get_member_index(&damage_pokemon.name)?)
+            }) {
+                damage_pokemon.set_team_member(member);
+            }
+            pokemon.push(damage_pokemon);
+        }
+
+        Ok(DamageCalculator::new(pokemon, &move_name, generation))
+    }
+
+    /// Every type a team's coverage and weaknesses are worked out against.
+    /// The type list is fetched here rather than taken from the list pane, which may not have
+    /// loaded yet. A type that fails to load only leaves a gap in the coverage.
+    pub async fn fetch_all_types(&self) -> FetchResult<FetchedResources<PokemonTypeExt>> {
+        let type_urls: Vec<FetchResult<String>> = self
+            .fetch_list("type")
+            .await?
+            .iter()
+            .filter(|pokemon_type| {
+                ATTACKING_TYPES.contains(&pokemon_type.get_name_or_stub().as_str())
+            })
+            .map(|pokemon_type| self.resolve_url(pokemon_type))
+            .collect();
+
+        Ok(self.fetch_type_resources(type_urls).await)
+    }
+
+    /// Checks every set of a Showdown team against PokéAPI, leaving out what doesn't exist
+    /// and, when there's a version group, the moves that can't be learned in it.
+    pub async fn fetch_showdown_import(
+        &self,
+        sets: Vec<ShowdownSet>,
+        team: Team,
+    ) -> FetchResult<ShowdownImport> {
+        let (pokemon_list, item_list, version_group_list) = join!(
+            self.fetch_list("pokemon"),
+            self.fetch_list("item"),
+            self.fetch_list("version-group")
+        );
+        let (pokemon_list, item_list) = (pokemon_list?, item_list?);
+        let is_listed = |list: &[NamedApiResource], name: &str| {
+            list.iter()
+                .any(|resource| resource.name.as_deref() == Some(name))
+        };
+        let mut team = team;
+        let mut problems = vec![];
+
+        if let Some(version_group) = team.version_group.clone() {
+            if !is_listed(&version_group_list?, &version_group) {
+                problems.push(format!("There's no version group called {}", version_group));
+                team.version_group = None;
+            }
+        }
+
+        for mut set in sets {
+            // Showdown leaves out the form when it's the default one, like "Giratina" for
+            // what PokéAPI calls "giratina-altered"
+            let default_form = format!("{}-", set.species);
+            let pokemon = pokemon_list
+                .iter()
+                .find(|pokemon| pokemon.name.as_deref() == Some(set.species.as_str()))
+                .or_else(|| {
+                    pokemon_list.iter().find(|pokemon| {
+                        pokemon
+                            .name
+                            .as_ref()
+                            .is_some_and(|name| name.starts_with(&default_form))
+                    })
+                });
+            let resource = match pokemon {
+                Some(pokemon) => pokemon.clone(),
+                None => {
+                    problems.push(format!("There's no Pokemon called {}", set.species));
+                    continue;
+                }
+            };
+
+            if let Some(item) = set.item.take() {
+                match is_listed(&item_list, &item) {
+                    true => set.item = Some(item),
+                    false => problems.push(format!("There's no item called {}", item)),
+                }
+            }
+
+            let uri = self.resolve_url(&resource)?;
+            let pokemon = self.source.pokemon(&uri).await?;
+            // Only the moves the Pokemon learns can end up on the team, and every one of them
+            // needs its type and damage class for the coverage
+            let move_urls: Vec<FetchResult<String>> = pokemon
+                .moves
+                .iter()
+                .flatten()
+                .filter_map(|pokemon_move| pokemon_move.de_move.as_ref())
+                .filter(|de_move| {
+                    de_move
+                        .name
+                        .as_ref()
+                        .is_some_and(|name| set.moves.contains(name))
+                })
+                .map(|de_move| self.resolve_url(de_move))
+                .collect();
+            let moves = fetch_external(
+                &self.source,
+                move_urls.as_slice(),
+                self.policy,
+                |source, url| {
+                    let url = url.clone();
+                    async move { source.pokemon_move(&url?).await }
+                },
+            )
+            .await;
+            if !moves.failures.is_empty() {
+                return Err(FetchError::Incomplete {
+                    failures: moves.failures,
+                });
+            }
+
+            let (member, member_problems) = set.into_member(
+                &pokemon,
+                resource,
+                &moves.resources,
+                team.version_group.as_deref(),
+            );
+            problems.extend(member_problems);
+
+            let member_name = member.get_name();
+            if !team.add_member(member) {
+                problems.push(format!("{} doesn't fit on the team", member_name));
+            }
+        }
+
+        Ok(ShowdownImport { team, problems })
+    }
+
+    /// Forms don't change stats, so only the form and the types it lists are fetched.
+    pub async fn fetch_pokemon_form(
+        &self,
+        form: &NamedApiResource,
+    ) -> FetchResult<ExtendedPokemonForm> {
+        let uri = self.resolve_url(form)?;
+
+        let form = self.source.pokemon_form(&uri).await?;
+        let type_urls: Vec<FetchResult<String>> = form
+            .types
+            .iter()
+            .flatten()
+            .map(|pokemon_type| {
+                pokemon_type
+                    .de_type
+                    .as_ref()
+                    .and_then(|de_type| de_type.url.as_ref())
+                    .map(|type_url| self.endpoints.resolve(type_url))
+                    .ok_or_else(|| FetchError::MissingField {
+                        resource: form.name.clone().unwrap_or(uri.clone()),
+                        field: "type url",
+                    })
+            })
+            .collect();
+        let types = self.fetch_types(type_urls).await?;
+
+        Ok(ExtendedPokemonForm { form, types })
+    }
+
+    /// Matchups are meaningless with one of the types missing, so any failure fails them all.
+    async fn fetch_types(
+        &self,
+        type_urls: Vec<FetchResult<String>>,
+    ) -> FetchResult<Vec<PokemonTypeExt>> {
+        let types = self.fetch_type_resources(type_urls).await;
+
+        if !types.failures.is_empty() {
+            return Err(FetchError::Incomplete {
+                failures: types.failures,
+            });
+        }
+
+        Ok(types.resources)
+    }
+
+    async fn fetch_type_resources(
+        &self,
+        type_urls: Vec<FetchResult<String>>,
+    ) -> FetchedResources<PokemonTypeExt> {
+        fetch_external(
+            &self.source,
+            type_urls.as_slice(),
+            self.policy,
+            |source, url| {
+                let url = url.clone();
+                async move { source.pokemon_type(&url?).await }
+            },
+        )
+        .await
+    }
+
+    /// The chain is only linked from the species, so the two requests can't run side by side.
+    async fn fetch_species_with_evolution_chain(
+        &self,
+        species_url: &str,
+    ) -> FetchResult<(PokemonSpecies, Option<EvolutionChain>)> {
+        let species = self.source.species(species_url).await?;
+        let evolution_chain = match species.evolution_chain.as_ref() {
+            Some(evolution_chain) => Some(
+                self.source
+                    .evolution_chain(&self.endpoints.resolve(&evolution_chain.url))
+                    .await?,
+            ),
+            None => None,
+        };
+
+        Ok((species, evolution_chain))
+    }
+
+    /// Sprites are hosted outside PokéAPI, so their URLs are fetched as they are.
+    pub async fn fetch_sprite(&self, sprite_url: &str) -> FetchResult<Sprite> {
+        let bytes = self.source.get(sprite_url).await?;
+
+        Sprite::decode(&bytes).map_err(|err| FetchError::Decode {
+            uri: sprite_url.to_string(),
+            message: err.to_string(),
+        })
+    }
+
+    /// A species' id is also the id of its default Pokemon.
+    pub fn get_default_pokemon(&self, species: &NamedApiResource) -> Option<NamedApiResource> {
+        Some(NamedApiResource {
+            name: species.name.clone(),
+            url: Some(self.endpoints.pokemon(species.get_id()?)),
+        })
+    }
+
+    /// The resource the list would link to, for a Pokemon that was reached some other way.
+    pub fn get_pokemon_resource(&self, pokemon: &Pokemon) -> Option<NamedApiResource> {
+        Some(NamedApiResource {
+            name: pokemon.name.clone(),
+            url: Some(self.endpoints.pokemon(pokemon.id?)),
+        })
+    }
+
+    /// Collects the URLs up front, so the fetch itself doesn't need to borrow the `Pokemon`.
+    pub fn get_abilities_and_moves_urls(
+        &self,
+        pokemon: &Pokemon,
+    ) -> (Vec<FetchResult<String>>, Vec<FetchResult<String>>) {
+        let pokemon_name = pokemon.name.clone().unwrap_or_default();
+        let fetch_url = |api_resource: Option<&NamedApiResource>, field: &'static str| {
+            api_resource
+                .and_then(|api_resource| api_resource.url.as_ref())
+                .map(|url| self.endpoints.resolve(url))
+                .ok_or_else(|| FetchError::MissingField {
+                    resource: pokemon_name.clone(),
+                    field,
+                })
+        };
+
+        let ability_urls = pokemon
+            .abilities
+            .iter()
+            .flatten()
+            .map(|ability| fetch_url(ability.ability.as_ref(), "ability url"))
+            .collect();
+        let move_urls = pokemon
+            .moves
+            .iter()
+            .flatten()
+            .map(|mv| fetch_url(mv.de_move.as_ref(), "move url"))
+            .collect();
+
+        (ability_urls, move_urls)
+    }
+
+    pub async fn fetch_abilities_and_moves(
+        &self,
+        ability_urls: Vec<FetchResult<String>>,
+        move_urls: Vec<FetchResult<String>>,
+    ) -> (
+        FetchedResources<PokemonAbilityExt>,
+        FetchedResources<PokemonMoveExt>,
+    ) {
+        join!(
+            fetch_external(
+                &self.source,
+                ability_urls.as_slice(),
+                self.policy,
+                |source, url| {
+                    let url = url.clone();
+                    async move { source.ability(&url?).await }
+                }
+            ),
+            fetch_external(
+                &self.source,
+                move_urls.as_slice(),
+                self.policy,
+                |source, url| {
+                    let url = url.clone();
+                    async move { source.pokemon_move(&url?).await }
+                }
+            ),
+        )
+    }
+}
@@ -1,16 +1,24 @@
-use std::{fmt::Debug, sync::Arc};
+use std::time::Duration;
 
+use async_trait::async_trait;
 use hyper::{
     body::{to_bytes, Bytes},
     client::HttpConnector,
+    header::{HeaderValue, CONTENT_TYPE, RETRY_AFTER},
+    http::uri::InvalidUri,
     Client, StatusCode,
 };
 use hyper_tls::HttpsConnector;
-use serde::de::DeserializeOwned;
-use tokio::sync::mpsc::{self, Sender};
+
+use crate::{
+    cache::DiskCache,
+    error::{FetchError, FetchResult},
+    source::PokeApiSource,
+};
 
 pub struct Http {
     client: Client<HttpsConnector<HttpConnector>>,
+    cache: Option<DiskCache>,
 }
 
 impl Http {
@@ -18,81 +26,91 @@ impl Http {
         let https = HttpsConnector::new();
         let client = Client::builder().build::<_, hyper::Body>(https);
 
-        Self { client }
+        Self {
+            client,
+            cache: DiskCache::with_default_location(),
+        }
     }
+}
 
-    async fn get(&self, uri: &str) -> Option<Bytes> {
-        if let Ok(uri) = uri.parse() {
-            let resp = self.client.get(uri).await;
-
-            if let Ok(resp) = resp {
-                if resp.status() == StatusCode::OK {
-                    if let Ok(result) = to_bytes(resp.into_body()).await {
-                        return Some(result);
-                    }
-                }
-            }
+#[async_trait]
+impl PokeApiSource for Http {
+    async fn get(&self, uri: &str) -> FetchResult<Bytes> {
+        if let Some(cached) = self.cache.as_ref().and_then(|cache| cache.get(uri)) {
+            return Ok(cached);
         }
 
-        None
-    }
-
-    pub async fn get_as_object<T>(&self, uri: &str) -> Option<T>
-    where
-        T: DeserializeOwned + Debug,
-    {
-        let bytes = self.get(uri).await;
+        let network_error = |message: String| FetchError::Network {
+            uri: uri.to_string(),
+            message,
+        };
+
+        let parsed_uri = uri
+            .parse()
+            .map_err(|err: InvalidUri| network_error(err.to_string()))?;
+        let resp = self
+            .client
+            .get(parsed_uri)
+            .await
+            .map_err(|err| network_error(err.to_string()))?;
+
+        if resp.status() == StatusCode::TOO_MANY_REQUESTS {
+            return Err(FetchError::RateLimited {
+                uri: uri.to_string(),
+                retry_after: resp.headers().get(RETRY_AFTER).and_then(parse_retry_after),
+            });
+        }
 
-        if let Some(bytes) = bytes {
-            let result: Result<T, serde_json::Error> = serde_json::from_slice(&bytes);
+        if resp.status() != StatusCode::OK {
+            return Err(FetchError::Status {
+                uri: uri.to_string(),
+                status: resp.status().as_u16(),
+            });
+        }
 
-            if let Ok(result) = result {
-                return Some(result);
-            }
+        let content_type = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(str::to_string);
+        let result = to_bytes(resp.into_body())
+            .await
+            .map_err(|err| network_error(err.to_string()))?;
+
+        if let Some(cache) = self.cache.as_ref() {
+            cache.set(uri, content_type.as_deref(), &result);
         }
 
-        None
+        Ok(result)
     }
 }
 
-pub async fn fetch_external<T, R: 'static, F>(data: &[T], fetch_url_extractor: F) -> Vec<R>
-where
-    R: DeserializeOwned + Send + Debug,
-    F: Fn(&T) -> String,
-{
-    let mut res = vec![];
-    let (tx, mut rx) = mpsc::channel(32);
-    let http = Arc::new(Http::new());
-
-    for item in data {
-        let http = Arc::clone(&http);
-        let url = fetch_url_extractor(item);
-        let tx = tx.clone();
-        spawn_fetcher(http, url, tx).await;
-    }
-
-    drop(tx);
-
-    while let Some(message) = rx.recv().await {
-        res.push(message)
-    }
-
-    res
+/// Only the delay-seconds form is understood, an HTTP date falls back to the regular backoff.
+fn parse_retry_after(value: &HeaderValue) -> Option<Duration> {
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .map(Duration::from_secs)
 }
 
-async fn spawn_fetcher<T: 'static>(http: Arc<Http>, url: String, tx: Sender<T>)
-where
-    T: DeserializeOwned + Send + Debug,
-{
-    tokio::spawn(async move {
-        let data = http.get(&url).await;
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
 
-        if let Some(bytes) = data {
-            // TODO: replace unwrap with something better
-            let fetched = serde_json::from_slice(&bytes).unwrap();
+    use hyper::header::HeaderValue;
 
-            // TODO: replace unwrap with something better
-            tx.send(fetched).await.unwrap();
-        }
-    });
+    use super::parse_retry_after;
+
+    #[test]
+    fn http_parse_retry_after() {
+        assert_eq!(
+            parse_retry_after(&HeaderValue::from_static("120")),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after(&HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT")),
+            None
+        );
+    }
 }
@@ -1,30 +1,97 @@
 mod app;
+mod cache;
+mod config;
+mod endpoints;
+mod error;
+mod event;
+mod fetcher;
 mod http;
 mod models;
+mod snapshot;
+mod source;
+mod sprite;
 mod stateful_list;
 pub mod switchable_table_state;
+mod team_store;
 mod ui;
 mod utils;
 
 use std::{
+    fs,
     io::{self, Stdout},
-    time::Duration,
+    path::Path,
+    sync::Arc,
 };
 
-use app::{App, CurrentMainPageState, SelectedPart};
+use app::{
+    App, BasicInfoFocus, CurrentMainPageState, ListMode, LocationFocus, SelectedPart, TypeFocus,
+};
+use cache::DiskCache;
+use config::Config;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use endpoints::Endpoints;
+use event::{spawn_input_listener, AppEvent};
+use fetcher::Fetcher;
+use http::Http;
+use models::{
+    damage_calculator::DAMAGE_SETTINGS_COUNT, showdown, stat_spread::STAT_NAMES,
+    team::get_free_team_name, Team,
+};
+use snapshot::LocalSnapshot;
+use source::{FetchPolicy, PokeApiSource};
+use team_store::TeamStore;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
 use tui::{backend::CrosstermBackend, Terminal};
 use ui::render;
 
-const POKEAPI_DEFAULT_URL: &str = "https://pokeapi.co/api/v2/";
-const DEFAULT_LIST_QUERY_PARAMS: &str = "?limit=100000&offset=0";
-
 #[tokio::main]
 async fn main() -> Result<(), io::Error> {
+    let config = Config::load()?;
+
+    if config.clear_cache {
+        if let Some(cache) = DiskCache::with_default_location() {
+            cache.clear()?;
+            println!("Cleared cache at {}", cache.dir().display());
+        }
+
+        return Ok(());
+    }
+
+    if let Some(team_name) = config.export_team.as_ref() {
+        return export_team(team_name);
+    }
+
+    let source: Arc<dyn PokeApiSource> = match config.offline {
+        Some(snapshot_dir) => {
+            let snapshot = LocalSnapshot::new(snapshot_dir);
+            if !snapshot.api_root().is_dir() {
+                eprintln!(
+                    "Offline data directory {} does not exist",
+                    snapshot.api_root().display()
+                );
+                return Ok(());
+            }
+
+            Arc::new(snapshot)
+        }
+        None => Arc::new(Http::new()),
+    };
+
+    let fetch_policy = FetchPolicy {
+        max_concurrent_requests: config.max_concurrent_requests,
+        ..FetchPolicy::default()
+    };
+    let endpoints = Endpoints::new(&config.base_url);
+
+    if let Some(path) = config.import_team.as_ref() {
+        let fetcher = Fetcher::new(source, endpoints, fetch_policy);
+        return import_team(fetcher, path, config.version_group).await;
+    }
+
     let mut stdout = io::stdout();
 
     enable_raw_mode()?;
@@ -33,8 +100,17 @@ async fn main() -> Result<(), io::Error> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let app = App::new();
-    let res = run_app(app, &mut terminal).await;
+    let (events_tx, events_rx) = mpsc::unbounded_channel();
+    spawn_input_listener(events_tx.clone());
+
+    let app = App::new(
+        source,
+        endpoints,
+        fetch_policy,
+        events_tx,
+        TeamStore::with_default_location(),
+    );
+    let res = run_app(app, events_rx, &mut terminal).await;
 
     disable_raw_mode()?;
     execute!(
@@ -51,85 +127,386 @@ async fn main() -> Result<(), io::Error> {
     Ok(())
 }
 
+fn export_team(team_name: &str) -> io::Result<()> {
+    let teams = match TeamStore::with_default_location() {
+        Some(team_store) => team_store.load()?,
+        None => vec![],
+    };
+
+    match teams.iter().find(|team| team.name == team_name) {
+        Some(team) => print!("{}", showdown::export_team(team)),
+        None => eprintln!("There's no team called {}", team_name),
+    }
+
+    Ok(())
+}
+
+/// Adds the team in the Showdown file as a new team, telling what had to be left out.
+async fn import_team(
+    fetcher: Fetcher,
+    path: &Path,
+    version_group: Option<String>,
+) -> io::Result<()> {
+    let team_store = match TeamStore::with_default_location() {
+        Some(team_store) => team_store,
+        None => {
+            eprintln!("There's no data directory to save teams to");
+            return Ok(());
+        }
+    };
+    let mut teams = team_store.load()?;
+    let sets = showdown::parse_team(&fs::read_to_string(path)?);
+
+    let mut team = Team::new(get_free_team_name(&teams));
+    team.version_group = version_group;
+    let import = match fetcher.fetch_showdown_import(sets, team).await {
+        Ok(import) => import,
+        Err(error) => {
+            eprintln!("{}", error);
+            return Ok(());
+        }
+    };
+
+    for problem in import.problems.iter() {
+        eprintln!("{}", problem);
+    }
+    println!(
+        "Imported {} Pokemon as {} to {}",
+        import.team.members.len(),
+        import.team.name,
+        team_store.path().display()
+    );
+    teams.push(import.team);
+
+    team_store.save(&teams)
+}
+
 async fn run_app(
     mut app: App,
+    mut events: UnboundedReceiver<AppEvent>,
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
 ) -> io::Result<()> {
-    let tick_rate = Duration::from_millis(250);
-
-    app.init().await;
+    app.init();
+    terminal.draw(|frame| render(frame, &mut app))?;
 
-    loop {
-        terminal.draw(|frame| render(frame, &mut app))?;
-
-        if crossterm::event::poll(tick_rate)? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') => return Ok(()),
-                    KeyCode::Esc => app.reset_current_pokemon(),
-                    KeyCode::Down => match app.selected_part {
-                        SelectedPart::List => app.pokemon_list.next(),
-                        SelectedPart::Main => match app.current_main_page_state {
-                            CurrentMainPageState::BasicInfo => {
+    while let Some(event) = events.recv().await {
+        match event {
+            AppEvent::Fetched { request_id, data } => app.on_fetched(request_id, data),
+            AppEvent::Input(Event::Key(key)) if app.failed_fetch.is_some() => match key.code {
+                KeyCode::Char('q') => return Ok(()),
+                KeyCode::Char('r') => app.on_retry(),
+                KeyCode::Esc => app.dismiss_failed_fetch(),
+                _ => {}
+            },
+            AppEvent::Input(Event::Key(key)) => match key.code {
+                KeyCode::Char('q') => return Ok(()),
+                KeyCode::Esc => app.on_escape(),
+                KeyCode::Down => match app.selected_part {
+                    SelectedPart::List => app.get_active_list_mut().next(),
+                    SelectedPart::Main if app.current_move.is_some() => {
+                        let move_learners_count = app.get_move_learners_count();
+                        app.move_learners_state.next(move_learners_count)
+                    }
+                    SelectedPart::Main if app.current_ability.is_some() => {
+                        let ability_pokemon_count = app.get_ability_pokemon_count();
+                        app.ability_pokemon_state.next(ability_pokemon_count)
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Items => {
+                        let item_holders_count = app.get_item_holders_count();
+                        app.item_holders_state.next(item_holders_count)
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Teams => {
+                        let team_members_count = app.get_team_members_count();
+                        app.team_members_state.next(team_members_count)
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Locations => {
+                        match app.location_focus {
+                            LocationFocus::Areas => {
+                                let location_areas_count = app.get_location_areas_count();
+                                app.location_areas_state.next(location_areas_count)
+                            }
+                            LocationFocus::Encounters => {
+                                let location_encounters_count = app.get_location_encounters_count();
+                                app.location_encounters_state
+                                    .next(location_encounters_count)
+                            }
+                        }
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Types => {
+                        match app.type_focus {
+                            TypeFocus::Pokemon => {
+                                let type_pokemon_count = app.get_type_pokemon_count();
+                                app.type_pokemon_state.next(type_pokemon_count)
+                            }
+                            TypeFocus::Moves => {
+                                let type_moves_count = app.get_type_moves_count();
+                                app.type_moves_state.next(type_moves_count)
+                            }
+                        }
+                    }
+                    SelectedPart::Main => match app.current_main_page_state {
+                        CurrentMainPageState::BasicInfo => match app.basic_info_focus {
+                            BasicInfoFocus::Moves => {
                                 app.pokemon_moves_list_state.next(app.rendered_moves_count)
                             }
-                            CurrentMainPageState::VersionGroupSelection => {
-                                app.version_groups.next()
+                            BasicInfoFocus::EvolutionChain => {
+                                let evolution_stages_count = app.get_evolution_stages_count();
+                                app.evolution_chain_state.next(evolution_stages_count)
+                            }
+                            BasicInfoFocus::Abilities => {
+                                let abilities_count = app.get_pokemon_abilities_count();
+                                app.pokemon_abilities_state.next(abilities_count)
+                            }
+                            BasicInfoFocus::HeldItems => {
+                                let held_items_count = app.get_pokemon_held_items_count();
+                                app.pokemon_held_items_state.next(held_items_count)
+                            }
+                            BasicInfoFocus::Encounters => {
+                                let encounters_count = app.get_pokemon_encounters_count();
+                                app.pokemon_encounters_state.next(encounters_count)
+                            }
+                            BasicInfoFocus::Forms => {
+                                let forms_count = app.get_pokemon_forms_count();
+                                app.pokemon_forms_state.next(forms_count)
                             }
                         },
+                        CurrentMainPageState::VersionGroupSelection => app.version_groups.next(),
+                        CurrentMainPageState::StatCalculator => {
+                            app.stat_calculator_state.next(Some(STAT_NAMES.len()))
+                        }
+                        CurrentMainPageState::Comparison => {
+                            let comparison_moves_count = app.get_comparison_moves_count();
+                            app.comparison_moves_state.next(comparison_moves_count)
+                        }
+                        CurrentMainPageState::DamageCalculator => {
+                            app.damage_settings_state.next(Some(DAMAGE_SETTINGS_COUNT))
+                        }
                     },
-                    KeyCode::Up => match app.selected_part {
-                        SelectedPart::List => app.pokemon_list.previous(),
-                        SelectedPart::Main => match app.current_main_page_state {
-                            CurrentMainPageState::BasicInfo => app
+                },
+                KeyCode::Up => match app.selected_part {
+                    SelectedPart::List => app.get_active_list_mut().previous(),
+                    SelectedPart::Main if app.current_move.is_some() => {
+                        let move_learners_count = app.get_move_learners_count();
+                        app.move_learners_state.previous(move_learners_count)
+                    }
+                    SelectedPart::Main if app.current_ability.is_some() => {
+                        let ability_pokemon_count = app.get_ability_pokemon_count();
+                        app.ability_pokemon_state.previous(ability_pokemon_count)
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Items => {
+                        let item_holders_count = app.get_item_holders_count();
+                        app.item_holders_state.previous(item_holders_count)
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Teams => {
+                        let team_members_count = app.get_team_members_count();
+                        app.team_members_state.previous(team_members_count)
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Locations => {
+                        match app.location_focus {
+                            LocationFocus::Areas => {
+                                let location_areas_count = app.get_location_areas_count();
+                                app.location_areas_state.previous(location_areas_count)
+                            }
+                            LocationFocus::Encounters => {
+                                let location_encounters_count = app.get_location_encounters_count();
+                                app.location_encounters_state
+                                    .previous(location_encounters_count)
+                            }
+                        }
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Types => {
+                        match app.type_focus {
+                            TypeFocus::Pokemon => {
+                                let type_pokemon_count = app.get_type_pokemon_count();
+                                app.type_pokemon_state.previous(type_pokemon_count)
+                            }
+                            TypeFocus::Moves => {
+                                let type_moves_count = app.get_type_moves_count();
+                                app.type_moves_state.previous(type_moves_count)
+                            }
+                        }
+                    }
+                    SelectedPart::Main => match app.current_main_page_state {
+                        CurrentMainPageState::BasicInfo => match app.basic_info_focus {
+                            BasicInfoFocus::Moves => app
                                 .pokemon_moves_list_state
                                 .previous(app.rendered_moves_count),
-                            CurrentMainPageState::VersionGroupSelection => {
-                                app.version_groups.previous()
+                            BasicInfoFocus::EvolutionChain => {
+                                let evolution_stages_count = app.get_evolution_stages_count();
+                                app.evolution_chain_state.previous(evolution_stages_count)
+                            }
+                            BasicInfoFocus::Abilities => {
+                                let abilities_count = app.get_pokemon_abilities_count();
+                                app.pokemon_abilities_state.previous(abilities_count)
+                            }
+                            BasicInfoFocus::HeldItems => {
+                                let held_items_count = app.get_pokemon_held_items_count();
+                                app.pokemon_held_items_state.previous(held_items_count)
+                            }
+                            BasicInfoFocus::Encounters => {
+                                let encounters_count = app.get_pokemon_encounters_count();
+                                app.pokemon_encounters_state.previous(encounters_count)
+                            }
+                            BasicInfoFocus::Forms => {
+                                let forms_count = app.get_pokemon_forms_count();
+                                app.pokemon_forms_state.previous(forms_count)
                             }
                         },
+                        CurrentMainPageState::VersionGroupSelection => {
+                            app.version_groups.previous()
+                        }
+                        CurrentMainPageState::StatCalculator => {
+                            app.stat_calculator_state.previous(Some(STAT_NAMES.len()))
+                        }
+                        CurrentMainPageState::Comparison => {
+                            let comparison_moves_count = app.get_comparison_moves_count();
+                            app.comparison_moves_state.previous(comparison_moves_count)
+                        }
+                        CurrentMainPageState::DamageCalculator => app
+                            .damage_settings_state
+                            .previous(Some(DAMAGE_SETTINGS_COUNT)),
                     },
-                    KeyCode::Left => match app.selected_part {
-                        SelectedPart::Main => app.selected_part = SelectedPart::List,
-                        _ => {}
-                    },
-                    KeyCode::Right => match (&app.selected_part, &app.current_pokemon) {
-                        (SelectedPart::List, Some(_)) => app.selected_part = SelectedPart::Main,
-                        _ => {}
-                    },
-                    KeyCode::Enter => match app.selected_part {
-                        SelectedPart::List => {
-                            app.on_pokemon_selected(|app| {
-                                terminal.draw(|frame| render(frame, app)).unwrap();
-                            })
-                            .await;
-                        }
-                        SelectedPart::Main => match app.current_main_page_state {
-                            CurrentMainPageState::VersionGroupSelection => {
-                                app.on_version_group_selected();
-                                app.on_moves_and_abilities_open(|app| {
-                                    terminal.draw(|frame| render(frame, app)).unwrap();
-                                })
-                                .await;
-                            }
-                            _ => {}
+                },
+                KeyCode::Left => {
+                    if let SelectedPart::Main = app.selected_part {
+                        app.selected_part = SelectedPart::List
+                    }
+                }
+                KeyCode::Right
+                    if matches!(app.selected_part, SelectedPart::List)
+                        && app.has_current_item() =>
+                {
+                    app.selected_part = SelectedPart::Main
+                }
+                KeyCode::Enter => match app.selected_part {
+                    SelectedPart::List => {
+                        app.on_list_item_selected();
+                    }
+                    SelectedPart::Main if app.current_move.is_some() => {
+                        app.on_move_learner_selected()
+                    }
+                    SelectedPart::Main if app.current_ability.is_some() => {
+                        app.on_ability_pokemon_selected()
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Items => {
+                        app.on_item_holder_selected()
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Teams => {
+                        app.on_team_member_selected()
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Locations => {
+                        match app.location_focus {
+                            LocationFocus::Areas => app.on_location_area_selected(),
+                            LocationFocus::Encounters => app.on_location_encounter_selected(),
+                        }
+                    }
+                    SelectedPart::Main if app.list_mode == ListMode::Types => {
+                        match app.type_focus {
+                            TypeFocus::Pokemon => app.on_type_pokemon_selected(),
+                            TypeFocus::Moves => app.on_type_move_selected(),
+                        }
+                    }
+                    SelectedPart::Main => match app.current_main_page_state {
+                        CurrentMainPageState::VersionGroupSelection => {
+                            app.on_version_group_selected();
+                            app.on_moves_and_abilities_open();
+                        }
+                        CurrentMainPageState::BasicInfo => match app.basic_info_focus {
+                            BasicInfoFocus::EvolutionChain => app.on_evolution_stage_selected(),
+                            BasicInfoFocus::Moves => app.on_pokemon_move_selected(),
+                            BasicInfoFocus::Abilities => app.on_pokemon_ability_selected(),
+                            BasicInfoFocus::HeldItems => app.on_pokemon_held_item_selected(),
+                            BasicInfoFocus::Encounters => app.on_pokemon_encounter_selected(),
+                            BasicInfoFocus::Forms => app.on_pokemon_form_selected(),
                         },
+                        CurrentMainPageState::StatCalculator
+                        | CurrentMainPageState::Comparison
+                        | CurrentMainPageState::DamageCalculator => {}
                     },
-                    KeyCode::Char(c) => match app.selected_part {
-                        SelectedPart::List => {
-                            app.on_search_append(c);
+                },
+                KeyCode::Tab => match (&app.selected_part, app.list_mode) {
+                    (SelectedPart::List, _) => app.on_list_mode_switch(),
+                    (SelectedPart::Main, _) if app.has_detail_page() => {}
+                    (SelectedPart::Main, ListMode::Types) => app.on_type_focus_switch(),
+                    (SelectedPart::Main, ListMode::Items | ListMode::Teams) => {}
+                    (SelectedPart::Main, ListMode::Locations) => app.on_location_focus_switch(),
+                    (SelectedPart::Main, ListMode::Pokemon) => match app.current_main_page_state {
+                        CurrentMainPageState::BasicInfo => app.on_basic_info_focus_switch(),
+                        CurrentMainPageState::StatCalculator => {
+                            app.on_stat_calculator_focus_switch()
                         }
-                        _ => {}
+                        CurrentMainPageState::VersionGroupSelection
+                        | CurrentMainPageState::Comparison
+                        | CurrentMainPageState::DamageCalculator => {}
                     },
-                    KeyCode::Backspace => match app.selected_part {
-                        SelectedPart::List => {
-                            app.on_search_remove();
+                },
+                KeyCode::Char(c) => match app.selected_part {
+                    SelectedPart::List => {
+                        app.on_search_append(c);
+                    }
+                    SelectedPart::Main if app.has_detail_page() => {}
+                    SelectedPart::Main => match (c, app.list_mode, &app.current_main_page_state) {
+                        ('s', ListMode::Pokemon, CurrentMainPageState::BasicInfo) => {
+                            app.on_sprite_variant_switch()
+                        }
+                        ('c', ListMode::Pokemon, CurrentMainPageState::BasicInfo) => {
+                            app.on_stat_calculator_open()
+                        }
+                        ('m', ListMode::Pokemon, CurrentMainPageState::BasicInfo) => {
+                            app.on_pokemon_mark()
+                        }
+                        ('v', ListMode::Pokemon, CurrentMainPageState::BasicInfo) => {
+                            app.on_comparison_open()
+                        }
+                        ('t', ListMode::Pokemon, CurrentMainPageState::BasicInfo) => {
+                            app.on_team_pokemon_toggle()
+                        }
+                        ('a', ListMode::Pokemon, CurrentMainPageState::BasicInfo) => {
+                            app.on_team_member_assign()
+                        }
+                        ('d', ListMode::Pokemon, CurrentMainPageState::BasicInfo) => {
+                            app.on_damage_calculator_open()
+                        }
+                        ('a', ListMode::Items, _) => app.on_team_item_assign(),
+                        ('n', ListMode::Teams, _) => app.on_team_create(),
+                        ('d', ListMode::Teams, _) => app.on_team_delete(),
+                        ('x', ListMode::Teams, _) => app.on_team_member_remove(),
+                        ('+', ListMode::Pokemon, CurrentMainPageState::StatCalculator) => {
+                            app.on_stat_change(1)
+                        }
+                        ('-', ListMode::Pokemon, CurrentMainPageState::StatCalculator) => {
+                            app.on_stat_change(-1)
+                        }
+                        (']', ListMode::Pokemon, CurrentMainPageState::StatCalculator) => {
+                            app.on_level_change(1)
+                        }
+                        ('[', ListMode::Pokemon, CurrentMainPageState::StatCalculator) => {
+                            app.on_level_change(-1)
+                        }
+                        ('n', ListMode::Pokemon, CurrentMainPageState::StatCalculator) => {
+                            app.on_nature_switch()
+                        }
+                        ('+', ListMode::Pokemon, CurrentMainPageState::DamageCalculator) => {
+                            app.on_damage_setting_change(1)
+                        }
+                        ('-', ListMode::Pokemon, CurrentMainPageState::DamageCalculator) => {
+                            app.on_damage_setting_change(-1)
                         }
                         _ => {}
                     },
-                    _ => {}
+                },
+                KeyCode::Backspace => {
+                    if let SelectedPart::List = app.selected_part {
+                        app.on_search_remove();
+                    }
                 }
-            }
+                _ => {}
+            },
+            AppEvent::Input(_) => {}
         }
+
+        terminal.draw(|frame| render(frame, &mut app))?;
     }
+
+    Ok(())
 }
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use super::VerboseEffect;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ContestEffect {
+    pub id: Option<i32>,
+    pub appeal: Option<i32>,
+    pub jam: Option<i32>,
+    /// Contest effects only have a long `effect`, there's no `short_effect`.
+    pub effect_entries: Option<Vec<VerboseEffect>>,
+}
+
+impl ContestEffect {
+    pub fn get_renderable_effect_entry(&self) -> String {
+        self.effect_entries
+            .iter()
+            .flatten()
+            .find(|effect_entry| effect_entry.get_language() == "en")
+            .and_then(|effect_entry| effect_entry.effect.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ContestEffect;
+
+    #[test]
+    fn contest_effect_get_renderable_effect_entry() {
+        let contest_effect: ContestEffect = serde_json::from_value(json!({
+            "id": 1,
+            "appeal": 4,
+            "jam": 0,
+            "effect_entries": [
+                { "effect": "Gibt eine hohe Note.", "language": { "name": "de", "url": null } },
+                { "effect": "Gives a high number of appeal points.", "language": { "name": "en", "url": null } }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            contest_effect.get_renderable_effect_entry(),
+            "Gives a high number of appeal points."
+        );
+    }
+}
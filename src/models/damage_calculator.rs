@@ -0,0 +1,1610 @@
+use std::collections::HashMap;
+
+use tui::{
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::Row,
+};
+
+use crate::utils::PrepareForDisplay;
+
+use super::{
+    pokemon_type::ATTACKING_TYPES,
+    stat_spread::{get_stat_abbreviation, Nature, MAX_EV, MAX_IV, STAT_NAMES},
+    team::TeamMember,
+    ExtendedPokemonInfo, PokemonMoveExt, StatSpread,
+};
+
+pub const LATEST_GENERATION: i32 = 9;
+pub const MAX_STAT_STAGE: i32 = 6;
+/// Rows of the settings table, in the order `DamageCalculator::change_setting` expects them.
+pub const DAMAGE_SETTINGS_COUNT: usize = 20;
+/// Past this many hits the chance to KO isn't worked out, only whether it's guaranteed.
+const MAX_KO_HITS: usize = 8;
+
+/// Until generation 4 split moves into physical and special, the type decided which stats a move used.
+const PHYSICAL_TYPES: [&str; 9] = [
+    "normal", "fighting", "flying", "poison", "ground", "rock", "bug", "ghost", "steel",
+];
+
+const HP: usize = 0;
+const ATTACK: usize = 1;
+const DEFENSE: usize = 2;
+const SPECIAL_ATTACK: usize = 3;
+const SPECIAL_DEFENSE: usize = 4;
+const SPEED: usize = 5;
+
+/// The most stat experience a stat could have in generations 1 and 2.
+const MAX_STAT_EXP: i32 = 65535;
+
+/// Modifiers are in 4096ths, which is how the games apply them since generation 5.
+const NEUTRAL: i32 = 4096;
+const ONE_AND_A_HALF: i32 = 6144;
+const HALF: i32 = 2048;
+const DOUBLE: i32 = 8192;
+
+/// The next or previous of `all` that's in the game already.
+fn cycle<T: Copy + PartialEq>(
+    all: &[T],
+    current: T,
+    delta: i32,
+    is_available: impl Fn(T) -> bool,
+) -> T {
+    let available: Vec<T> = all
+        .iter()
+        .copied()
+        .filter(|item| is_available(*item))
+        .collect();
+    let index = available
+        .iter()
+        .position(|item| *item == current)
+        .unwrap_or_default() as i32;
+
+    available
+        .get((index + delta).rem_euclid(available.len().max(1) as i32) as usize)
+        .copied()
+        .unwrap_or(current)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Weather {
+    None,
+    Sun,
+    Rain,
+    Sand,
+    Snow,
+}
+
+impl Weather {
+    pub const ALL: [Weather; 5] = [
+        Weather::None,
+        Weather::Sun,
+        Weather::Rain,
+        Weather::Sand,
+        Weather::Snow,
+    ];
+
+    /// Hail did nothing to damage, snow raising the Defense of Ice types came with generation 9.
+    pub fn is_available(self, generation: i32) -> bool {
+        match self {
+            Weather::None => true,
+            Weather::Sun | Weather::Rain | Weather::Sand => generation >= 2,
+            Weather::Snow => generation >= 9,
+        }
+    }
+
+    pub fn change(self, delta: i32, generation: i32) -> Self {
+        cycle(&Weather::ALL, self, delta, |weather| {
+            weather.is_available(generation)
+        })
+    }
+
+    pub fn get_name(self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn get_modifier(self, move_type: &str) -> i32 {
+        match (self, move_type) {
+            (Weather::Sun, "fire") | (Weather::Rain, "water") => ONE_AND_A_HALF,
+            (Weather::Sun, "water") | (Weather::Rain, "fire") => HALF,
+            _ => NEUTRAL,
+        }
+    }
+}
+
+/// The defender is assumed to be on the ground, which terrains need to take effect.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Terrain {
+    None,
+    Electric,
+    Grassy,
+    Psychic,
+    Misty,
+}
+
+impl Terrain {
+    pub const ALL: [Terrain; 5] = [
+        Terrain::None,
+        Terrain::Electric,
+        Terrain::Grassy,
+        Terrain::Psychic,
+        Terrain::Misty,
+    ];
+
+    pub fn is_available(self, generation: i32) -> bool {
+        match self {
+            Terrain::None => true,
+            Terrain::Electric | Terrain::Grassy | Terrain::Misty => generation >= 6,
+            Terrain::Psychic => generation >= 7,
+        }
+    }
+
+    pub fn change(self, delta: i32, generation: i32) -> Self {
+        cycle(&Terrain::ALL, self, delta, |terrain| {
+            terrain.is_available(generation)
+        })
+    }
+
+    pub fn get_name(self) -> String {
+        format!("{:?}", self)
+    }
+
+    /// Boosting terrains were toned down from 1.5x to 1.3x in generation 8.
+    fn get_power_modifier(self, move_type: &str, generation: i32) -> i32 {
+        match (self, move_type) {
+            (Terrain::Electric, "electric")
+            | (Terrain::Grassy, "grass")
+            | (Terrain::Psychic, "psychic") => match generation >= 8 {
+                true => 5325,
+                false => ONE_AND_A_HALF,
+            },
+            (Terrain::Misty, "dragon") => HALF,
+            _ => NEUTRAL,
+        }
+    }
+}
+
+/// The item that boosts moves of each type, in the order of `ATTACKING_TYPES`: its type,
+/// its name in PokéAPI and how it's shown.
+const TYPE_BOOSTERS: [(&str, &str, &str); 18] = [
+    ("normal", "silk-scarf", "Silk Scarf"),
+    ("fire", "charcoal", "Charcoal"),
+    ("water", "mystic-water", "Mystic Water"),
+    ("electric", "magnet", "Magnet"),
+    ("grass", "miracle-seed", "Miracle Seed"),
+    ("ice", "never-melt-ice", "Never-Melt Ice"),
+    ("fighting", "black-belt", "Black Belt"),
+    ("poison", "poison-barb", "Poison Barb"),
+    ("ground", "soft-sand", "Soft Sand"),
+    ("flying", "sharp-beak", "Sharp Beak"),
+    ("psychic", "twisted-spoon", "Twisted Spoon"),
+    ("bug", "silver-powder", "Silver Powder"),
+    ("rock", "hard-stone", "Hard Stone"),
+    ("ghost", "spell-tag", "Spell Tag"),
+    ("dragon", "dragon-fang", "Dragon Fang"),
+    ("dark", "black-glasses", "Black Glasses"),
+    ("steel", "metal-coat", "Metal Coat"),
+    ("fairy", "fairy-feather", "Fairy Feather"),
+];
+
+/// Held items that make the attacker hit harder. A type booster only raises the power of moves
+/// of its type, like Charcoal for a Fire move.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AttackerItem {
+    None,
+    ChoiceBand,
+    ChoiceSpecs,
+    LifeOrb,
+    ExpertBelt,
+    TypeBooster(&'static str),
+}
+
+impl AttackerItem {
+    pub const ALL: [AttackerItem; 23] = [
+        AttackerItem::None,
+        AttackerItem::ChoiceBand,
+        AttackerItem::ChoiceSpecs,
+        AttackerItem::LifeOrb,
+        AttackerItem::ExpertBelt,
+        AttackerItem::TypeBooster("normal"),
+        AttackerItem::TypeBooster("fire"),
+        AttackerItem::TypeBooster("water"),
+        AttackerItem::TypeBooster("electric"),
+        AttackerItem::TypeBooster("grass"),
+        AttackerItem::TypeBooster("ice"),
+        AttackerItem::TypeBooster("fighting"),
+        AttackerItem::TypeBooster("poison"),
+        AttackerItem::TypeBooster("ground"),
+        AttackerItem::TypeBooster("flying"),
+        AttackerItem::TypeBooster("psychic"),
+        AttackerItem::TypeBooster("bug"),
+        AttackerItem::TypeBooster("rock"),
+        AttackerItem::TypeBooster("ghost"),
+        AttackerItem::TypeBooster("dragon"),
+        AttackerItem::TypeBooster("dark"),
+        AttackerItem::TypeBooster("steel"),
+        AttackerItem::TypeBooster("fairy"),
+    ];
+
+    /// Silk Scarf came with generation 3 and Fairy Feather with generation 9.
+    pub fn is_available(self, generation: i32) -> bool {
+        match self {
+            AttackerItem::None => true,
+            AttackerItem::TypeBooster("normal") => generation >= 3,
+            AttackerItem::TypeBooster("fairy") => generation >= 9,
+            AttackerItem::TypeBooster(_) => generation >= 2,
+            AttackerItem::ChoiceBand => generation >= 3,
+            AttackerItem::ChoiceSpecs | AttackerItem::LifeOrb | AttackerItem::ExpertBelt => {
+                generation >= 4
+            }
+        }
+    }
+
+    pub fn change(self, delta: i32, generation: i32) -> Self {
+        cycle(&AttackerItem::ALL, self, delta, |item| {
+            item.is_available(generation)
+        })
+    }
+
+    /// The item a team member holds, when it's one of those that matter here.
+    pub fn from_name(item_name: &str) -> Self {
+        match item_name {
+            "choice-band" => AttackerItem::ChoiceBand,
+            "choice-specs" => AttackerItem::ChoiceSpecs,
+            "life-orb" => AttackerItem::LifeOrb,
+            "expert-belt" => AttackerItem::ExpertBelt,
+            _ => TYPE_BOOSTERS
+                .iter()
+                .find(|(_, booster_name, _)| *booster_name == item_name)
+                .map(|(boosted_type, ..)| AttackerItem::TypeBooster(boosted_type))
+                .unwrap_or(AttackerItem::None),
+        }
+    }
+
+    pub fn get_name(self) -> &'static str {
+        match self {
+            AttackerItem::None => "None",
+            AttackerItem::ChoiceBand => "Choice Band",
+            AttackerItem::ChoiceSpecs => "Choice Specs",
+            AttackerItem::LifeOrb => "Life Orb",
+            AttackerItem::ExpertBelt => "Expert Belt",
+            AttackerItem::TypeBooster(boosted_type) => TYPE_BOOSTERS
+                .iter()
+                .find(|(booster_type, ..)| *booster_type == boosted_type)
+                .map(|(.., name)| *name)
+                .unwrap_or("Type booster"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DefenderItem {
+    None,
+    AssaultVest,
+    Eviolite,
+}
+
+impl DefenderItem {
+    pub const ALL: [DefenderItem; 3] = [
+        DefenderItem::None,
+        DefenderItem::AssaultVest,
+        DefenderItem::Eviolite,
+    ];
+
+    pub fn is_available(self, generation: i32) -> bool {
+        match self {
+            DefenderItem::None => true,
+            DefenderItem::Eviolite => generation >= 5,
+            DefenderItem::AssaultVest => generation >= 6,
+        }
+    }
+
+    pub fn change(self, delta: i32, generation: i32) -> Self {
+        cycle(&DefenderItem::ALL, self, delta, |item| {
+            item.is_available(generation)
+        })
+    }
+
+    pub fn from_name(item_name: &str) -> Self {
+        match item_name {
+            "assault-vest" => DefenderItem::AssaultVest,
+            "eviolite" => DefenderItem::Eviolite,
+            _ => DefenderItem::None,
+        }
+    }
+
+    pub fn get_name(self) -> &'static str {
+        match self {
+            DefenderItem::None => "None",
+            DefenderItem::AssaultVest => "Assault Vest",
+            DefenderItem::Eviolite => "Eviolite",
+        }
+    }
+}
+
+/// How a stat stage scales a stat, as a fraction: +1 is 3/2, -1 is 2/3.
+pub fn apply_stat_stage(stat: i32, stage: i32) -> i32 {
+    let stage = stage.clamp(-MAX_STAT_STAGE, MAX_STAT_STAGE);
+
+    match stage >= 0 {
+        true => stat * (2 + stage) / 2,
+        false => stat * 2 / (2 - stage),
+    }
+}
+
+/// The stat of `stat_index` with `spread`, the way `generation` works it out.
+pub fn calculate_stat(
+    spread: &StatSpread,
+    stat_index: usize,
+    base_stats: &[i32; 6],
+    generation: i32,
+) -> i32 {
+    match generation <= 2 {
+        true => calculate_early_stat(spread, stat_index, base_stats),
+        false => spread.calculate(stat_index, base_stats[stat_index]),
+    }
+}
+
+/// Where the spread keeps `stat_index`: both specials share their DV and stat experience in
+/// generations 1 and 2, which the Special Attack ones stand for.
+fn get_spread_index(stat_index: usize, generation: i32) -> usize {
+    match (stat_index, generation <= 2) {
+        (SPECIAL_DEFENSE, true) => SPECIAL_ATTACK,
+        _ => stat_index,
+    }
+}
+
+/// Generations 1 and 2 had DVs from 0 to 15 instead of IVs, a DV being half the IV.
+/// The HP DV is made of the last bits of the others.
+fn get_dv(spread: &StatSpread, stat_index: usize) -> i32 {
+    let get_dv =
+        |stat_index: usize| spread.ivs[get_spread_index(stat_index, 1)].clamp(0, MAX_IV) / 2;
+
+    match stat_index {
+        HP => [ATTACK, DEFENSE, SPEED, SPECIAL_ATTACK]
+            .into_iter()
+            .fold(0, |dv, stat_index| dv * 2 + get_dv(stat_index) % 2),
+        _ => get_dv(stat_index),
+    }
+}
+
+/// Stat experience took the place of EVs, a full 252 EVs being the most of it.
+fn get_stat_exp(spread: &StatSpread, stat_index: usize) -> i32 {
+    spread.evs[get_spread_index(stat_index, 1)].clamp(0, MAX_EV) * MAX_STAT_EXP / MAX_EV
+}
+
+/// Generations 1 and 2 had DVs and stat experience instead of IVs and EVs, and no natures.
+fn calculate_early_stat(spread: &StatSpread, stat_index: usize, base_stats: &[i32; 6]) -> i32 {
+    let dv = get_dv(spread, stat_index);
+    let stat_exp = get_stat_exp(spread, stat_index);
+    let stat_exp_bonus = ((stat_exp as f64).sqrt().ceil() as i32).min(255) / 4;
+    let stat = ((base_stats[stat_index] + dv) * 2 + stat_exp_bonus) * spread.level / 100;
+
+    match stat_index {
+        HP => stat + spread.level + 10,
+        _ => stat + 5,
+    }
+}
+
+/// A modifier in 4096ths, rounded down like the games before generation 5 did.
+fn apply_floor(value: i32, modifier: i32) -> i32 {
+    value * modifier / NEUTRAL
+}
+
+/// A modifier in 4096ths, rounded to the nearest with halves going down like the games since generation 5.
+fn apply_round(value: i32, modifier: i32) -> i32 {
+    (value * modifier + 2047) / NEUTRAL
+}
+
+/// Several modifiers in 4096ths combined into one, the way the games chain them.
+fn chain_modifiers(modifiers: &[i32]) -> i32 {
+    modifiers.iter().fold(NEUTRAL, |chained, modifier| {
+        (chained * modifier + 2048) / NEUTRAL
+    })
+}
+
+/// A damaging move with a fixed power, with what the formula needs to know about it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DamageMove {
+    pub name: String,
+    pub power: i32,
+    pub move_type: String,
+    pub damage_class: String,
+}
+
+impl DamageMove {
+    /// Status moves and moves whose power depends on something else can't be worked out.
+    pub fn from_move(pokemon_move: &PokemonMoveExt) -> Option<Self> {
+        let damage_class = pokemon_move.damage_class.as_ref()?.name.clone()?;
+        if damage_class == "status" {
+            return None;
+        }
+
+        Some(Self {
+            name: pokemon_move.name.clone()?,
+            power: pokemon_move.power.filter(|power| *power > 0)?,
+            move_type: pokemon_move.pk_type.as_ref()?.name.clone()?,
+            damage_class,
+        })
+    }
+
+    pub fn is_physical(&self, generation: i32) -> bool {
+        match generation < 4 {
+            true => PHYSICAL_TYPES.contains(&self.move_type.as_str()),
+            false => self.damage_class == "physical",
+        }
+    }
+
+    /// Indexes in `STAT_NAMES` of the stat the move attacks with and the one it hits.
+    fn get_stat_indexes(&self, generation: i32) -> (usize, usize) {
+        match self.is_physical(generation) {
+            true => (ATTACK, DEFENSE),
+            false => (SPECIAL_ATTACK, SPECIAL_DEFENSE),
+        }
+    }
+}
+
+/// Everything about the attack that isn't the Pokemon or the move themselves.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DamageSettings {
+    pub attacker_spread: StatSpread,
+    pub defender_spread: StatSpread,
+    pub attacker_stage: i32,
+    pub defender_stage: i32,
+    pub attacker_item: AttackerItem,
+    pub defender_item: DefenderItem,
+    pub weather: Weather,
+    pub terrain: Terrain,
+    pub is_critical_hit: bool,
+}
+
+impl DamageSettings {
+    pub fn new(attacker_spread: StatSpread, defender_spread: StatSpread) -> Self {
+        Self {
+            attacker_spread,
+            defender_spread,
+            attacker_stage: 0,
+            defender_stage: 0,
+            attacker_item: AttackerItem::None,
+            defender_item: DefenderItem::None,
+            weather: Weather::None,
+            terrain: Terrain::None,
+            is_critical_hit: false,
+        }
+    }
+}
+
+/// A Pokemon the calculator can pick for either side, with what it needs of it in the
+/// version group and generation it was opened in.
+#[derive(Clone, Debug)]
+pub struct DamagePokemon {
+    pub name: String,
+    pub base_stats: [i32; 6],
+    pub types: Vec<String>,
+    /// How much a move of each type deals to it, with the type chart of the generation.
+    pub multipliers: HashMap<String, f32>,
+    /// The damaging moves it learns, by name. Moves whose details aren't loaded are left out.
+    pub moves: Vec<DamageMove>,
+    /// Where its settings start when it's picked.
+    pub stat_spread: StatSpread,
+    pub item: Option<String>,
+}
+
+impl DamagePokemon {
+    pub fn new(pokemon: &ExtendedPokemonInfo, version_group: &str, generation: i32) -> Self {
+        let types = pokemon.get_types();
+        let mut moves: Vec<DamageMove> = pokemon
+            .pokemon
+            .get_move_names(version_group)
+            .iter()
+            .filter_map(|move_name| pokemon.moves.get(move_name))
+            .filter_map(DamageMove::from_move)
+            .collect();
+        moves.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            name: pokemon.pokemon.name.clone().unwrap_or_default(),
+            base_stats: STAT_NAMES
+                .map(|stat_name| pokemon.pokemon.get_base_stat(stat_name).unwrap_or_default()),
+            types: types
+                .iter()
+                .filter_map(|de_type| de_type.name.clone())
+                .collect(),
+            multipliers: ATTACKING_TYPES
+                .iter()
+                .map(|attacking_type| {
+                    let multiplier = types
+                        .iter()
+                        .map(|de_type| de_type.get_damage_multiplier_in(attacking_type, generation))
+                        .product();
+
+                    (attacking_type.to_string(), multiplier)
+                })
+                .collect(),
+            moves,
+            stat_spread: StatSpread::default(),
+            item: None,
+        }
+    }
+
+    /// Team members start with their own spread and held item.
+    pub fn set_team_member(&mut self, member: &TeamMember) {
+        self.stat_spread = member.stat_spread.clone();
+        self.item = member.item.clone();
+    }
+
+    fn get_multiplier(&self, move_type: &str) -> f32 {
+        self.multipliers.get(move_type).copied().unwrap_or(1.0)
+    }
+}
+
+/// One side of the attack.
+pub struct Combatant<'a> {
+    pub base_stats: [i32; 6],
+    pub types: Vec<&'a str>,
+}
+
+impl<'a> Combatant<'a> {
+    pub fn new(pokemon: &'a DamagePokemon) -> Self {
+        Self {
+            base_stats: pokemon.base_stats,
+            types: pokemon.types.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// The numbers the formula works with once stats, stages and items are accounted for.
+#[derive(Debug)]
+struct AttackInputs<'a> {
+    level: i32,
+    power: i32,
+    attack: i32,
+    defense: i32,
+    move_type: &'a str,
+    is_stab: bool,
+    effectiveness: f32,
+}
+
+/// Every damage roll of the move, from the lowest to the highest.
+/// `effectiveness` is how the defender's types take the move's type in `generation`.
+/// Abilities aren't accounted for, neither there nor anywhere else in the formula.
+pub fn calculate_damage(
+    attacker: &Combatant,
+    defender: &Combatant,
+    effectiveness: f32,
+    damage_move: &DamageMove,
+    settings: &DamageSettings,
+    generation: i32,
+) -> DamageResult {
+    let (attack_index, defense_index) = damage_move.get_stat_indexes(generation);
+    let is_physical = attack_index == ATTACK;
+    let move_type = damage_move.move_type.as_str();
+    let apply = |value: i32, modifier: i32| match generation >= 5 {
+        true => apply_round(value, modifier),
+        false => apply_floor(value, modifier),
+    };
+
+    // Critical hits ignore every stage in generation 1, in generation 2 both stages when the
+    // defender's is at least as high, and since only the attacker's drops and defender's boosts
+    let (attacker_stage, defender_stage) = match (settings.is_critical_hit, generation) {
+        (false, _) => (settings.attacker_stage, settings.defender_stage),
+        (true, ..=1) => (0, 0),
+        (true, 2) if settings.defender_stage >= settings.attacker_stage => (0, 0),
+        (true, 2) => (settings.attacker_stage, settings.defender_stage),
+        (true, _) => (
+            settings.attacker_stage.max(0),
+            settings.defender_stage.min(0),
+        ),
+    };
+
+    let mut attack = apply_stat_stage(
+        calculate_stat(
+            &settings.attacker_spread,
+            attack_index,
+            &attacker.base_stats,
+            generation,
+        ),
+        attacker_stage,
+    );
+    if matches!(
+        (settings.attacker_item, is_physical),
+        (AttackerItem::ChoiceBand, true) | (AttackerItem::ChoiceSpecs, false)
+    ) && settings.attacker_item.is_available(generation)
+    {
+        attack = apply(attack, ONE_AND_A_HALF);
+    }
+
+    let mut defense = apply_stat_stage(
+        calculate_stat(
+            &settings.defender_spread,
+            defense_index,
+            &defender.base_stats,
+            generation,
+        ),
+        defender_stage,
+    );
+    let is_defense_boosted = match settings.defender_item {
+        DefenderItem::AssaultVest => !is_physical,
+        DefenderItem::Eviolite => true,
+        DefenderItem::None => false,
+    } && settings.defender_item.is_available(generation);
+    if is_defense_boosted {
+        defense = apply(defense, ONE_AND_A_HALF);
+    }
+    let is_weather_boosted = match settings.weather {
+        Weather::Sand => !is_physical && generation >= 4 && defender.types.contains(&"rock"),
+        Weather::Snow => is_physical && generation >= 9 && defender.types.contains(&"ice"),
+        _ => false,
+    };
+    if is_weather_boosted {
+        defense = apply(defense, ONE_AND_A_HALF);
+    }
+
+    let mut power = damage_move.power;
+    let is_type_boosted = match settings.attacker_item {
+        AttackerItem::TypeBooster(boosted_type) => boosted_type == move_type,
+        _ => false,
+    } && settings.attacker_item.is_available(generation);
+    if is_type_boosted {
+        power = match generation >= 4 {
+            true => apply(power, 4915),
+            false => apply(power, 4506),
+        };
+    }
+    if settings.terrain.is_available(generation) {
+        power = apply(
+            power,
+            settings.terrain.get_power_modifier(move_type, generation),
+        );
+    }
+
+    // The early games kept stats in a byte here, so big ones were scaled down together
+    if generation <= 2 && (attack > 255 || defense > 255) {
+        attack /= 4;
+        defense /= 4;
+    }
+
+    let inputs = AttackInputs {
+        level: settings.attacker_spread.level,
+        power,
+        attack: attack.max(1),
+        defense: defense.max(1),
+        move_type,
+        is_stab: attacker.types.contains(&move_type),
+        effectiveness,
+    };
+    let rolls = match generation {
+        ..=2 => calculate_early_rolls(&inputs, settings, generation),
+        3 | 4 => calculate_advance_rolls(&inputs, settings, generation),
+        _ => calculate_modern_rolls(&inputs, settings, generation),
+    };
+
+    DamageResult {
+        rolls,
+        defender_hp: calculate_stat(
+            &settings.defender_spread,
+            HP,
+            &defender.base_stats,
+            generation,
+        ),
+        effectiveness,
+    }
+}
+
+fn get_base_damage(level: i32, inputs: &AttackInputs) -> i32 {
+    (2 * level / 5 + 2) * inputs.power * inputs.attack / inputs.defense / 50
+}
+
+fn apply_effectiveness(damage: i32, effectiveness: f32) -> i32 {
+    (damage as f32 * effectiveness) as i32
+}
+
+/// Generations 1 and 2: critical hits double the level in the first and the damage in the second,
+/// and the random roll goes from 217 to 255 out of 255 at the very end.
+fn calculate_early_rolls(
+    inputs: &AttackInputs,
+    settings: &DamageSettings,
+    generation: i32,
+) -> Vec<i32> {
+    let level = match settings.is_critical_hit && generation == 1 {
+        true => inputs.level * 2,
+        false => inputs.level,
+    };
+    let mut base = get_base_damage(level, inputs);
+    if settings.is_critical_hit && generation == 2 {
+        base *= 2;
+    }
+    base = base.min(997) + 2;
+    if settings.weather.is_available(generation) {
+        base = apply_floor(base, settings.weather.get_modifier(inputs.move_type));
+    }
+    if inputs.is_stab {
+        base = apply_floor(base, ONE_AND_A_HALF);
+    }
+    base = apply_effectiveness(base, inputs.effectiveness);
+
+    (217..=255)
+        .map(|roll| match base {
+            0 | 1 => base,
+            _ => base * roll / 255,
+        })
+        .collect()
+}
+
+/// Generations 3 and 4: the random roll comes between the critical hit and STAB.
+fn calculate_advance_rolls(
+    inputs: &AttackInputs,
+    settings: &DamageSettings,
+    generation: i32,
+) -> Vec<i32> {
+    let mut base = get_base_damage(inputs.level, inputs);
+    base = apply_floor(base, settings.weather.get_modifier(inputs.move_type));
+    base += 2;
+    if settings.is_critical_hit {
+        base *= 2;
+    }
+    if settings.attacker_item == AttackerItem::LifeOrb && generation >= 4 {
+        base = apply_floor(base, 5324);
+    }
+
+    (85..=100)
+        .map(|roll| {
+            let mut damage = base * roll / 100;
+            if inputs.is_stab {
+                damage = apply_floor(damage, ONE_AND_A_HALF);
+            }
+            damage = apply_effectiveness(damage, inputs.effectiveness);
+            if settings.attacker_item == AttackerItem::ExpertBelt
+                && generation >= 4
+                && inputs.effectiveness > 1.0
+            {
+                damage = apply_floor(damage, 4915);
+            }
+
+            match inputs.effectiveness > 0.0 {
+                true => damage.max(1),
+                false => 0,
+            }
+        })
+        .collect()
+}
+
+/// Generation 5 onwards: modifiers are in 4096ths and rounded, critical hits are 1.5x from generation 6.
+fn calculate_modern_rolls(
+    inputs: &AttackInputs,
+    settings: &DamageSettings,
+    generation: i32,
+) -> Vec<i32> {
+    let mut base = get_base_damage(inputs.level, inputs) + 2;
+    base = apply_round(base, settings.weather.get_modifier(inputs.move_type));
+    if settings.is_critical_hit {
+        base = match generation >= 6 {
+            true => apply_floor(base, ONE_AND_A_HALF),
+            false => apply_floor(base, DOUBLE),
+        };
+    }
+    let mut final_modifiers = vec![];
+    if settings.attacker_item == AttackerItem::LifeOrb {
+        final_modifiers.push(5324);
+    }
+    if settings.attacker_item == AttackerItem::ExpertBelt && inputs.effectiveness > 1.0 {
+        final_modifiers.push(4915);
+    }
+    let final_modifier = chain_modifiers(&final_modifiers);
+
+    (85..=100)
+        .map(|roll| {
+            let mut damage = base * roll / 100;
+            if inputs.is_stab {
+                damage = apply_round(damage, ONE_AND_A_HALF);
+            }
+            damage = apply_effectiveness(damage, inputs.effectiveness);
+            damage = apply_round(damage, final_modifier);
+
+            match inputs.effectiveness > 0.0 {
+                true => damage.max(1),
+                false => 0,
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum KoChance {
+    Never,
+    Guaranteed(usize),
+    Chance(usize, f64),
+    /// It takes more hits than are worth working out the chance of.
+    Slow(usize),
+}
+
+impl KoChance {
+    fn get_name(hits: usize) -> String {
+        match hits {
+            1 => String::from("OHKO"),
+            _ => format!("{}HKO", hits),
+        }
+    }
+
+    pub fn get_renderable(&self) -> String {
+        match self {
+            KoChance::Never => String::from("Can't KO"),
+            KoChance::Guaranteed(hits) => format!("Guaranteed {}", Self::get_name(*hits)),
+            KoChance::Chance(hits, chance) => {
+                format!("{:.1}% chance to {}", chance * 100.0, Self::get_name(*hits))
+            }
+            KoChance::Slow(hits) => format!("{} or more hits to KO", hits),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DamageResult {
+    pub rolls: Vec<i32>,
+    pub defender_hp: i32,
+    pub effectiveness: f32,
+}
+
+impl DamageResult {
+    pub fn get_min(&self) -> i32 {
+        self.rolls.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn get_max(&self) -> i32 {
+        self.rolls.iter().copied().max().unwrap_or_default()
+    }
+
+    fn get_percentage(&self, damage: i32) -> f64 {
+        damage as f64 * 100.0 / self.defender_hp.max(1) as f64
+    }
+
+    /// The fewest hits that can KO, and how likely they are to, every roll being as likely.
+    pub fn get_ko_chance(&self) -> KoChance {
+        let (min, max) = (self.get_min(), self.get_max());
+        if max <= 0 {
+            return KoChance::Never;
+        }
+        let hp = self.defender_hp.max(1) as usize;
+        let hits = hp.div_ceil(max as usize);
+        if min as usize * hits >= hp {
+            return KoChance::Guaranteed(hits);
+        }
+        if hits > MAX_KO_HITS {
+            return KoChance::Slow(hits);
+        }
+
+        // How likely every total is after each hit, anything from the HP up being a KO
+        let mut totals = vec![0.0; hp + 1];
+        totals[0] = 1.0;
+        for _ in 0..hits {
+            let mut next_totals = vec![0.0; hp + 1];
+            for (total, chance) in totals
+                .iter()
+                .enumerate()
+                .filter(|(_, chance)| **chance > 0.0)
+            {
+                for roll in &self.rolls {
+                    next_totals[(total + *roll as usize).min(hp)] +=
+                        chance / self.rolls.len() as f64;
+                }
+            }
+            totals = next_totals;
+        }
+
+        KoChance::Chance(hits, totals[hp])
+    }
+
+    pub fn get_renderable_range(&self) -> String {
+        let (min, max) = (self.get_min(), self.get_max());
+
+        format!(
+            "{} - {} ({:.1}% - {:.1}%)",
+            min,
+            max,
+            self.get_percentage(min),
+            self.get_percentage(max)
+        )
+    }
+}
+
+/// The Pokemon either side can be picked from, the attacker's move and what's been set since.
+/// It's opened with two Pokemon at least, so there's always one to attack and one to defend.
+pub struct DamageCalculator {
+    /// The page's Pokemon first, then the other marked ones.
+    pub pokemon: Vec<DamagePokemon>,
+    pub attacker_index: usize,
+    pub defender_index: usize,
+    /// Index in the attacker's moves.
+    pub move_index: usize,
+    pub settings: DamageSettings,
+    pub generation: i32,
+}
+
+impl DamageCalculator {
+    /// The first Pokemon uses `move_name` on the second, or its first damaging move when it
+    /// doesn't learn it.
+    pub fn new(pokemon: Vec<DamagePokemon>, move_name: &str, generation: i32) -> Self {
+        let mut damage_calculator = Self {
+            pokemon,
+            attacker_index: 0,
+            defender_index: 1,
+            move_index: 0,
+            settings: DamageSettings::new(StatSpread::default(), StatSpread::default()),
+            generation,
+        };
+        damage_calculator.reset_attacker();
+        damage_calculator.reset_defender();
+        damage_calculator.move_index = damage_calculator
+            .get_attacker()
+            .moves
+            .iter()
+            .position(|damage_move| damage_move.name == move_name)
+            .unwrap_or_default();
+
+        damage_calculator
+    }
+
+    pub fn get_attacker(&self) -> &DamagePokemon {
+        &self.pokemon[self.attacker_index]
+    }
+
+    pub fn get_defender(&self) -> &DamagePokemon {
+        &self.pokemon[self.defender_index]
+    }
+
+    /// Nothing when the attacker has no damaging move to pick.
+    pub fn get_move(&self) -> Option<&DamageMove> {
+        self.get_attacker().moves.get(self.move_index)
+    }
+
+    /// Indexes in `STAT_NAMES` of the stat the move attacks with and the one it hits.
+    fn get_stat_indexes(&self) -> (usize, usize) {
+        self.get_move()
+            .map(|damage_move| damage_move.get_stat_indexes(self.generation))
+            .unwrap_or((ATTACK, DEFENSE))
+    }
+
+    /// A newly picked attacker starts over from its own spread, item and first move.
+    fn reset_attacker(&mut self) {
+        let attacker = &self.pokemon[self.attacker_index];
+        self.settings.attacker_spread = attacker.stat_spread.clone();
+        self.settings.attacker_item = attacker
+            .item
+            .as_deref()
+            .map(AttackerItem::from_name)
+            .unwrap_or(AttackerItem::None);
+        self.settings.attacker_stage = 0;
+        self.move_index = 0;
+    }
+
+    fn reset_defender(&mut self) {
+        let defender = &self.pokemon[self.defender_index];
+        self.settings.defender_spread = defender.stat_spread.clone();
+        self.settings.defender_item = defender
+            .item
+            .as_deref()
+            .map(DefenderItem::from_name)
+            .unwrap_or(DefenderItem::None);
+        self.settings.defender_stage = 0;
+    }
+
+    /// Picking the Pokemon on the other side swaps the two.
+    fn pick_attacker(&mut self, delta: i32) {
+        let attacker_index =
+            (self.attacker_index as i32 + delta).rem_euclid(self.pokemon.len() as i32) as usize;
+        if attacker_index == self.defender_index {
+            self.defender_index = self.attacker_index;
+            self.reset_defender();
+        }
+        self.attacker_index = attacker_index;
+        self.reset_attacker();
+    }
+
+    fn pick_defender(&mut self, delta: i32) {
+        let defender_index =
+            (self.defender_index as i32 + delta).rem_euclid(self.pokemon.len() as i32) as usize;
+        if defender_index == self.attacker_index {
+            self.attacker_index = self.defender_index;
+            self.reset_attacker();
+        }
+        self.defender_index = defender_index;
+        self.reset_defender();
+    }
+
+    pub fn calculate(&self) -> Option<DamageResult> {
+        let damage_move = self.get_move()?;
+        let defender = self.get_defender();
+
+        Some(calculate_damage(
+            &Combatant::new(self.get_attacker()),
+            &Combatant::new(defender),
+            defender.get_multiplier(&damage_move.move_type),
+            damage_move,
+            &self.settings,
+            self.generation,
+        ))
+    }
+
+    /// What `+` and `-` do to the row at `index`. IVs and EVs change like in the stat
+    /// calculator, DVs by one and stat experience without the total EV cap in generations 1 and 2.
+    pub fn change_setting(&mut self, index: usize, delta: i32) {
+        let (attack_index, defense_index) = self.get_stat_indexes();
+        let generation = self.generation;
+        let change_stage = |stage: i32| (stage + delta).clamp(-MAX_STAT_STAGE, MAX_STAT_STAGE);
+        let change_nature = |spread: &mut StatSpread| {
+            if generation >= 3 {
+                spread.nature = cycle(&Nature::ALL, spread.nature, delta, |_| true);
+            }
+        };
+        // The HP DV is made of the others, so there's nothing to change about it
+        let change_iv = |spread: &mut StatSpread, stat_index: usize| match generation <= 2 {
+            true if stat_index == HP => {}
+            true => spread.change_iv(get_spread_index(stat_index, generation), delta * 2),
+            false => spread.change_iv(stat_index, delta),
+        };
+        let change_ev = |spread: &mut StatSpread, stat_index: usize| match generation <= 2 {
+            true => {
+                let ev = &mut spread.evs[get_spread_index(stat_index, generation)];
+                *ev = (*ev + delta * 4).clamp(0, MAX_EV);
+            }
+            false => spread.change_ev(stat_index, delta * 4),
+        };
+        let settings = &mut self.settings;
+
+        match index {
+            0 => self.pick_attacker(delta),
+            1 => {
+                let moves_count = self.get_attacker().moves.len().max(1) as i32;
+                self.move_index = (self.move_index as i32 + delta).rem_euclid(moves_count) as usize;
+            }
+            2 => settings.attacker_spread.change_level(delta),
+            3 => change_nature(&mut settings.attacker_spread),
+            4 => change_iv(&mut settings.attacker_spread, attack_index),
+            5 => change_ev(&mut settings.attacker_spread, attack_index),
+            6 => settings.attacker_stage = change_stage(settings.attacker_stage),
+            7 => settings.attacker_item = settings.attacker_item.change(delta, generation),
+            8 => self.pick_defender(delta),
+            9 => settings.defender_spread.change_level(delta),
+            10 => change_nature(&mut settings.defender_spread),
+            11 => change_iv(&mut settings.defender_spread, HP),
+            12 => change_ev(&mut settings.defender_spread, HP),
+            13 => change_iv(&mut settings.defender_spread, defense_index),
+            14 => change_ev(&mut settings.defender_spread, defense_index),
+            15 => settings.defender_stage = change_stage(settings.defender_stage),
+            16 => settings.defender_item = settings.defender_item.change(delta, generation),
+            17 => settings.weather = settings.weather.change(delta, generation),
+            18 => settings.terrain = settings.terrain.change(delta, generation),
+            19 => settings.is_critical_hit = !settings.is_critical_hit,
+            _ => {}
+        }
+    }
+
+    pub fn get_renderable_settings(&self) -> Vec<Row<'static>> {
+        let (attack_index, defense_index) = self.get_stat_indexes();
+        let attack_name = get_stat_abbreviation(STAT_NAMES[attack_index]);
+        let defense_name = get_stat_abbreviation(STAT_NAMES[defense_index]);
+        let settings = &self.settings;
+        let is_early = self.generation <= 2;
+        let (iv_label, ev_label) = match is_early {
+            true => ("DVs", "stat exp"),
+            false => ("IVs", "EVs"),
+        };
+        let get_renderable_stage = |stage: i32| match stage > 0 {
+            true => format!("+{}", stage),
+            false => stage.to_string(),
+        };
+        let get_renderable_nature = |spread: &StatSpread| match is_early {
+            true => String::from("None"),
+            false => spread.nature.get_name(),
+        };
+        let get_renderable_iv = |spread: &StatSpread, stat_index: usize| match is_early {
+            true => get_dv(spread, stat_index).to_string(),
+            false => spread.ivs[stat_index].to_string(),
+        };
+        let get_renderable_ev = |spread: &StatSpread, stat_index: usize| match is_early {
+            true => get_stat_exp(spread, stat_index).to_string(),
+            false => spread.evs[stat_index].to_string(),
+        };
+        let damage_move = self
+            .get_move()
+            .map(|damage_move| {
+                format!(
+                    "{} ({})",
+                    damage_move.name.clone().split_capitalize(),
+                    damage_move.power
+                )
+            })
+            .unwrap_or_else(|| String::from("None"));
+
+        [
+            (
+                String::from("Attacker"),
+                self.get_attacker().name.clone().split_capitalize(),
+            ),
+            (String::from("Move"), damage_move),
+            (
+                String::from("Attacker level"),
+                settings.attacker_spread.level.to_string(),
+            ),
+            (
+                String::from("Attacker nature"),
+                get_renderable_nature(&settings.attacker_spread),
+            ),
+            (
+                format!("Attacker {} {}", attack_name, iv_label),
+                get_renderable_iv(&settings.attacker_spread, attack_index),
+            ),
+            (
+                format!("Attacker {} {}", attack_name, ev_label),
+                get_renderable_ev(&settings.attacker_spread, attack_index),
+            ),
+            (
+                format!("Attacker {} stage", attack_name),
+                get_renderable_stage(settings.attacker_stage),
+            ),
+            (
+                String::from("Attacker item"),
+                settings.attacker_item.get_name().to_string(),
+            ),
+            (
+                String::from("Defender"),
+                self.get_defender().name.clone().split_capitalize(),
+            ),
+            (
+                String::from("Defender level"),
+                settings.defender_spread.level.to_string(),
+            ),
+            (
+                String::from("Defender nature"),
+                get_renderable_nature(&settings.defender_spread),
+            ),
+            (
+                format!("Defender HP {}", iv_label),
+                get_renderable_iv(&settings.defender_spread, HP),
+            ),
+            (
+                format!("Defender HP {}", ev_label),
+                get_renderable_ev(&settings.defender_spread, HP),
+            ),
+            (
+                format!("Defender {} {}", defense_name, iv_label),
+                get_renderable_iv(&settings.defender_spread, defense_index),
+            ),
+            (
+                format!("Defender {} {}", defense_name, ev_label),
+                get_renderable_ev(&settings.defender_spread, defense_index),
+            ),
+            (
+                format!("Defender {} stage", defense_name),
+                get_renderable_stage(settings.defender_stage),
+            ),
+            (
+                String::from("Defender item"),
+                settings.defender_item.get_name().to_string(),
+            ),
+            (String::from("Weather"), settings.weather.get_name()),
+            (String::from("Terrain"), settings.terrain.get_name()),
+            (
+                String::from("Critical hit"),
+                String::from(match settings.is_critical_hit {
+                    true => "Yes",
+                    false => "No",
+                }),
+            ),
+        ]
+        .into_iter()
+        .map(|(label, value)| {
+            Row::new(vec![
+                Span::styled(label.append_padding(), Style::default().fg(Color::Blue)),
+                Span::raw(value),
+            ])
+        })
+        .collect()
+    }
+
+    pub fn get_renderable_result(&self) -> Vec<Spans<'static>> {
+        let (attacker, defender) = (self.get_attacker(), self.get_defender());
+        let label = |label: String| Span::styled(label, Style::default().fg(Color::Blue));
+        let footer = Spans::from(Span::styled(
+            format!(
+                "Generation {} formula, abilities aren't accounted for",
+                self.generation
+            ),
+            Style::default().fg(Color::DarkGray),
+        ));
+        let (damage_move, result) = match (self.get_move(), self.calculate()) {
+            (Some(damage_move), Some(result)) => (damage_move, result),
+            _ => {
+                return vec![
+                    Spans::from(format!(
+                        "{} has no damaging move to work out in this version group.",
+                        attacker.name.clone().split_capitalize()
+                    )),
+                    Spans::from(""),
+                    footer,
+                ]
+            }
+        };
+        let (attack_index, defense_index) = self.get_stat_indexes();
+        let get_stat = |spread: &StatSpread, stat_index: usize, pokemon: &DamagePokemon| {
+            calculate_stat(spread, stat_index, &pokemon.base_stats, self.generation).to_string()
+        };
+
+        vec![
+            Spans::from(Span::styled(
+                format!(
+                    "{} {} vs. {}",
+                    attacker.name.clone().split_capitalize(),
+                    damage_move.name.clone().split_capitalize(),
+                    defender.name.clone().split_capitalize()
+                ),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Spans::from(""),
+            Spans::from(vec![
+                label(String::from("Damage: ")),
+                Span::raw(result.get_renderable_range()),
+            ]),
+            Spans::from(vec![
+                label(String::from("KO: ")),
+                Span::raw(result.get_ko_chance().get_renderable()),
+            ]),
+            Spans::from(vec![
+                label(format!(
+                    "Attacker {}: ",
+                    get_stat_abbreviation(STAT_NAMES[attack_index])
+                )),
+                Span::raw(get_stat(
+                    &self.settings.attacker_spread,
+                    attack_index,
+                    attacker,
+                )),
+            ]),
+            Spans::from(vec![
+                label(format!(
+                    "Defender {}: ",
+                    get_stat_abbreviation(STAT_NAMES[defense_index])
+                )),
+                Span::raw(get_stat(
+                    &self.settings.defender_spread,
+                    defense_index,
+                    defender,
+                )),
+            ]),
+            Spans::from(vec![
+                label(String::from("Defender HP: ")),
+                Span::raw(result.defender_hp.to_string()),
+            ]),
+            Spans::from(vec![
+                label(String::from("Effectiveness: ")),
+                Span::raw(format!("{}x", result.effectiveness)),
+            ]),
+            Spans::from(vec![
+                label(String::from("Rolls: ")),
+                Span::raw(
+                    result
+                        .rolls
+                        .iter()
+                        .map(|roll| roll.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                ),
+            ]),
+            Spans::from(""),
+            footer,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::models::{Nature, StatSpread};
+
+    use super::{
+        apply_stat_stage, calculate_advance_rolls, calculate_damage, calculate_modern_rolls,
+        calculate_stat, AttackInputs, AttackerItem, Combatant, DamageCalculator, DamageMove,
+        DamagePokemon, DamageResult, DamageSettings, KoChance, Weather,
+    };
+
+    fn get_stub_inputs() -> AttackInputs<'static> {
+        // Bulbapedia's example: a level 75 Glaceon's Ice Fang against a Garchomp
+        AttackInputs {
+            level: 75,
+            power: 65,
+            attack: 123,
+            defense: 163,
+            move_type: "ice",
+            is_stab: true,
+            effectiveness: 4.0,
+        }
+    }
+
+    fn get_stub_settings() -> DamageSettings {
+        DamageSettings::new(StatSpread::default(), StatSpread::default())
+    }
+
+    fn get_stub_pokemon(name: &str, moves: Vec<DamageMove>) -> DamagePokemon {
+        DamagePokemon {
+            name: name.to_string(),
+            base_stats: [35, 55, 40, 50, 50, 90],
+            types: vec![String::from("electric")],
+            multipliers: HashMap::new(),
+            moves,
+            stat_spread: StatSpread::default(),
+            item: None,
+        }
+    }
+
+    #[test]
+    fn damage_calculator_apply_stat_stage() {
+        assert_eq!(apply_stat_stage(100, 0), 100);
+        assert_eq!(apply_stat_stage(100, 1), 150);
+        assert_eq!(apply_stat_stage(100, 6), 400);
+        assert_eq!(apply_stat_stage(100, -1), 66);
+        assert_eq!(apply_stat_stage(100, -6), 25);
+        assert_eq!(apply_stat_stage(100, 9), 400);
+    }
+
+    #[test]
+    fn damage_calculator_calculate_early_stat() {
+        // A level 100 Mewtwo with every DV and all the stat experience, nature doesn't matter
+        let base_stats = [106, 110, 90, 154, 90, 130];
+        let mut spread = StatSpread {
+            level: 100,
+            nature: Nature::Modest,
+            evs: [252; 6],
+            ..StatSpread::default()
+        };
+        let stats = [0, 1, 3, 4, 5].map(|index| calculate_stat(&spread, index, &base_stats, 1));
+        assert_eq!(stats, [415, 318, 406, 278, 358]);
+        assert_eq!(calculate_stat(&spread, 3, &base_stats, 3), 447);
+
+        // The HP DV is made of the others, an even Defense DV takes 4 off it
+        spread.ivs[2] = 28;
+        assert_eq!(calculate_stat(&spread, 0, &base_stats, 2), 407);
+        // Both specials share the Special Attack DV
+        spread.ivs[4] = 0;
+        assert_eq!(calculate_stat(&spread, 4, &base_stats, 2), 278);
+    }
+
+    #[test]
+    fn damage_calculator_early_critical_hits_ignore_stages() {
+        let attacker = Combatant {
+            base_stats: [35, 55, 40, 50, 50, 90],
+            types: vec!["electric"],
+        };
+        let defender = Combatant {
+            base_stats: [44, 48, 65, 50, 64, 43],
+            types: vec!["water"],
+        };
+        let damage_move = DamageMove {
+            name: String::from("thunderbolt"),
+            power: 90,
+            move_type: String::from("electric"),
+            damage_class: String::from("special"),
+        };
+        let get_max = |attacker_stage: i32, defender_stage: i32, generation: i32| {
+            let mut settings = get_stub_settings();
+            settings.attacker_stage = attacker_stage;
+            settings.defender_stage = defender_stage;
+            settings.is_critical_hit = true;
+
+            calculate_damage(
+                &attacker,
+                &defender,
+                2.0,
+                &damage_move,
+                &settings,
+                generation,
+            )
+            .get_max()
+        };
+
+        assert_eq!(get_max(2, 0, 1), get_max(0, 0, 1));
+        assert_eq!(get_max(-2, 2, 2), get_max(0, 0, 2));
+        assert!(get_max(2, 0, 2) > get_max(0, 0, 2));
+        assert_eq!(get_max(-2, 0, 3), get_max(0, 0, 3));
+        assert!(get_max(2, 0, 3) > get_max(0, 0, 3));
+    }
+
+    #[test]
+    fn damage_calculator_change_setting() {
+        let tackle = DamageMove {
+            name: String::from("tackle"),
+            power: 40,
+            move_type: String::from("normal"),
+            damage_class: String::from("physical"),
+        };
+        let thunderbolt = DamageMove {
+            name: String::from("thunderbolt"),
+            power: 90,
+            move_type: String::from("electric"),
+            damage_class: String::from("special"),
+        };
+        let mut pikachu = get_stub_pokemon("pikachu", vec![tackle.clone(), thunderbolt.clone()]);
+        pikachu.item = Some(String::from("choice-specs"));
+        let pokemon = vec![
+            pikachu,
+            get_stub_pokemon("squirtle", vec![]),
+            get_stub_pokemon("pichu", vec![]),
+        ];
+        let mut damage_calculator = DamageCalculator::new(pokemon, "thunderbolt", 2);
+        assert_eq!(damage_calculator.get_move(), Some(&thunderbolt));
+        assert_eq!(
+            damage_calculator.settings.attacker_item,
+            AttackerItem::ChoiceSpecs
+        );
+        damage_calculator.change_setting(1, 1);
+        assert_eq!(damage_calculator.get_move(), Some(&tackle));
+
+        // No natures in generation 2, DVs go by one and the HP one is made of the others
+        damage_calculator.change_setting(3, 1);
+        assert_eq!(
+            damage_calculator.settings.attacker_spread.nature,
+            Nature::Hardy
+        );
+        damage_calculator.change_setting(13, -1);
+        damage_calculator.change_setting(11, -1);
+        assert_eq!(
+            damage_calculator.settings.defender_spread.ivs[..3],
+            [31, 31, 29]
+        );
+
+        // Both specials share their stat experience
+        damage_calculator.change_setting(1, -1);
+        damage_calculator.change_setting(14, 1);
+        assert_eq!(damage_calculator.settings.defender_spread.evs[3..5], [4, 0]);
+
+        // Picking the attacker as the defender swaps the two
+        damage_calculator.change_setting(8, 1);
+        assert_eq!(damage_calculator.get_defender().name, "pichu");
+        damage_calculator.change_setting(8, 1);
+        assert_eq!(damage_calculator.get_attacker().name, "pichu");
+        assert_eq!(damage_calculator.get_defender().name, "pikachu");
+        assert_eq!(damage_calculator.settings.attacker_item, AttackerItem::None);
+        assert_eq!(damage_calculator.get_move(), None);
+        assert!(damage_calculator.calculate().is_none());
+    }
+
+    #[test]
+    fn damage_calculator_matches_the_games() {
+        let settings = get_stub_settings();
+        let rolls = calculate_advance_rolls(&get_stub_inputs(), &settings, 4);
+
+        assert_eq!(rolls.len(), 16);
+        assert_eq!(rolls.first(), Some(&168));
+        assert_eq!(rolls.last(), Some(&196));
+
+        let rolls = calculate_modern_rolls(&get_stub_inputs(), &settings, 9);
+        assert_eq!((rolls[0], rolls[15]), (168, 196));
+    }
+
+    #[test]
+    fn damage_calculator_applies_generation_modifiers() {
+        let mut settings = get_stub_settings();
+        settings.is_critical_hit = true;
+
+        assert_eq!(
+            calculate_advance_rolls(&get_stub_inputs(), &settings, 4)[15],
+            396
+        );
+        assert_eq!(
+            calculate_modern_rolls(&get_stub_inputs(), &settings, 5)[15],
+            396
+        );
+        assert_eq!(
+            calculate_modern_rolls(&get_stub_inputs(), &settings, 6)[15],
+            292
+        );
+
+        settings.is_critical_hit = false;
+        settings.weather = Weather::Rain;
+        settings.attacker_item = AttackerItem::LifeOrb;
+        let mut inputs = get_stub_inputs();
+        inputs.move_type = "fire";
+        inputs.is_stab = false;
+        inputs.effectiveness = 1.0;
+        // 33 halved by rain is 16, then 1.3x from the Life Orb
+        assert_eq!(calculate_modern_rolls(&inputs, &settings, 9)[15], 21);
+    }
+
+    #[test]
+    fn damage_calculator_calculate_damage() {
+        let attacker = Combatant {
+            base_stats: [35, 55, 40, 50, 50, 90],
+            types: vec!["electric"],
+        };
+        let defender = Combatant {
+            base_stats: [44, 48, 65, 50, 64, 43],
+            types: vec!["water"],
+        };
+        let damage_move = DamageMove {
+            name: String::from("thunderbolt"),
+            power: 90,
+            move_type: String::from("electric"),
+            damage_class: String::from("special"),
+        };
+        let settings = get_stub_settings();
+        let result = calculate_damage(&attacker, &defender, 2.0, &damage_move, &settings, 9);
+
+        assert_eq!(result.defender_hp, 119);
+        assert_eq!(result.get_min(), 86);
+        assert_eq!(result.get_max(), 104);
+        assert!(!damage_move.is_physical(3));
+
+        let immune = calculate_damage(&attacker, &defender, 0.0, &damage_move, &settings, 9);
+        assert_eq!(immune.get_max(), 0);
+        assert_eq!(immune.get_ko_chance(), KoChance::Never);
+    }
+
+    #[test]
+    fn damage_calculator_type_boosters_match_the_move() {
+        let attacker = Combatant {
+            base_stats: [35, 55, 40, 50, 50, 90],
+            types: vec!["electric"],
+        };
+        let defender = Combatant {
+            base_stats: [44, 48, 65, 50, 64, 43],
+            types: vec!["water"],
+        };
+        let damage_move = DamageMove {
+            name: String::from("thunderbolt"),
+            power: 90,
+            move_type: String::from("electric"),
+            damage_class: String::from("special"),
+        };
+        let get_max = |item_name: &str| {
+            let mut settings = get_stub_settings();
+            settings.attacker_item = AttackerItem::from_name(item_name);
+
+            calculate_damage(&attacker, &defender, 2.0, &damage_move, &settings, 9).get_max()
+        };
+
+        assert_eq!(
+            AttackerItem::from_name("charcoal"),
+            AttackerItem::TypeBooster("fire")
+        );
+        assert_eq!(AttackerItem::TypeBooster("fire").get_name(), "Charcoal");
+        assert_eq!(get_max("charcoal"), get_max("none"));
+        assert!(get_max("magnet") > get_max("none"));
+    }
+
+    #[test]
+    fn damage_calculator_get_ko_chance() {
+        let get_result = |rolls: Vec<i32>, defender_hp: i32| DamageResult {
+            rolls,
+            defender_hp,
+            effectiveness: 1.0,
+        };
+
+        assert_eq!(
+            get_result(vec![50, 60], 50).get_ko_chance(),
+            KoChance::Guaranteed(1)
+        );
+        assert_eq!(
+            get_result(vec![40, 60], 50).get_ko_chance(),
+            KoChance::Chance(1, 0.5)
+        );
+        assert_eq!(
+            get_result(vec![20, 30], 50).get_ko_chance(),
+            KoChance::Chance(2, 0.75)
+        );
+        assert_eq!(
+            get_result(vec![1], 500).get_ko_chance(),
+            KoChance::Guaranteed(500)
+        );
+        assert_eq!(
+            get_result(vec![1, 2], 500).get_ko_chance(),
+            KoChance::Slow(250)
+        );
+        assert_eq!(
+            KoChance::Chance(2, 0.75).get_renderable(),
+            "75.0% chance to 2HKO"
+        );
+        assert_eq!(KoChance::Guaranteed(1).get_renderable(), "Guaranteed OHKO");
+    }
+}
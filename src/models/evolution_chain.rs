@@ -0,0 +1,330 @@
+use serde::{Deserialize, Serialize};
+use tui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::Row,
+};
+
+use crate::utils::PrepareForDisplay;
+
+use super::NamedApiResource;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EvolutionChain {
+    pub id: Option<i32>,
+    pub baby_trigger_item: Option<NamedApiResource>,
+    pub chain: Option<ChainLink>,
+}
+
+impl EvolutionChain {
+    /// Flattens the tree depth-first, so every stage gets one row and branches stay
+    /// below the stage they evolve from.
+    pub fn get_stages(&self) -> Vec<EvolutionStage<'_>> {
+        let mut stages = vec![];
+
+        if let Some(chain) = self.chain.as_ref() {
+            chain.collect_stages(String::new(), None, &mut stages);
+        }
+
+        stages
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChainLink {
+    pub is_baby: Option<bool>,
+    pub species: Option<NamedApiResource>,
+    pub evolution_details: Option<Vec<EvolutionDetail>>,
+    pub evolves_to: Option<Vec<ChainLink>>,
+}
+
+impl ChainLink {
+    /// `prefix` continues the tree lines of the ancestors, `is_last` is `None` for the root.
+    fn collect_stages<'a>(
+        &'a self,
+        prefix: String,
+        is_last: Option<bool>,
+        stages: &mut Vec<EvolutionStage<'a>>,
+    ) {
+        let (branch, children_prefix) = match is_last {
+            None => (String::new(), prefix),
+            Some(true) => (format!("{}└─ ", prefix), format!("{}   ", prefix)),
+            Some(false) => (format!("{}├─ ", prefix), format!("{}│  ", prefix)),
+        };
+
+        stages.push(EvolutionStage { branch, link: self });
+
+        let evolves_to = self.evolves_to.as_deref().unwrap_or_default();
+        for (i, next_link) in evolves_to.iter().enumerate() {
+            next_link.collect_stages(
+                children_prefix.clone(),
+                Some(i == evolves_to.len() - 1),
+                stages,
+            );
+        }
+    }
+
+    fn get_renderable_conditions(&self) -> String {
+        self.evolution_details
+            .as_ref()
+            .map(|evolution_details| {
+                evolution_details
+                    .iter()
+                    .map(|evolution_detail| evolution_detail.get_renderable_conditions())
+                    .filter(|conditions| !conditions.is_empty())
+                    .collect::<Vec<String>>()
+                    .join(" or ")
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// One row of the rendered tree: a species together with the tree lines leading to it.
+pub struct EvolutionStage<'a> {
+    pub branch: String,
+    pub link: &'a ChainLink,
+}
+
+impl EvolutionStage<'_> {
+    pub fn get_species(&self) -> Option<&NamedApiResource> {
+        self.link.species.as_ref()
+    }
+
+    pub fn get_renderable_as_row(&self, current_species: &str) -> Row<'static> {
+        let species_name = self
+            .get_species()
+            .map(|species| species.get_name_or_stub())
+            .unwrap_or_default();
+        let mut name_style = Style::default().fg(Color::Blue);
+        if species_name == current_species {
+            name_style = Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD);
+        }
+
+        let mut name = format!("{}{}", self.branch, species_name.split_capitalize());
+        if self.link.is_baby.unwrap_or(false) {
+            name.push_str(" (baby)");
+        }
+
+        Row::new(vec![
+            Span::styled(name.append_padding(), name_style),
+            Span::raw(self.link.get_renderable_conditions()),
+        ])
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EvolutionDetail {
+    pub item: Option<NamedApiResource>,
+    pub trigger: Option<NamedApiResource>,
+    pub gender: Option<i32>,
+    pub held_item: Option<NamedApiResource>,
+    pub known_move: Option<NamedApiResource>,
+    pub known_move_type: Option<NamedApiResource>,
+    pub location: Option<NamedApiResource>,
+    pub min_level: Option<i32>,
+    pub min_happiness: Option<i32>,
+    pub min_beauty: Option<i32>,
+    pub min_affection: Option<i32>,
+    pub needs_overworld_rain: Option<bool>,
+    pub party_species: Option<NamedApiResource>,
+    pub party_type: Option<NamedApiResource>,
+    pub relative_physical_stats: Option<i32>,
+    pub time_of_day: Option<String>,
+    pub trade_species: Option<NamedApiResource>,
+    pub turn_upside_down: Option<bool>,
+}
+
+impl EvolutionDetail {
+    pub fn get_renderable_conditions(&self) -> String {
+        let display_name =
+            |resource: &NamedApiResource| resource.get_name_or_stub().split_capitalize();
+        let mut conditions = vec![];
+
+        match self
+            .trigger
+            .as_ref()
+            .map(|trigger| trigger.get_name_or_stub())
+        {
+            Some(trigger) if trigger == "level-up" => conditions.push(
+                self.min_level
+                    .map(|min_level| format!("Level {}", min_level))
+                    .unwrap_or_else(|| String::from("Level up")),
+            ),
+            Some(trigger) if trigger == "use-item" => {
+                if let Some(item) = self.item.as_ref() {
+                    conditions.push(format!("Use {}", display_name(item)));
+                }
+            }
+            Some(trigger) => conditions.push(trigger.split_capitalize()),
+            None => {}
+        }
+
+        if let Some(held_item) = self.held_item.as_ref() {
+            conditions.push(format!("holding {}", display_name(held_item)));
+        }
+        if let Some(trade_species) = self.trade_species.as_ref() {
+            conditions.push(format!("for {}", display_name(trade_species)));
+        }
+        if let Some(min_happiness) = self.min_happiness {
+            conditions.push(format!("happiness {}+", min_happiness));
+        }
+        if let Some(min_affection) = self.min_affection {
+            conditions.push(format!("affection {}+", min_affection));
+        }
+        if let Some(min_beauty) = self.min_beauty {
+            conditions.push(format!("beauty {}+", min_beauty));
+        }
+        if let Some(time_of_day) = self.time_of_day.as_ref().filter(|time| !time.is_empty()) {
+            conditions.push(format!("at {}", time_of_day));
+        }
+        if let Some(known_move) = self.known_move.as_ref() {
+            conditions.push(format!("knowing {}", display_name(known_move)));
+        }
+        if let Some(known_move_type) = self.known_move_type.as_ref() {
+            conditions.push(format!("knowing a {} move", display_name(known_move_type)));
+        }
+        if let Some(location) = self.location.as_ref() {
+            conditions.push(format!("in {}", display_name(location)));
+        }
+        if let Some(party_species) = self.party_species.as_ref() {
+            conditions.push(format!("with {} in party", display_name(party_species)));
+        }
+        if let Some(party_type) = self.party_type.as_ref() {
+            conditions.push(format!("with a {} type in party", display_name(party_type)));
+        }
+        match self.gender {
+            Some(1) => conditions.push(String::from("female")),
+            Some(2) => conditions.push(String::from("male")),
+            _ => {}
+        }
+        match self.relative_physical_stats {
+            Some(1) => conditions.push(String::from("Attack > Defense")),
+            Some(0) => conditions.push(String::from("Attack = Defense")),
+            Some(-1) => conditions.push(String::from("Attack < Defense")),
+            _ => {}
+        }
+        if self.needs_overworld_rain.unwrap_or(false) {
+            conditions.push(String::from("while raining"));
+        }
+        if self.turn_upside_down.unwrap_or(false) {
+            conditions.push(String::from("upside down"));
+        }
+
+        conditions.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tui::{
+        style::{Color, Modifier, Style},
+        text::Span,
+        widgets::Row,
+    };
+
+    use super::{EvolutionChain, EvolutionDetail};
+
+    fn get_stub_evolution_chain() -> EvolutionChain {
+        serde_json::from_value(json!({
+            "id": 67,
+            "baby_trigger_item": null,
+            "chain": {
+                "is_baby": false,
+                "species": { "name": "eevee", "url": "https://pokeapi.co/api/v2/pokemon-species/133/" },
+                "evolution_details": [],
+                "evolves_to": [
+                    {
+                        "is_baby": false,
+                        "species": { "name": "vaporeon", "url": "https://pokeapi.co/api/v2/pokemon-species/134/" },
+                        "evolution_details": [{
+                            "trigger": { "name": "use-item", "url": null },
+                            "item": { "name": "water-stone", "url": null },
+                            "time_of_day": ""
+                        }],
+                        "evolves_to": []
+                    },
+                    {
+                        "is_baby": false,
+                        "species": { "name": "espeon", "url": "https://pokeapi.co/api/v2/pokemon-species/196/" },
+                        "evolution_details": [{
+                            "trigger": { "name": "level-up", "url": null },
+                            "min_happiness": 160,
+                            "time_of_day": "day"
+                        }],
+                        "evolves_to": []
+                    }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    fn get_stub_evolution_detail() -> EvolutionDetail {
+        serde_json::from_value(json!({
+            "trigger": { "name": "trade", "url": null },
+            "held_item": { "name": "metal-coat", "url": null },
+            "time_of_day": ""
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn evolution_chain_get_stages() {
+        let evolution_chain = get_stub_evolution_chain();
+        let stages = evolution_chain.get_stages();
+
+        assert_eq!(
+            stages
+                .iter()
+                .map(|stage| (
+                    stage.branch.as_str(),
+                    stage.get_species().unwrap().get_name_or_stub()
+                ))
+                .collect::<Vec<(&str, String)>>(),
+            vec![
+                ("", String::from("eevee")),
+                ("├─ ", String::from("vaporeon")),
+                ("└─ ", String::from("espeon")),
+            ]
+        );
+    }
+
+    #[test]
+    fn evolution_stage_get_renderable_as_row() {
+        let evolution_chain = get_stub_evolution_chain();
+        let stages = evolution_chain.get_stages();
+
+        assert_eq!(
+            stages[1].get_renderable_as_row("vaporeon"),
+            Row::new(vec![
+                Span::styled(
+                    "\u{A0}├─ Vaporeon",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                ),
+                Span::raw("Use Water Stone"),
+            ])
+        );
+        assert_eq!(
+            stages[2].get_renderable_as_row("vaporeon"),
+            Row::new(vec![
+                Span::styled("\u{A0}└─ Espeon", Style::default().fg(Color::Blue)),
+                Span::raw("Level up, happiness 160+, at day"),
+            ])
+        );
+    }
+
+    #[test]
+    fn evolution_detail_get_renderable_conditions() {
+        let evolution_detail = get_stub_evolution_detail();
+
+        assert_eq!(
+            evolution_detail.get_renderable_conditions(),
+            String::from("Trade, holding Metal Coat")
+        );
+    }
+}
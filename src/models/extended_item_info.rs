@@ -0,0 +1,95 @@
+use tui::{
+    style::{Color, Style},
+    text::{Span, Spans},
+};
+
+use crate::{sprite::Sprite, utils::PrepareForDisplay};
+
+use super::{Item, Machine};
+
+pub struct ExtendedItemInfo {
+    pub item: Item,
+    /// Only TMs, HMs and TRs have machines, one per version group.
+    pub machines: Vec<Machine>,
+    pub sprite: Option<Sprite>,
+}
+
+impl ExtendedItemInfo {
+    /// One line per taught move, with every version group that teaches it.
+    pub fn get_renderable_machines(&self) -> Vec<Spans<'static>> {
+        let mut machines: Vec<(String, Vec<String>)> = vec![];
+        for machine in self.machines.iter() {
+            let de_move = machine.get_renderable_move();
+            let version_group = machine
+                .version_group
+                .as_ref()
+                .map(|version_group| version_group.get_name_or_stub().split_capitalize())
+                .unwrap_or_default();
+
+            match machines.iter_mut().find(|(name, _)| *name == de_move) {
+                Some((_, version_groups)) => version_groups.push(version_group),
+                None => machines.push((de_move, vec![version_group])),
+            }
+        }
+
+        machines
+            .into_iter()
+            .map(|(de_move, version_groups)| {
+                Spans::from(vec![
+                    Span::styled(
+                        format!("{}: ", de_move).append_padding(),
+                        Style::default().fg(Color::Blue),
+                    ),
+                    Span::raw(version_groups.join(", ")),
+                ])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tui::{
+        style::{Color, Style},
+        text::{Span, Spans},
+    };
+
+    use super::ExtendedItemInfo;
+
+    #[test]
+    fn extended_item_info_get_renderable_machines() {
+        let machine = |de_move: &str, version_group: &str| {
+            serde_json::from_value(json!({
+                "id": 1,
+                "item": { "name": "tm01", "url": null },
+                "move": { "name": de_move, "url": null },
+                "version_group": { "name": version_group, "url": null }
+            }))
+            .unwrap()
+        };
+        let extended_item_info = ExtendedItemInfo {
+            item: serde_json::from_value(json!({ "name": "tm01" })).unwrap(),
+            machines: vec![
+                machine("mega-punch", "red-blue"),
+                machine("mega-punch", "yellow"),
+                machine("work-up", "black-2-white-2"),
+            ],
+            sprite: None,
+        };
+
+        assert_eq!(
+            extended_item_info.get_renderable_machines(),
+            vec![
+                Spans::from(vec![
+                    Span::styled("\u{A0}Mega Punch: ", Style::default().fg(Color::Blue)),
+                    Span::raw("Red Blue, Yellow"),
+                ]),
+                Spans::from(vec![
+                    Span::styled("\u{A0}Work Up: ", Style::default().fg(Color::Blue)),
+                    Span::raw("Black 2 White 2"),
+                ]),
+            ]
+        );
+    }
+}
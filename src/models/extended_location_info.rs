@@ -0,0 +1,249 @@
+use tui::{
+    style::{Color, Style},
+    text::Span,
+    widgets::Row,
+};
+
+use crate::utils::PrepareForDisplay;
+
+use super::{
+    location::{Location, LocationArea},
+    NamedApiResource,
+};
+
+pub struct ExtendedLocationInfo {
+    pub location: Location,
+    /// The area on screen, the first one of the location unless another was asked for.
+    pub area: Option<LocationArea>,
+}
+
+impl ExtendedLocationInfo {
+    /// Region and location, e.g. `Kanto › Viridian Forest`.
+    pub fn get_renderable_title(&self) -> String {
+        let region = self
+            .location
+            .region
+            .as_ref()
+            .map(|region| region.get_name_or_stub().split_capitalize());
+        let location = self
+            .location
+            .name
+            .as_ref()
+            .map(|name| name.to_string().split_capitalize());
+
+        let title = region
+            .into_iter()
+            .chain(location)
+            .collect::<Vec<String>>()
+            .join(" › ");
+
+        title.append_padding()
+    }
+
+    pub fn get_area(&self, index: usize) -> Option<&NamedApiResource> {
+        self.location.areas.as_ref()?.get(index)
+    }
+
+    pub fn get_areas_count(&self) -> usize {
+        self.location.areas.as_ref().map_or(0, Vec::len)
+    }
+
+    /// The row of the areas table the shown area is on.
+    pub fn get_area_index(&self) -> Option<usize> {
+        let area_name = self.area.as_ref()?.name.as_ref()?;
+
+        self.location
+            .areas
+            .as_ref()?
+            .iter()
+            .position(|area| area.name.as_ref() == Some(area_name))
+    }
+
+    pub fn get_renderable_areas(&self) -> Vec<Row<'static>> {
+        let area_index = self.get_area_index();
+
+        self.location
+            .areas
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(index, area)| {
+                let name = area.get_name_or_stub().split_capitalize().append_padding();
+                if Some(index) == area_index {
+                    Row::new(vec![Span::styled(name, Style::default().fg(Color::Blue))])
+                } else {
+                    Row::new(vec![Span::raw(name)])
+                }
+            })
+            .collect()
+    }
+
+    /// One row per encounter slot: the same Pokemon often shows up with several methods,
+    /// level ranges or conditions in a single version.
+    fn get_encounter_rows(&self) -> Vec<(&NamedApiResource, Vec<String>)> {
+        let mut rows = vec![];
+        for area_encounter in self
+            .area
+            .iter()
+            .flat_map(|area| area.pokemon_encounters.iter().flatten())
+        {
+            let Some(pokemon) = area_encounter.pokemon.as_ref() else {
+                continue;
+            };
+
+            for version_detail in area_encounter.version_details.iter().flatten() {
+                let version = version_detail
+                    .version
+                    .as_ref()
+                    .map(|version| version.get_name_or_stub().split_capitalize())
+                    .unwrap_or_default();
+
+                for encounter_detail in version_detail.encounter_details.iter().flatten() {
+                    rows.push((
+                        pokemon,
+                        vec![
+                            pokemon
+                                .get_name_or_stub()
+                                .split_capitalize()
+                                .append_padding(),
+                            version.clone(),
+                            encounter_detail.get_renderable_method(),
+                            encounter_detail.get_renderable_levels(),
+                            encounter_detail.get_renderable_chance(),
+                            encounter_detail.get_renderable_conditions(),
+                        ],
+                    ));
+                }
+            }
+        }
+
+        rows
+    }
+
+    pub fn get_encounter_pokemon(&self, index: usize) -> Option<&NamedApiResource> {
+        self.get_encounter_rows()
+            .into_iter()
+            .nth(index)
+            .map(|(pokemon, _)| pokemon)
+    }
+
+    pub fn get_encounters_count(&self) -> usize {
+        self.get_encounter_rows().len()
+    }
+
+    pub fn get_renderable_encounters(&self) -> Vec<Row<'static>> {
+        self.get_encounter_rows()
+            .into_iter()
+            .map(|(_, cells)| Row::new(cells))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tui::{
+        style::{Color, Style},
+        text::Span,
+        widgets::Row,
+    };
+
+    use super::ExtendedLocationInfo;
+
+    fn get_stub_extended_location_info() -> ExtendedLocationInfo {
+        ExtendedLocationInfo {
+            location: serde_json::from_value(json!({
+                "id": 86,
+                "name": "mt-moon",
+                "region": { "name": "kanto", "url": null },
+                "areas": [
+                    { "name": "mt-moon-1f", "url": "https://pokeapi.co/api/v2/location-area/1/" },
+                    { "name": "mt-moon-b1f", "url": "https://pokeapi.co/api/v2/location-area/2/" }
+                ]
+            }))
+            .unwrap(),
+            area: Some(
+                serde_json::from_value(json!({
+                    "id": 2,
+                    "name": "mt-moon-b1f",
+                    "pokemon_encounters": [{
+                        "pokemon": { "name": "zubat", "url": "https://pokeapi.co/api/v2/pokemon/41/" },
+                        "version_details": [{
+                            "version": { "name": "red", "url": null },
+                            "max_chance": 30,
+                            "encounter_details": [
+                                {
+                                    "chance": 15,
+                                    "min_level": 7,
+                                    "max_level": 9,
+                                    "method": { "name": "walk", "url": null },
+                                    "condition_values": []
+                                },
+                                {
+                                    "chance": 15,
+                                    "min_level": 10,
+                                    "max_level": 10,
+                                    "method": { "name": "walk", "url": null },
+                                    "condition_values": [{ "name": "time-night", "url": null }]
+                                }
+                            ]
+                        }]
+                    }]
+                }))
+                .unwrap(),
+            ),
+        }
+    }
+
+    #[test]
+    fn extended_location_info_get_renderable_title() {
+        assert_eq!(
+            get_stub_extended_location_info().get_renderable_title(),
+            "\u{A0}Kanto › Mt Moon"
+        );
+    }
+
+    #[test]
+    fn extended_location_info_get_renderable_areas() {
+        let extended_location_info = get_stub_extended_location_info();
+
+        assert_eq!(extended_location_info.get_area_index(), Some(1));
+        assert_eq!(
+            extended_location_info.get_renderable_areas(),
+            vec![
+                Row::new(vec![Span::raw("\u{A0}Mt Moon 1f")]),
+                Row::new(vec![Span::styled(
+                    "\u{A0}Mt Moon B1f",
+                    Style::default().fg(Color::Blue)
+                )]),
+            ]
+        );
+    }
+
+    #[test]
+    fn extended_location_info_get_renderable_encounters() {
+        let extended_location_info = get_stub_extended_location_info();
+
+        assert_eq!(extended_location_info.get_encounters_count(), 2);
+        assert_eq!(
+            extended_location_info.get_renderable_encounters(),
+            vec![
+                Row::new(vec!["\u{A0}Zubat", "Red", "Walk", "7 - 9", "15%", ""]),
+                Row::new(vec![
+                    "\u{A0}Zubat",
+                    "Red",
+                    "Walk",
+                    "10",
+                    "15%",
+                    "Time Night"
+                ]),
+            ]
+        );
+        assert_eq!(
+            extended_location_info
+                .get_encounter_pokemon(1)
+                .map(|pokemon| pokemon.get_name_or_stub()),
+            Some(String::from("zubat"))
+        );
+    }
+}
@@ -0,0 +1,420 @@
+use tui::{
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::Row,
+};
+
+use crate::utils::{get_styled_pokemon_type, PrepareForDisplay};
+
+use super::{
+    flavor_text_entry::get_renderable_flavor_texts_by_version_group, ContestEffect, Machine,
+    MoveDetail, NamedApiResource,
+};
+
+pub struct ExtendedMoveInfo {
+    pub move_detail: MoveDetail,
+    pub machines: Vec<Machine>,
+    pub contest_effect: Option<ContestEffect>,
+}
+
+impl ExtendedMoveInfo {
+    pub fn get_renderable_name(&self) -> String {
+        self.move_detail.pokemon_move.get_renderable_name()
+    }
+
+    /// Rows without a value are left out, most moves only use a few of the meta fields.
+    pub fn get_renderable_details(&self) -> Vec<Row<'static>> {
+        let pokemon_move = &self.move_detail.pokemon_move;
+        let meta = self.move_detail.meta.as_ref();
+        let percent = |value: Option<i32>| {
+            value
+                .filter(|value| *value != 0)
+                .map(|value| format!("{}%", value))
+        };
+        let name = |resource: Option<&NamedApiResource>| {
+            resource.map(|resource| resource.get_name_or_stub().split_capitalize())
+        };
+
+        let details: Vec<(&str, Option<String>)> = vec![
+            (
+                "Damage Class",
+                Some(pokemon_move.get_renderable_damage_class()),
+            ),
+            ("Power", Some(pokemon_move.get_renderable_power())),
+            ("Accuracy", Some(pokemon_move.get_renderable_accuracy())),
+            ("PP", Some(pokemon_move.get_renderable_pp())),
+            ("Priority", Some(self.move_detail.get_renderable_priority())),
+            ("Target", name(self.move_detail.target.as_ref())),
+            ("Effect Chance", percent(self.move_detail.effect_chance)),
+            (
+                "Crit Rate",
+                meta.and_then(|meta| meta.crit_rate)
+                    .filter(|crit_rate| *crit_rate > 0)
+                    .map(|crit_rate| format!("+{} stage", crit_rate)),
+            ),
+            (
+                "Drain",
+                percent(meta.and_then(|meta| meta.drain).filter(|drain| *drain > 0)),
+            ),
+            (
+                "Recoil",
+                percent(
+                    meta.and_then(|meta| meta.drain)
+                        .filter(|drain| *drain < 0)
+                        .map(|drain| -drain),
+                ),
+            ),
+            ("Healing", percent(meta.and_then(|meta| meta.healing))),
+            (
+                "Ailment",
+                meta.and_then(|meta| meta.get_renderable_ailment()),
+            ),
+            (
+                "Flinch Chance",
+                percent(meta.and_then(|meta| meta.flinch_chance)),
+            ),
+            ("Hits", meta.and_then(|meta| meta.get_renderable_hits())),
+            ("Turns", meta.and_then(|meta| meta.get_renderable_turns())),
+            (
+                "Stat Changes",
+                Some(self.move_detail.get_renderable_stat_changes()),
+            ),
+            (
+                "Stat Chance",
+                percent(meta.and_then(|meta| meta.stat_chance)),
+            ),
+            ("Contest Type", name(self.move_detail.contest_type.as_ref())),
+            (
+                "Appeal",
+                self.contest_effect
+                    .as_ref()
+                    .and_then(|contest_effect| contest_effect.appeal)
+                    .map(|appeal| appeal.to_string()),
+            ),
+            (
+                "Jam",
+                self.contest_effect
+                    .as_ref()
+                    .and_then(|contest_effect| contest_effect.jam)
+                    .map(|jam| jam.to_string()),
+            ),
+            ("Generation", name(self.move_detail.generation.as_ref())),
+        ];
+
+        let mut rows = vec![Row::new(vec![
+            Span::styled(
+                String::from("Type").append_padding(),
+                Style::default().fg(Color::Blue),
+            ),
+            get_styled_pokemon_type(pokemon_move.get_renderable_type()),
+        ])];
+        rows.extend(details.into_iter().filter_map(|(label, value)| {
+            let value = value.filter(|value| !value.is_empty())?;
+
+            Some(Row::new(vec![
+                Span::styled(
+                    label.to_string().append_padding(),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::raw(value),
+            ]))
+        }));
+
+        rows
+    }
+
+    pub fn get_renderable_effects(&self) -> Vec<Spans<'static>> {
+        let english_effect = self
+            .move_detail
+            .pokemon_move
+            .effect_entries
+            .iter()
+            .flatten()
+            .find(|effect_entry| effect_entry.get_language() == "en");
+        let contest_effect = self
+            .contest_effect
+            .as_ref()
+            .map(|contest_effect| contest_effect.get_renderable_effect_entry());
+
+        [
+            (
+                "Effect",
+                english_effect.and_then(|effect_entry| effect_entry.effect.clone()),
+            ),
+            (
+                "Short Effect",
+                english_effect.and_then(|effect_entry| effect_entry.short_effect.clone()),
+            ),
+            ("Contest Effect", contest_effect),
+        ]
+        .into_iter()
+        .filter_map(|(label, effect)| {
+            let effect = effect.filter(|effect| !effect.is_empty())?;
+
+            Some(Spans::from(vec![
+                Span::styled(
+                    format!("{}: ", label).append_padding(),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::raw(
+                    self.move_detail
+                        .substitute_effect_chance(&effect)
+                        .split_whitespace()
+                        .collect::<Vec<&str>>()
+                        .join(" "),
+                ),
+            ]))
+        })
+        .collect()
+    }
+
+    pub fn get_renderable_flavor_texts(&self) -> Vec<Spans<'static>> {
+        get_renderable_flavor_texts_by_version_group(
+            self.move_detail
+                .pokemon_move
+                .flavor_text_entries
+                .as_deref()
+                .unwrap_or_default(),
+        )
+    }
+
+    /// One line per machine item, with every version group it's sold in.
+    pub fn get_renderable_machines(&self) -> Vec<Spans<'static>> {
+        let mut machines: Vec<(String, Vec<String>)> = vec![];
+        for machine in self.machines.iter() {
+            let item = machine.get_renderable_item();
+            let version_group = machine
+                .version_group
+                .as_ref()
+                .map(|version_group| version_group.get_name_or_stub().split_capitalize())
+                .unwrap_or_default();
+
+            match machines.iter_mut().find(|(name, _)| *name == item) {
+                Some((_, version_groups)) => version_groups.push(version_group),
+                None => machines.push((item, vec![version_group])),
+            }
+        }
+
+        machines
+            .into_iter()
+            .map(|(item, version_groups)| {
+                Spans::from(vec![
+                    Span::styled(
+                        format!("{}: ", item).append_padding(),
+                        Style::default().fg(Color::Blue),
+                    ),
+                    Span::raw(version_groups.join(", ")),
+                ])
+            })
+            .collect()
+    }
+
+    pub fn get_learner(&self, index: usize) -> Option<&NamedApiResource> {
+        self.move_detail.learned_by_pokemon.as_ref()?.get(index)
+    }
+
+    pub fn get_learners_count(&self) -> usize {
+        self.move_detail
+            .learned_by_pokemon
+            .as_ref()
+            .map_or(0, Vec::len)
+    }
+
+    pub fn get_renderable_learners(&self) -> Vec<Row<'static>> {
+        self.move_detail
+            .learned_by_pokemon
+            .iter()
+            .flatten()
+            .map(|pokemon| {
+                Row::new(vec![pokemon
+                    .get_name_or_stub()
+                    .split_capitalize()
+                    .append_padding()])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tui::{
+        style::{Color, Style},
+        text::{Span, Spans},
+        widgets::Row,
+    };
+
+    use crate::models::{ContestEffect, Machine, NamedApiResource};
+
+    use super::ExtendedMoveInfo;
+
+    fn get_stub_extended_move_info() -> ExtendedMoveInfo {
+        let machine = |item: &str, version_group: &str| Machine {
+            id: None,
+            item: Some(NamedApiResource {
+                name: Some(String::from(item)),
+                url: None,
+            }),
+            de_move: None,
+            version_group: Some(NamedApiResource {
+                name: Some(String::from(version_group)),
+                url: None,
+            }),
+        };
+
+        ExtendedMoveInfo {
+            move_detail: serde_json::from_value(json!({
+                "id": 85,
+                "name": "thunderbolt",
+                "accuracy": 100,
+                "pp": 15,
+                "power": 90,
+                "type": { "name": "electric", "url": null },
+                "damage_class": { "name": "special", "url": null },
+                "effect_chance": 10,
+                "priority": 0,
+                "meta": {
+                    "ailment": { "name": "paralysis", "url": null },
+                    "ailment_chance": 10,
+                    "crit_rate": 0,
+                    "drain": 0,
+                    "healing": 0,
+                    "flinch_chance": 0,
+                    "stat_chance": 0
+                },
+                "stat_changes": [],
+                "effect_entries": [{
+                    "effect": "Inflicts regular damage.\nHas a $effect_chance% chance to paralyze the target.",
+                    "short_effect": "Has a $effect_chance% chance to paralyze the target.",
+                    "language": { "name": "en", "url": null }
+                }],
+                "flavor_text_entries": [
+                    {
+                        "flavor_text": "A strong electrical\nattack.",
+                        "version_group": { "name": "gold-silver", "url": null },
+                        "language": { "name": "en", "url": null }
+                    },
+                    {
+                        "flavor_text": "A strong electrical attack.",
+                        "version_group": { "name": "crystal", "url": null },
+                        "language": { "name": "en", "url": null }
+                    }
+                ],
+                "learned_by_pokemon": [
+                    { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" },
+                    { "name": "raichu", "url": "https://pokeapi.co/api/v2/pokemon/26/" }
+                ]
+            }))
+            .unwrap(),
+            machines: vec![
+                machine("tm24", "red-blue"),
+                machine("tm24", "yellow"),
+                machine("tm85", "x-y"),
+            ],
+            contest_effect: Some(ContestEffect {
+                id: None,
+                appeal: Some(4),
+                jam: Some(0),
+                effect_entries: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn extended_move_info_get_renderable_details() {
+        let extended_move_info = get_stub_extended_move_info();
+        let paint_blue = |label: &str| {
+            Span::styled(format!("\u{A0}{}", label), Style::default().fg(Color::Blue))
+        };
+
+        assert_eq!(
+            extended_move_info.get_renderable_details(),
+            vec![
+                Row::new(vec![
+                    paint_blue("Type"),
+                    Span::styled("Electric ", Style::default().fg(Color::Rgb(255, 204, 51))),
+                ]),
+                Row::new(vec![paint_blue("Damage Class"), Span::raw("Special")]),
+                Row::new(vec![paint_blue("Power"), Span::raw("90")]),
+                Row::new(vec![paint_blue("Accuracy"), Span::raw("100")]),
+                Row::new(vec![paint_blue("PP"), Span::raw("15")]),
+                Row::new(vec![paint_blue("Priority"), Span::raw("0")]),
+                Row::new(vec![paint_blue("Effect Chance"), Span::raw("10%")]),
+                Row::new(vec![paint_blue("Ailment"), Span::raw("Paralysis (10%)")]),
+                Row::new(vec![paint_blue("Appeal"), Span::raw("4")]),
+                Row::new(vec![paint_blue("Jam"), Span::raw("0")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn extended_move_info_get_renderable_effects() {
+        let extended_move_info = get_stub_extended_move_info();
+
+        assert_eq!(
+            extended_move_info.get_renderable_effects(),
+            vec![
+                Spans::from(vec![
+                    Span::styled("\u{A0}Effect: ", Style::default().fg(Color::Blue)),
+                    Span::raw("Inflicts regular damage. Has a 10% chance to paralyze the target."),
+                ]),
+                Spans::from(vec![
+                    Span::styled("\u{A0}Short Effect: ", Style::default().fg(Color::Blue)),
+                    Span::raw("Has a 10% chance to paralyze the target."),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn extended_move_info_get_renderable_flavor_texts() {
+        let extended_move_info = get_stub_extended_move_info();
+
+        assert_eq!(
+            extended_move_info.get_renderable_flavor_texts(),
+            vec![Spans::from(vec![
+                Span::styled(
+                    "\u{A0}Gold Silver, Crystal: ",
+                    Style::default().fg(Color::Blue)
+                ),
+                Span::raw("A strong electrical attack."),
+            ])]
+        );
+    }
+
+    #[test]
+    fn extended_move_info_get_renderable_machines() {
+        let extended_move_info = get_stub_extended_move_info();
+
+        assert_eq!(
+            extended_move_info.get_renderable_machines(),
+            vec![
+                Spans::from(vec![
+                    Span::styled("\u{A0}TM24: ", Style::default().fg(Color::Blue)),
+                    Span::raw("Red Blue, Yellow"),
+                ]),
+                Spans::from(vec![
+                    Span::styled("\u{A0}TM85: ", Style::default().fg(Color::Blue)),
+                    Span::raw("X Y"),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn extended_move_info_get_learner() {
+        let extended_move_info = get_stub_extended_move_info();
+
+        assert_eq!(extended_move_info.get_learners_count(), 2);
+        assert_eq!(
+            extended_move_info
+                .get_learner(1)
+                .map(|pokemon| pokemon.get_name_or_stub()),
+            Some(String::from("raichu"))
+        );
+        assert_eq!(
+            extended_move_info.get_renderable_learners()[0],
+            Row::new(vec!["\u{A0}Pikachu"])
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashMap};
 
 use rayon::prelude::*;
 use tui::{
@@ -10,18 +10,123 @@ use tui::{
 use crate::utils::PrepareForDisplay;
 
 use super::{
-    pokemon_move::PokemonMoveExt, Pokemon, PokemonAbilityExt, PokemonEncounter, PokemonSpecies,
+    pokemon_encounter::PokemonEncounterVersionDetail,
+    pokemon_form::FormChoice,
+    pokemon_move::{PokemonMove, PokemonMoveExt, PokemonMoveVersion},
+    EvolutionChain, EvolutionStage, ExtendedPokemonForm, NamedApiResource, Pokemon,
+    PokemonAbilityExt, PokemonEncounter, PokemonSpecies, PokemonSprites, PokemonTypeExt,
+    TypeMatchups, VersionGroup,
 };
 
 pub struct ExtendedPokemonInfo {
     pub pokemon: Pokemon,
-    pub abilities: Vec<PokemonAbilityExt>,
-    pub moves: Vec<PokemonMoveExt>,
+    /// Keyed by ability name, which is how `pokemon.abilities` refers to them.
+    pub abilities: HashMap<String, PokemonAbilityExt>,
+    /// Keyed by move name, which is how `pokemon.moves` refers to them.
+    pub moves: HashMap<String, PokemonMoveExt>,
     pub species: PokemonSpecies,
     pub encounters: Vec<PokemonEncounter>,
+    pub evolution_chain: Option<EvolutionChain>,
+    pub types: Vec<PokemonTypeExt>,
+    /// The form picked on the forms table, its types and sprites replace the Pokemon's.
+    pub form: Option<ExtendedPokemonForm>,
 }
 
 impl ExtendedPokemonInfo {
+    pub fn set_abilities(&mut self, abilities: Vec<PokemonAbilityExt>) {
+        self.abilities = abilities
+            .into_iter()
+            .filter_map(|ability| Some((ability.name.clone()?, ability)))
+            .collect();
+    }
+
+    pub fn set_moves(&mut self, moves: Vec<PokemonMoveExt>) {
+        self.moves = moves
+            .into_iter()
+            .filter_map(|pokemon_move| Some((pokemon_move.name.clone()?, pokemon_move)))
+            .collect();
+    }
+
+    /// The types of the picked form, or of the Pokemon when the form doesn't list its own.
+    pub fn get_types(&self) -> &[PokemonTypeExt] {
+        match self.form.as_ref() {
+            Some(form) if !form.types.is_empty() => &form.types,
+            _ => &self.types,
+        }
+    }
+
+    pub fn get_renderable_types(&self) -> Vec<Span<'_>> {
+        match self.form.as_ref() {
+            Some(form) if !form.types.is_empty() => form.form.get_renderable_types(),
+            _ => self.pokemon.get_renderable_types(),
+        }
+    }
+
+    pub fn get_sprites(&self) -> Option<&PokemonSprites> {
+        self.form
+            .as_ref()
+            .and_then(|form| form.form.sprites.as_ref())
+            .or(self.pokemon.sprites.as_ref())
+    }
+
+    /// Varieties of the species, then forms of the Pokemon. Either is left out when it
+    /// only has the one already on screen.
+    fn get_form_choices(&self) -> Vec<FormChoice> {
+        let varieties: Vec<FormChoice> = self
+            .species
+            .varieties
+            .iter()
+            .flatten()
+            .filter_map(|variety| variety.pokemon.clone())
+            .map(FormChoice::Variety)
+            .collect();
+        let forms: Vec<FormChoice> = self
+            .pokemon
+            .forms
+            .iter()
+            .flatten()
+            .cloned()
+            .map(FormChoice::Form)
+            .collect();
+
+        [varieties, forms]
+            .into_iter()
+            .filter(|choices| choices.len() > 1)
+            .flatten()
+            .collect()
+    }
+
+    pub fn get_form_choice(&self, index: usize) -> Option<FormChoice> {
+        self.get_form_choices().into_iter().nth(index)
+    }
+
+    pub fn get_renderable_form_choices(&self) -> Vec<Row<'static>> {
+        let current_form = self
+            .form
+            .as_ref()
+            .and_then(|form| form.form.name.clone())
+            .or_else(|| {
+                self.pokemon
+                    .forms
+                    .as_ref()?
+                    .first()
+                    .map(|form| form.get_name_or_stub())
+            });
+
+        self.get_form_choices()
+            .iter()
+            .map(|choice| {
+                let name = choice.get_resource().name.as_ref();
+                let is_current = match choice {
+                    FormChoice::Variety(_) => name == self.pokemon.name.as_ref(),
+                    FormChoice::Form(_) => name == current_form.as_ref(),
+                };
+
+                choice.get_renderable_as_row(is_current)
+            })
+            .collect()
+    }
+
     pub fn get_renderable_abilities(&self) -> Vec<Row> {
         self.pokemon
             .abilities
@@ -31,21 +136,11 @@ impl ExtendedPokemonInfo {
                     abilities
                         .par_iter()
                         .filter_map(|ability| {
-                            let extended_ability =
-                                self.abilities.par_iter().find_any(|extended_ability| {
-                                    if let Some(item_ability) = ability.ability.as_ref() {
-                                        return extended_ability
-                                            .name
-                                            .as_ref()
-                                            .unwrap_or(&"".to_string())
-                                            == item_ability
-                                                .name
-                                                .as_ref()
-                                                .unwrap_or(&"".to_string());
-                                    }
-
-                                    false
-                                });
+                            let extended_ability = ability
+                                .ability
+                                .as_ref()
+                                .and_then(|item_ability| item_ability.name.as_ref())
+                                .and_then(|name| self.abilities.get(name));
 
                             ability.get_renderable_as_row(extended_ability)
                         })
@@ -55,6 +150,22 @@ impl ExtendedPokemonInfo {
             .unwrap_or(vec![])
     }
 
+    /// The ability on the given row of the abilities table, which skips abilities that aren't loaded.
+    pub fn get_ability(&self, index: usize) -> Option<&NamedApiResource> {
+        self.pokemon
+            .abilities
+            .iter()
+            .flatten()
+            .filter_map(|ability| ability.ability.as_ref())
+            .filter(|ability| {
+                ability
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| self.abilities.contains_key(name))
+            })
+            .nth(index)
+    }
+
     pub fn get_renderable_basic_info_items(&self) -> Vec<Row> {
         let paint_blue = |string: String| Span::styled(string, Style::default().fg(Color::Blue));
 
@@ -73,7 +184,7 @@ impl ExtendedPokemonInfo {
             ]),
             Row::new(vec![
                 Spans::from(paint_blue(String::from("Types").append_padding())),
-                Spans::from(self.pokemon.get_renderable_types()),
+                Spans::from(self.get_renderable_types()),
             ]),
             Row::new(vec![
                 paint_blue(String::from("Height").append_padding()),
@@ -107,6 +218,31 @@ impl ExtendedPokemonInfo {
     }
 
     pub fn get_renderable_moves(&self, selected_version_group: &str) -> Vec<Row> {
+        self.get_sorted_moves(selected_version_group)
+            .into_iter()
+            .filter_map(|(pokemon_move, extended_pokemon_move, move_version)| {
+                pokemon_move.get_renderable_as_row(Some(extended_pokemon_move), move_version)
+            })
+            .collect()
+    }
+
+    /// The move on the given row of the moves table.
+    pub fn get_move(
+        &self,
+        selected_version_group: &str,
+        index: usize,
+    ) -> Option<&NamedApiResource> {
+        self.get_sorted_moves(selected_version_group)
+            .get(index)
+            .and_then(|(pokemon_move, ..)| pokemon_move.de_move.as_ref())
+    }
+
+    /// Moves learned in the version group, in the order of the moves table. Moves that
+    /// aren't loaded yet have no row, so they're left out to keep the indexes in line.
+    fn get_sorted_moves(
+        &self,
+        selected_version_group: &str,
+    ) -> Vec<(&PokemonMove, &PokemonMoveExt, &PokemonMoveVersion)> {
         let mut prepared_moves = self
             .pokemon
             .moves
@@ -170,24 +306,123 @@ impl ExtendedPokemonInfo {
         });
 
         prepared_moves
-            .par_iter()
+            .into_iter()
             .filter_map(|(pokemon_move, move_versions)| {
-                let extended_pokemon_move = self.moves.par_iter().find_any(|extended_move| {
-                    if let Some(pokemon_move) = pokemon_move.de_move.as_ref() {
-                        return extended_move.name.as_ref().unwrap_or(&"".to_string())
-                            == pokemon_move.name.as_ref().unwrap_or(&"".to_string());
-                    }
+                let extended_pokemon_move = pokemon_move
+                    .de_move
+                    .as_ref()
+                    .and_then(|de_move| de_move.name.as_ref())
+                    .and_then(|name| self.moves.get(name))?;
+
+                Some((pokemon_move, extended_pokemon_move, *move_versions.first()?))
+            })
+            .collect()
+    }
+
+    /// An ability is only certain when the Pokemon can't have any other, so it's applied then.
+    /// Otherwise every ability that changes a matchup gets a line of its own.
+    pub fn get_renderable_type_matchups(&self) -> Vec<Spans<'static>> {
+        if self.get_types().is_empty() {
+            return vec![];
+        }
+
+        let ability_names: Vec<&str> = self
+            .pokemon
+            .abilities
+            .iter()
+            .flatten()
+            .filter_map(|ability| ability.ability.as_ref()?.name.as_deref())
+            .collect();
+        let type_matchups = TypeMatchups::new(self.get_types(), None);
+
+        if let [ability_name] = ability_names.as_slice() {
+            let ability_matchups = TypeMatchups::new(self.get_types(), Some(ability_name));
+            let mut lines = ability_matchups.get_renderable();
+            if !type_matchups.get_changes(&ability_matchups).is_empty() {
+                lines.push(Spans::from(Span::styled(
+                    format!("Includes {}", ability_name.to_string().split_capitalize()),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+
+            return lines;
+        }
+
+        let mut lines = type_matchups.get_renderable();
+        for ability_name in ability_names {
+            let ability_matchups = TypeMatchups::new(self.get_types(), Some(ability_name));
+            if !type_matchups.get_changes(&ability_matchups).is_empty() {
+                lines.push(
+                    type_matchups.get_renderable_ability_changes(ability_name, &ability_matchups),
+                );
+            }
+        }
+
+        lines
+    }
 
-                    false
-                });
+    pub fn get_evolution_stages(&self) -> Vec<EvolutionStage<'_>> {
+        self.evolution_chain
+            .as_ref()
+            .map(|evolution_chain| evolution_chain.get_stages())
+            .unwrap_or_default()
+    }
 
-                pokemon_move
-                    .get_renderable_as_row(extended_pokemon_move, move_versions.first().unwrap())
+    pub fn get_renderable_evolution_stages(&self) -> Vec<Row<'static>> {
+        let current_species = self
+            .pokemon
+            .species
+            .as_ref()
+            .map(|species| species.get_name_or_stub())
+            .unwrap_or_default();
+
+        self.get_evolution_stages()
+            .iter()
+            .map(|stage| stage.get_renderable_as_row(&current_species))
+            .collect()
+    }
+
+    /// The encounters table has a row per location area and version.
+    fn get_encounter_version_details(
+        &self,
+        selected_version_group: &VersionGroup,
+    ) -> Vec<(&PokemonEncounter, &PokemonEncounterVersionDetail)> {
+        self.encounters
+            .iter()
+            .flat_map(|encounter| {
+                encounter
+                    .get_version_details(selected_version_group)
+                    .into_iter()
+                    .map(move |version_detail| (encounter, version_detail))
             })
             .collect()
     }
 
-    pub fn get_renderable_encounters(&self, selected_version_group: &str) -> Vec<Row> {
+    pub fn get_encounter_location_area(
+        &self,
+        selected_version_group: &VersionGroup,
+        index: usize,
+    ) -> Option<&NamedApiResource> {
+        self.get_encounter_version_details(selected_version_group)
+            .into_iter()
+            .nth(index)
+            .and_then(|(encounter, _)| encounter.location_area.as_ref())
+    }
+
+    /// Every method of the given row of the encounters table on a row of its own.
+    pub fn get_renderable_encounter_details(
+        &self,
+        selected_version_group: &VersionGroup,
+        index: usize,
+    ) -> Vec<Row<'static>> {
+        self.get_encounter_version_details(selected_version_group)
+            .into_iter()
+            .nth(index)
+            .map(|(_, version_detail)| version_detail.get_renderable_encounter_details())
+            .unwrap_or_default()
+    }
+
+    pub fn get_renderable_encounters(&self, selected_version_group: &VersionGroup) -> Vec<Row<'_>> {
         self.encounters
             .par_iter()
             .flat_map(|encounter| encounter.get_renderable_as_rows(selected_version_group))
@@ -197,23 +432,31 @@ impl ExtendedPokemonInfo {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
     use tui::{
-        style::{Color, Style},
+        style::{Color, Modifier, Style},
         text::{Span, Spans},
         widgets::Row,
     };
 
     use crate::models::{
         pokemon_encounter::{PokemonEncounterDetail, PokemonEncounterVersionDetail},
-        NamedApiResource, Pokemon, PokemonAbility, PokemonAbilityExt, PokemonEncounter,
-        PokemonHeldItem, PokemonHeldItemVersion, PokemonMove, PokemonMoveExt, PokemonMoveVersion,
+        pokemon_form::FormChoice,
+        pokemon_move::PokemonMoveVersion,
+        pokemon_species::PokemonSpeciesVariety,
+        pokemon_type::fixture::get_stub_type_ext,
+        version_group::fixture::get_stub_version_group,
+        ExtendedPokemonForm, NamedApiResource, Pokemon, PokemonAbility, PokemonAbilityExt,
+        PokemonEncounter, PokemonHeldItem, PokemonHeldItemVersion, PokemonMove, PokemonMoveExt,
         PokemonSpecies, PokemonStat, PokemonType, VerboseEffect,
     };
 
     use super::ExtendedPokemonInfo;
 
     fn get_stub_extended_pokemon_info() -> ExtendedPokemonInfo {
-        ExtendedPokemonInfo {
+        let mut extended_pokemon_info = ExtendedPokemonInfo {
             pokemon: Pokemon {
                 id: Some(1),
                 name: Some(String::from("raichu")),
@@ -300,69 +543,8 @@ mod tests {
                     }),
                 }]),
             },
-            abilities: vec![PokemonAbilityExt {
-                id: Some(1),
-                name: Some(String::from("guts")),
-                effect_entries: Some(vec![VerboseEffect {
-                    effect: None,
-                    short_effect: Some(String::from("short effect")),
-                    language: Some(NamedApiResource {
-                        name: Some(String::from("en")),
-                        url: None,
-                    }),
-                }]),
-                flavor_text_entries: None,
-            }],
-            moves: vec![
-                PokemonMoveExt {
-                    id: Some(1),
-                    name: Some(String::from("swift")),
-                    accuracy: Some(100),
-                    pp: Some(20),
-                    power: Some(60),
-                    pk_type: Some(NamedApiResource {
-                        name: Some(String::from("normal")),
-                        url: None,
-                    }),
-                    flavor_text_entries: None,
-                    effect_entries: Some(vec![VerboseEffect {
-                        effect: None,
-                        short_effect: Some(String::from("short effect")),
-                        language: Some(NamedApiResource {
-                            name: Some(String::from("en")),
-                            url: None,
-                        }),
-                    }]),
-                    damage_class: Some(NamedApiResource {
-                        name: Some(String::from("special")),
-                        url: None,
-                    }),
-                },
-                PokemonMoveExt {
-                    id: Some(1),
-                    name: Some(String::from("pound")),
-                    accuracy: Some(100),
-                    pp: Some(20),
-                    power: Some(60),
-                    pk_type: Some(NamedApiResource {
-                        name: Some(String::from("normal")),
-                        url: None,
-                    }),
-                    flavor_text_entries: None,
-                    effect_entries: Some(vec![VerboseEffect {
-                        effect: None,
-                        short_effect: Some(String::from("short effect")),
-                        language: Some(NamedApiResource {
-                            name: Some(String::from("en")),
-                            url: None,
-                        }),
-                    }]),
-                    damage_class: Some(NamedApiResource {
-                        name: Some(String::from("physical")),
-                        url: None,
-                    }),
-                },
-            ],
+            abilities: HashMap::new(),
+            moves: HashMap::new(),
             species: PokemonSpecies {
                 gender_rate: Some(4),
                 capture_rate: Some(100),
@@ -375,6 +557,7 @@ mod tests {
                 evolution_chain: None,
                 flavor_text_entries: None,
                 pokedex_numbers: None,
+                varieties: None,
             },
             encounters: vec![
                 PokemonEncounter {
@@ -391,6 +574,7 @@ mod tests {
                             }),
                             encounter_details: Some(vec![
                                 PokemonEncounterDetail {
+                                    condition_values: None,
                                     chance: Some(50),
                                     min_level: Some(1),
                                     max_level: Some(10),
@@ -400,6 +584,7 @@ mod tests {
                                     }),
                                 },
                                 PokemonEncounterDetail {
+                                    condition_values: None,
                                     chance: Some(50),
                                     min_level: Some(1),
                                     max_level: Some(10),
@@ -409,6 +594,7 @@ mod tests {
                                     }),
                                 },
                                 PokemonEncounterDetail {
+                                    condition_values: None,
                                     chance: Some(70),
                                     min_level: Some(5),
                                     max_level: Some(25),
@@ -427,6 +613,7 @@ mod tests {
                             }),
                             encounter_details: Some(vec![
                                 PokemonEncounterDetail {
+                                    condition_values: None,
                                     chance: Some(50),
                                     min_level: Some(1),
                                     max_level: Some(10),
@@ -436,6 +623,7 @@ mod tests {
                                     }),
                                 },
                                 PokemonEncounterDetail {
+                                    condition_values: None,
                                     chance: Some(50),
                                     min_level: Some(1),
                                     max_level: Some(10),
@@ -445,6 +633,7 @@ mod tests {
                                     }),
                                 },
                                 PokemonEncounterDetail {
+                                    condition_values: None,
                                     chance: Some(100),
                                     min_level: Some(5),
                                     max_level: Some(15),
@@ -469,6 +658,7 @@ mod tests {
                             url: None,
                         }),
                         encounter_details: Some(vec![PokemonEncounterDetail {
+                            condition_values: None,
                             chance: Some(50),
                             min_level: Some(1),
                             max_level: Some(10),
@@ -491,6 +681,7 @@ mod tests {
                             url: None,
                         }),
                         encounter_details: Some(vec![PokemonEncounterDetail {
+                            condition_values: None,
                             chance: Some(10),
                             min_level: Some(10),
                             max_level: Some(12),
@@ -502,7 +693,99 @@ mod tests {
                     }]),
                 },
             ],
-        }
+            evolution_chain: None,
+            types: vec![get_stub_type_ext(
+                "electric",
+                &["ground"],
+                &["electric", "flying", "steel"],
+                &[],
+            )],
+            form: None,
+        };
+        extended_pokemon_info.set_abilities(vec![PokemonAbilityExt {
+            id: Some(1),
+            name: Some(String::from("guts")),
+            effect_entries: Some(vec![VerboseEffect {
+                effect: None,
+                short_effect: Some(String::from("short effect")),
+                language: Some(NamedApiResource {
+                    name: Some(String::from("en")),
+                    url: None,
+                }),
+            }]),
+            flavor_text_entries: None,
+            generation: None,
+            effect_changes: None,
+            pokemon: None,
+        }]);
+        extended_pokemon_info.set_moves(vec![
+            PokemonMoveExt {
+                id: Some(1),
+                name: Some(String::from("swift")),
+                accuracy: Some(100),
+                pp: Some(20),
+                power: Some(60),
+                pk_type: Some(NamedApiResource {
+                    name: Some(String::from("normal")),
+                    url: None,
+                }),
+                flavor_text_entries: None,
+                effect_entries: Some(vec![VerboseEffect {
+                    effect: None,
+                    short_effect: Some(String::from("short effect")),
+                    language: Some(NamedApiResource {
+                        name: Some(String::from("en")),
+                        url: None,
+                    }),
+                }]),
+                damage_class: Some(NamedApiResource {
+                    name: Some(String::from("special")),
+                    url: None,
+                }),
+            },
+            PokemonMoveExt {
+                id: Some(1),
+                name: Some(String::from("pound")),
+                accuracy: Some(100),
+                pp: Some(20),
+                power: Some(60),
+                pk_type: Some(NamedApiResource {
+                    name: Some(String::from("normal")),
+                    url: None,
+                }),
+                flavor_text_entries: None,
+                effect_entries: Some(vec![VerboseEffect {
+                    effect: None,
+                    short_effect: Some(String::from("short effect")),
+                    language: Some(NamedApiResource {
+                        name: Some(String::from("en")),
+                        url: None,
+                    }),
+                }]),
+                damage_class: Some(NamedApiResource {
+                    name: Some(String::from("physical")),
+                    url: None,
+                }),
+            },
+        ]);
+
+        extended_pokemon_info
+    }
+
+    #[test]
+    fn extended_pokemon_info_set_moves() {
+        let mut extended_pokemon_info = get_stub_extended_pokemon_info();
+        let pound = extended_pokemon_info.moves.remove("pound").unwrap();
+        let mut nameless_move = extended_pokemon_info.moves.remove("swift").unwrap();
+        nameless_move.name = None;
+
+        extended_pokemon_info.set_moves(vec![pound, nameless_move]);
+
+        assert_eq!(extended_pokemon_info.moves.len(), 1);
+        assert_eq!(
+            extended_pokemon_info.moves["pound"].name.as_deref(),
+            Some("pound")
+        );
     }
 
     #[test]
@@ -610,11 +893,55 @@ mod tests {
         )
     }
 
+    #[test]
+    fn extended_pokemon_info_get_move() {
+        let extended_pokemon_info = get_stub_extended_pokemon_info();
+
+        assert_eq!(
+            extended_pokemon_info
+                .get_move("x-y", 1)
+                .map(|de_move| de_move.get_name_or_stub()),
+            Some(String::from("swift"))
+        );
+        assert!(extended_pokemon_info.get_move("x-y", 2).is_none());
+    }
+
+    #[test]
+    fn extended_pokemon_info_get_encounter_location_area() {
+        let extended_pokemon_info = get_stub_extended_pokemon_info();
+
+        assert_eq!(
+            extended_pokemon_info
+                .get_encounter_location_area(&get_stub_version_group("x-y", &["x", "y"]), 2)
+                .map(|location_area| location_area.get_name_or_stub()),
+            Some(String::from("kanto-route-3"))
+        );
+        assert!(extended_pokemon_info
+            .get_encounter_location_area(&get_stub_version_group("x-y", &["x", "y"]), 3)
+            .is_none());
+    }
+
+    #[test]
+    fn extended_pokemon_info_get_renderable_encounter_details() {
+        let extended_pokemon_info = get_stub_extended_pokemon_info();
+
+        assert_eq!(
+            extended_pokemon_info
+                .get_renderable_encounter_details(&get_stub_version_group("x-y", &["x", "y"]), 2)
+                .len(),
+            1
+        );
+        assert!(extended_pokemon_info
+            .get_renderable_encounter_details(&get_stub_version_group("x-y", &["x", "y"]), 3)
+            .is_empty());
+    }
+
     #[test]
     fn extended_pokemon_info_get_renderable_encounters() {
         let extended_pokemon_info = get_stub_extended_pokemon_info();
         assert_eq!(
-            extended_pokemon_info.get_renderable_encounters("x-y"),
+            extended_pokemon_info
+                .get_renderable_encounters(&get_stub_version_group("x-y", &["x", "y"])),
             vec![
                 Row::new(vec![
                     Span::raw("\u{A0}Kanto Route 1"),
@@ -640,7 +967,8 @@ mod tests {
             ],
         );
         assert_eq!(
-            extended_pokemon_info.get_renderable_encounters("sun-moon"),
+            extended_pokemon_info
+                .get_renderable_encounters(&get_stub_version_group("sun-moon", &["sun", "moon"])),
             vec![Row::new(vec![
                 Span::raw("\u{A0}Kanto Route 2"),
                 Span::raw("Walk"),
@@ -650,8 +978,139 @@ mod tests {
             ]),],
         );
         assert_eq!(
-            extended_pokemon_info.get_renderable_encounters("red-green"),
+            extended_pokemon_info
+                .get_renderable_encounters(&get_stub_version_group("red-green", &["red", "green"])),
+            vec![]
+        );
+        assert_eq!(
+            extended_pokemon_info.get_renderable_encounters(&get_stub_version_group(
+                "ultra-sun-ultra-moon",
+                &["ultra-sun", "ultra-moon"]
+            )),
             vec![]
         )
     }
+
+    #[test]
+    fn extended_pokemon_info_get_renderable_type_matchups() {
+        let mut extended_pokemon_info = get_stub_extended_pokemon_info();
+        let type_matchups = extended_pokemon_info.get_renderable_type_matchups();
+
+        assert_eq!(type_matchups.len(), 3);
+        assert_eq!(
+            type_matchups[0],
+            Spans::from(vec![
+                Span::styled("2x   ", Style::default().fg(Color::Red)),
+                Span::styled("Ground ", Style::default().fg(Color::Rgb(221, 187, 85))),
+            ])
+        );
+
+        extended_pokemon_info.pokemon.abilities = Some(vec![
+            PokemonAbility {
+                is_hidden: Some(false),
+                slot: None,
+                ability: Some(NamedApiResource {
+                    name: Some(String::from("static")),
+                    url: None,
+                }),
+            },
+            PokemonAbility {
+                is_hidden: Some(true),
+                slot: None,
+                ability: Some(NamedApiResource {
+                    name: Some(String::from("levitate")),
+                    url: None,
+                }),
+            },
+        ]);
+        let type_matchups = extended_pokemon_info.get_renderable_type_matchups();
+
+        assert_eq!(type_matchups.len(), 4);
+        assert_eq!(
+            type_matchups[3],
+            Spans::from(vec![
+                Span::styled("With Levitate: ", Style::default().fg(Color::Blue)),
+                Span::styled("Ground ", Style::default().fg(Color::Rgb(221, 187, 85))),
+                Span::styled("0x   ", Style::default().fg(Color::DarkGray)),
+            ])
+        );
+    }
+
+    #[test]
+    fn extended_pokemon_info_get_form_choice() {
+        let mut extended_pokemon_info = get_stub_extended_pokemon_info();
+        assert!(extended_pokemon_info
+            .get_renderable_form_choices()
+            .is_empty());
+
+        extended_pokemon_info.species.varieties = Some(vec![
+            PokemonSpeciesVariety {
+                is_default: Some(true),
+                pokemon: Some(NamedApiResource {
+                    name: Some(String::from("raichu")),
+                    url: None,
+                }),
+            },
+            PokemonSpeciesVariety {
+                is_default: Some(false),
+                pokemon: Some(NamedApiResource {
+                    name: Some(String::from("raichu-alola")),
+                    url: None,
+                }),
+            },
+        ]);
+        extended_pokemon_info.pokemon.forms = Some(vec![NamedApiResource {
+            name: Some(String::from("raichu")),
+            url: None,
+        }]);
+
+        assert_eq!(
+            extended_pokemon_info.get_renderable_form_choices(),
+            vec![
+                Row::new(vec![
+                    Span::styled(
+                        "\u{A0}Raichu",
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD)
+                    ),
+                    Span::raw("Variety"),
+                ]),
+                Row::new(vec![
+                    Span::styled("\u{A0}Raichu Alola", Style::default().fg(Color::Blue)),
+                    Span::raw("Variety"),
+                ]),
+            ]
+        );
+        assert!(matches!(
+            extended_pokemon_info.get_form_choice(1),
+            Some(FormChoice::Variety(variety)) if variety.get_name_or_stub() == "raichu-alola"
+        ));
+        assert!(extended_pokemon_info.get_form_choice(2).is_none());
+    }
+
+    #[test]
+    fn extended_pokemon_info_get_types_of_form() {
+        let mut extended_pokemon_info = get_stub_extended_pokemon_info();
+        extended_pokemon_info.form = Some(ExtendedPokemonForm {
+            form: serde_json::from_value(json!({
+                "name": "raichu-fire",
+                "types": [{ "slot": 1, "type": { "name": "fire", "url": null } }]
+            }))
+            .unwrap(),
+            types: vec![get_stub_type_ext("fire", &["water"], &[], &[])],
+        });
+
+        assert_eq!(
+            extended_pokemon_info.get_renderable_types(),
+            vec![Span::styled(
+                "Fire ",
+                Style::default().fg(Color::Rgb(255, 68, 34))
+            )]
+        );
+        assert_eq!(
+            extended_pokemon_info.get_types()[0].name.as_deref(),
+            Some("fire")
+        );
+    }
 }
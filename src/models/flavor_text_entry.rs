@@ -1,10 +1,135 @@
 use serde::{Deserialize, Serialize};
+use tui::{
+    style::{Color, Style},
+    text::{Span, Spans},
+};
+
+use crate::utils::PrepareForDisplay;
 
 use super::NamedApiResource;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct FlavorTextEntry {
     pub flavor_text: Option<String>,
+    /// Set on move and ability entries.
     pub version_group: Option<NamedApiResource>,
+    /// Set on species entries instead of `version_group`.
+    pub version: Option<NamedApiResource>,
     pub language: Option<NamedApiResource>,
 }
+
+impl FlavorTextEntry {
+    pub fn get_language(&self) -> String {
+        self.language
+            .as_ref()
+            .map(|language| language.get_name_or_stub())
+            .unwrap_or_default()
+    }
+
+    pub fn get_version_group(&self) -> String {
+        self.version_group
+            .as_ref()
+            .map(|version_group| version_group.get_name_or_stub())
+            .unwrap_or_default()
+    }
+
+    pub fn get_version(&self) -> String {
+        self.version
+            .as_ref()
+            .map(|version| version.get_name_or_stub())
+            .unwrap_or_default()
+    }
+
+    /// The texts are copied verbatim from the games, with their line breaks, page breaks
+    /// (form feeds) and soft hyphens at the end of broken lines.
+    pub fn get_normalized_flavor_text(&self) -> String {
+        self.flavor_text
+            .as_ref()
+            .map(|flavor_text| {
+                flavor_text
+                    .replace("\u{AD}\n", "")
+                    .replace('\u{AD}', "")
+                    .split_whitespace()
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// English move and ability texts, one line per text with every version group that uses it.
+pub fn get_renderable_flavor_texts_by_version_group(
+    flavor_text_entries: &[FlavorTextEntry],
+) -> Vec<Spans<'static>> {
+    let mut flavor_texts: Vec<(Vec<String>, String)> = vec![];
+    for flavor_text_entry in flavor_text_entries
+        .iter()
+        .filter(|flavor_text_entry| flavor_text_entry.get_language() == "en")
+    {
+        let version_group = flavor_text_entry.get_version_group().split_capitalize();
+        let flavor_text = flavor_text_entry.get_normalized_flavor_text();
+
+        match flavor_texts
+            .iter_mut()
+            .find(|(_, text)| *text == flavor_text)
+        {
+            Some((version_groups, _)) => version_groups.push(version_group),
+            None => flavor_texts.push((vec![version_group], flavor_text)),
+        }
+    }
+
+    flavor_texts
+        .into_iter()
+        .map(|(version_groups, flavor_text)| {
+            Spans::from(vec![
+                Span::styled(
+                    format!("{}: ", version_groups.join(", ")).append_padding(),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::raw(flavor_text),
+            ])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::NamedApiResource;
+
+    use super::FlavorTextEntry;
+
+    fn get_stub_flavor_text_entry() -> FlavorTextEntry {
+        FlavorTextEntry {
+            flavor_text: Some(String::from(
+                "When several of\nthese POKéMON\ngather, their\u{C}electricity could\nbuild and cause\nlight\u{AD}\nning storms.",
+            )),
+            version_group: None,
+            version: Some(NamedApiResource {
+                name: Some(String::from("red")),
+                url: None,
+            }),
+            language: Some(NamedApiResource {
+                name: Some(String::from("en")),
+                url: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn flavor_text_entry_get_normalized_flavor_text() {
+        assert_eq!(
+            get_stub_flavor_text_entry().get_normalized_flavor_text(),
+            String::from(
+                "When several of these POKéMON gather, their electricity could build and cause lightning storms."
+            )
+        );
+    }
+
+    #[test]
+    fn flavor_text_entry_get_language_and_version() {
+        let flavor_text_entry = get_stub_flavor_text_entry();
+
+        assert_eq!(flavor_text_entry.get_language(), String::from("en"));
+        assert_eq!(flavor_text_entry.get_version(), String::from("red"));
+    }
+}
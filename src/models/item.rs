@@ -0,0 +1,275 @@
+use serde::{Deserialize, Serialize};
+use tui::{
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::Row,
+};
+
+use crate::utils::PrepareForDisplay;
+
+use super::{MachineVersionDetail, NamedApiResource, PokemonHeldItemVersion, VerboseEffect};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Item {
+    pub id: Option<i32>,
+    pub name: Option<String>,
+    pub cost: Option<i32>,
+    pub fling_power: Option<i32>,
+    pub fling_effect: Option<NamedApiResource>,
+    pub attributes: Option<Vec<NamedApiResource>>,
+    pub category: Option<NamedApiResource>,
+    pub effect_entries: Option<Vec<VerboseEffect>>,
+    pub sprites: Option<ItemSprites>,
+    pub held_by_pokemon: Option<Vec<ItemHolderPokemon>>,
+    pub machines: Option<Vec<MachineVersionDetail>>,
+}
+
+impl Item {
+    pub fn get_renderable_name(&self) -> String {
+        self.name
+            .as_ref()
+            .map(|name| name.to_string().split_capitalize().append_padding())
+            .unwrap_or_default()
+    }
+
+    pub fn get_sprite_url(&self) -> Option<&String> {
+        self.sprites.as_ref()?.default.as_ref()
+    }
+
+    /// Rows without a value are left out, most items can't be flung or bought.
+    pub fn get_renderable_details(&self) -> Vec<Row<'static>> {
+        let name = |resource: Option<&NamedApiResource>| {
+            resource.map(|resource| resource.get_name_or_stub().split_capitalize())
+        };
+        let attributes = self
+            .attributes
+            .iter()
+            .flatten()
+            .map(|attribute| attribute.get_name_or_stub().split_capitalize())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let details: Vec<(&str, Option<String>)> = vec![
+            ("Category", name(self.category.as_ref())),
+            (
+                "Cost",
+                self.cost
+                    .filter(|cost| *cost > 0)
+                    .map(|cost| cost.to_string()),
+            ),
+            (
+                "Fling Power",
+                self.fling_power.map(|fling_power| fling_power.to_string()),
+            ),
+            ("Fling Effect", name(self.fling_effect.as_ref())),
+            ("Attributes", Some(attributes)),
+        ];
+
+        details
+            .into_iter()
+            .filter_map(|(label, value)| {
+                let value = value.filter(|value| !value.is_empty())?;
+
+                Some(Row::new(vec![
+                    Span::styled(
+                        label.to_string().append_padding(),
+                        Style::default().fg(Color::Blue),
+                    ),
+                    Span::raw(value),
+                ]))
+            })
+            .collect()
+    }
+
+    pub fn get_renderable_effects(&self) -> Vec<Spans<'static>> {
+        let english_effect = self
+            .effect_entries
+            .iter()
+            .flatten()
+            .find(|effect_entry| effect_entry.get_language() == "en");
+
+        [
+            (
+                "Effect",
+                english_effect.and_then(|effect_entry| effect_entry.effect.clone()),
+            ),
+            (
+                "Short Effect",
+                english_effect.and_then(|effect_entry| effect_entry.short_effect.clone()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(label, effect)| {
+            let effect = effect.filter(|effect| !effect.is_empty())?;
+
+            Some(Spans::from(vec![
+                Span::styled(
+                    format!("{}: ", label).append_padding(),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::raw(effect.split_whitespace().collect::<Vec<&str>>().join(" ")),
+            ]))
+        })
+        .collect()
+    }
+
+    pub fn get_holder(&self, index: usize) -> Option<&NamedApiResource> {
+        self.held_by_pokemon
+            .as_ref()?
+            .get(index)
+            .and_then(|holder| holder.pokemon.as_ref())
+    }
+
+    pub fn get_holders_count(&self) -> usize {
+        self.held_by_pokemon.as_ref().map_or(0, Vec::len)
+    }
+
+    pub fn get_renderable_holders(&self) -> Vec<Row<'static>> {
+        self.held_by_pokemon
+            .iter()
+            .flatten()
+            .map(|holder| holder.get_renderable_as_row())
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ItemSprites {
+    pub default: Option<String>,
+}
+
+/// A wild Pokemon that may hold the item, with its rarity per version.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ItemHolderPokemon {
+    pub pokemon: Option<NamedApiResource>,
+    pub version_details: Option<Vec<PokemonHeldItemVersion>>,
+}
+
+impl ItemHolderPokemon {
+    pub fn get_renderable_as_row(&self) -> Row<'static> {
+        let name = self
+            .pokemon
+            .as_ref()
+            .map(|pokemon| {
+                pokemon
+                    .get_name_or_stub()
+                    .split_capitalize()
+                    .append_padding()
+            })
+            .unwrap_or_default();
+        let versions = self
+            .version_details
+            .iter()
+            .flatten()
+            .map(|version_detail| {
+                format!(
+                    "{} {}",
+                    version_detail.get_renderable_version().content,
+                    version_detail.get_renderable_rarity().content
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        Row::new(vec![name, versions])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tui::{
+        style::{Color, Style},
+        text::{Span, Spans},
+        widgets::Row,
+    };
+
+    use super::Item;
+
+    fn get_stub_item() -> Item {
+        serde_json::from_value(json!({
+            "id": 213,
+            "name": "light-ball",
+            "cost": 1000,
+            "fling_power": 30,
+            "fling_effect": { "name": "paralyze", "url": null },
+            "attributes": [
+                { "name": "holdable", "url": null },
+                { "name": "holdable-active", "url": null }
+            ],
+            "category": { "name": "species-specific", "url": null },
+            "effect_entries": [{
+                "effect": "Held by Pikachu:\nDoubles the holder's initial Attack and Special Attack.",
+                "short_effect": "Doubles Pikachu's Attack and Special Attack.",
+                "language": { "name": "en", "url": null }
+            }],
+            "sprites": { "default": "https://example.com/light-ball.png" },
+            "held_by_pokemon": [{
+                "pokemon": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" },
+                "version_details": [
+                    { "rarity": 5, "version": { "name": "red", "url": null } },
+                    { "rarity": 5, "version": { "name": "blue", "url": null } }
+                ]
+            }],
+            "machines": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn item_get_renderable_details() {
+        let paint_blue = |label: &str| {
+            Span::styled(format!("\u{A0}{}", label), Style::default().fg(Color::Blue))
+        };
+
+        assert_eq!(
+            get_stub_item().get_renderable_details(),
+            vec![
+                Row::new(vec![paint_blue("Category"), Span::raw("Species Specific")]),
+                Row::new(vec![paint_blue("Cost"), Span::raw("1000")]),
+                Row::new(vec![paint_blue("Fling Power"), Span::raw("30")]),
+                Row::new(vec![paint_blue("Fling Effect"), Span::raw("Paralyze")]),
+                Row::new(vec![
+                    paint_blue("Attributes"),
+                    Span::raw("Holdable, Holdable Active")
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn item_get_renderable_effects() {
+        assert_eq!(
+            get_stub_item().get_renderable_effects()[0],
+            Spans::from(vec![
+                Span::styled("\u{A0}Effect: ", Style::default().fg(Color::Blue)),
+                Span::raw(
+                    "Held by Pikachu: Doubles the holder's initial Attack and Special Attack."
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn item_get_holder() {
+        let item = get_stub_item();
+
+        assert_eq!(item.get_holders_count(), 1);
+        assert_eq!(
+            item.get_holder(0).map(|pokemon| pokemon.get_name_or_stub()),
+            Some(String::from("pikachu"))
+        );
+        assert_eq!(
+            item.get_renderable_holders(),
+            vec![Row::new(vec!["\u{A0}Pikachu", "Red 5%, Blue 5%"])]
+        );
+    }
+
+    #[test]
+    fn item_get_sprite_url() {
+        assert_eq!(
+            get_stub_item().get_sprite_url().map(String::as_str),
+            Some("https://example.com/light-ball.png")
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use super::{pokemon_encounter::PokemonEncounterVersionDetail, NamedApiResource};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Location {
+    pub id: Option<i32>,
+    pub name: Option<String>,
+    pub region: Option<NamedApiResource>,
+    pub areas: Option<Vec<NamedApiResource>>,
+}
+
+/// The region is how locations are browsed, there are too many to list at once.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Region {
+    pub id: Option<i32>,
+    pub name: Option<String>,
+    pub locations: Option<Vec<NamedApiResource>>,
+}
+
+/// A part of a location with encounters of its own, like a floor of a cave.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LocationArea {
+    pub id: Option<i32>,
+    pub name: Option<String>,
+    pub location: Option<NamedApiResource>,
+    pub pokemon_encounters: Option<Vec<LocationAreaEncounter>>,
+}
+
+/// The area's side of `PokemonEncounter`, naming the Pokemon instead of the area.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LocationAreaEncounter {
+    pub pokemon: Option<NamedApiResource>,
+    pub version_details: Option<Vec<PokemonEncounterVersionDetail>>,
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::PrepareForDisplay;
+
+use super::NamedApiResource;
+
+/// A TM, HM or TR item teaching a move in one version group.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Machine {
+    pub id: Option<i32>,
+    pub item: Option<NamedApiResource>,
+    #[serde(rename = "move")]
+    pub de_move: Option<NamedApiResource>,
+    pub version_group: Option<NamedApiResource>,
+}
+
+impl Machine {
+    /// Item names are lowercase, e.g. `tm24` or `hm05`.
+    pub fn get_renderable_item(&self) -> String {
+        self.item
+            .as_ref()
+            .map(|item| item.get_name_or_stub().to_uppercase())
+            .unwrap_or_default()
+    }
+
+    pub fn get_renderable_move(&self) -> String {
+        self.de_move
+            .as_ref()
+            .map(|de_move| de_move.get_name_or_stub().split_capitalize())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::NamedApiResource;
+
+    use super::Machine;
+
+    #[test]
+    fn machine_get_renderable_item() {
+        let machine = Machine {
+            id: Some(1),
+            item: Some(NamedApiResource {
+                name: Some(String::from("tm24")),
+                url: None,
+            }),
+            de_move: None,
+            version_group: None,
+        };
+
+        assert_eq!(machine.get_renderable_item(), "TM24");
+    }
+}
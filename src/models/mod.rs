@@ -1,33 +1,66 @@
 pub mod api_resource;
+pub mod contest_effect;
+pub mod damage_calculator;
+pub mod evolution_chain;
+pub mod extended_item_info;
+pub mod extended_location_info;
+pub mod extended_move_info;
 pub mod extended_pokemon_info;
 pub mod flavor_text_entry;
+pub mod item;
 pub mod list_wrapper;
+pub mod location;
+pub mod machine;
+pub mod move_detail;
 pub mod named_api_resource;
 pub mod pokemon;
 pub mod pokemon_ability;
+pub mod pokemon_comparison;
 pub mod pokemon_encounter;
+pub mod pokemon_form;
 pub mod pokemon_held_item;
 pub mod pokemon_move;
 pub mod pokemon_species;
 pub mod pokemon_sprites;
 pub mod pokemon_stat;
 pub mod pokemon_type;
+pub mod showdown;
+pub mod stat_spread;
+pub mod team;
+pub mod type_matchups;
 pub mod verbose_effect;
 pub mod version_game_index;
+pub mod version_group;
 
 pub use api_resource::APIResource;
+pub use contest_effect::ContestEffect;
+pub use damage_calculator::{DamageCalculator, DamageMove, DamagePokemon};
+pub use evolution_chain::{EvolutionChain, EvolutionStage};
+pub use extended_item_info::ExtendedItemInfo;
+pub use extended_location_info::ExtendedLocationInfo;
+pub use extended_move_info::ExtendedMoveInfo;
 pub use extended_pokemon_info::ExtendedPokemonInfo;
 pub use flavor_text_entry::FlavorTextEntry;
+pub use item::Item;
 pub use list_wrapper::ListWrapper;
+pub use location::{Location, LocationArea, Region};
+pub use machine::Machine;
+pub use move_detail::{MachineVersionDetail, MoveDetail};
 pub use named_api_resource::NamedApiResource;
 pub use pokemon::Pokemon;
 pub use pokemon_ability::{PokemonAbility, PokemonAbilityExt};
+pub use pokemon_comparison::PokemonComparison;
 pub use pokemon_encounter::PokemonEncounter;
+pub use pokemon_form::{ExtendedPokemonForm, PokemonForm};
 pub use pokemon_held_item::{PokemonHeldItem, PokemonHeldItemVersion};
-pub use pokemon_move::{PokemonMove, PokemonMoveExt, PokemonMoveVersion};
-pub use pokemon_species::{PokedexNumber, PokemonSpecies};
-pub use pokemon_sprites::PokemonSprites;
+pub use pokemon_move::{PokemonMove, PokemonMoveExt};
+pub use pokemon_species::PokemonSpecies;
+pub use pokemon_sprites::{PokemonSprites, SpriteVariant};
 pub use pokemon_stat::PokemonStat;
-pub use pokemon_type::PokemonType;
+pub use pokemon_type::{PokemonType, PokemonTypeExt};
+pub use stat_spread::{Nature, StatSpread};
+pub use team::{Team, TeamMember, TeamMove};
+pub use type_matchups::TypeMatchups;
 pub use verbose_effect::VerboseEffect;
 pub use version_game_index::VersionGameIndex;
+pub use version_group::VersionGroup;
@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::PrepareForDisplay;
+
+use super::{APIResource, NamedApiResource, PokemonMoveExt};
+
+/// The full `move` resource. The fields the moves table needs live in `PokemonMoveExt`,
+/// this adds what only the move page shows.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MoveDetail {
+    #[serde(flatten)]
+    pub pokemon_move: PokemonMoveExt,
+    pub effect_chance: Option<i32>,
+    pub priority: Option<i32>,
+    pub target: Option<NamedApiResource>,
+    pub meta: Option<MoveMetaData>,
+    pub stat_changes: Option<Vec<MoveStatChange>>,
+    pub contest_type: Option<NamedApiResource>,
+    pub contest_effect: Option<APIResource>,
+    pub machines: Option<Vec<MachineVersionDetail>>,
+    pub learned_by_pokemon: Option<Vec<NamedApiResource>>,
+    pub generation: Option<NamedApiResource>,
+}
+
+impl MoveDetail {
+    pub fn get_renderable_priority(&self) -> String {
+        match self.priority {
+            Some(priority) if priority > 0 => format!("+{}", priority),
+            Some(priority) => priority.to_string(),
+            None => String::from("-"),
+        }
+    }
+
+    pub fn get_renderable_stat_changes(&self) -> String {
+        self.stat_changes
+            .iter()
+            .flatten()
+            .map(|stat_change| stat_change.get_renderable())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Effect texts refer to the chance as `$effect_chance` instead of spelling it out.
+    pub fn substitute_effect_chance(&self, effect: &str) -> String {
+        match self.effect_chance {
+            Some(effect_chance) => effect.replace("$effect_chance", &effect_chance.to_string()),
+            None => effect.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MoveMetaData {
+    pub ailment: Option<NamedApiResource>,
+    pub category: Option<NamedApiResource>,
+    pub min_hits: Option<i32>,
+    pub max_hits: Option<i32>,
+    pub min_turns: Option<i32>,
+    pub max_turns: Option<i32>,
+    /// Percent of the damage dealt the user recovers, negative for recoil.
+    pub drain: Option<i32>,
+    /// Percent of its maximum HP the user recovers.
+    pub healing: Option<i32>,
+    pub crit_rate: Option<i32>,
+    pub ailment_chance: Option<i32>,
+    pub flinch_chance: Option<i32>,
+    pub stat_chance: Option<i32>,
+}
+
+impl MoveMetaData {
+    /// `none` is what PokéAPI names the ailment of moves that don't cause one.
+    pub fn get_renderable_ailment(&self) -> Option<String> {
+        let ailment = self
+            .ailment
+            .as_ref()
+            .map(|ailment| ailment.get_name_or_stub())
+            .filter(|ailment| !ailment.is_empty() && ailment != "none")?
+            .split_capitalize();
+
+        Some(match self.ailment_chance {
+            Some(ailment_chance) if ailment_chance > 0 => {
+                format!("{} ({}%)", ailment, ailment_chance)
+            }
+            _ => ailment,
+        })
+    }
+
+    pub fn get_renderable_hits(&self) -> Option<String> {
+        get_renderable_range(self.min_hits, self.max_hits)
+    }
+
+    pub fn get_renderable_turns(&self) -> Option<String> {
+        get_renderable_range(self.min_turns, self.max_turns)
+    }
+}
+
+fn get_renderable_range(min: Option<i32>, max: Option<i32>) -> Option<String> {
+    match (min, max) {
+        (Some(min), Some(max)) if min != max => Some(format!("{} - {}", min, max)),
+        (Some(min), _) => Some(min.to_string()),
+        (None, Some(max)) => Some(max.to_string()),
+        (None, None) => None,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MoveStatChange {
+    pub change: Option<i32>,
+    pub stat: Option<NamedApiResource>,
+}
+
+impl MoveStatChange {
+    pub fn get_renderable(&self) -> String {
+        let stat = self
+            .stat
+            .as_ref()
+            .map(|stat| stat.get_name_or_stub().split_capitalize())
+            .unwrap_or_default();
+
+        match self.change {
+            Some(change) if change > 0 => format!("{} +{}", stat, change),
+            Some(change) => format!("{} {}", stat, change),
+            None => stat,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MachineVersionDetail {
+    pub machine: Option<APIResource>,
+    pub version_group: Option<NamedApiResource>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{MoveDetail, MoveMetaData};
+
+    fn get_stub_move_detail() -> MoveDetail {
+        serde_json::from_value(json!({
+            "id": 85,
+            "name": "thunderbolt",
+            "power": 90,
+            "effect_chance": 10,
+            "priority": 1,
+            "stat_changes": [
+                { "change": -1, "stat": { "name": "special-defense", "url": null } },
+                { "change": 2, "stat": { "name": "speed", "url": null } }
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn get_stub_move_meta_data() -> MoveMetaData {
+        serde_json::from_value(json!({
+            "ailment": { "name": "paralysis", "url": null },
+            "ailment_chance": 10,
+            "min_hits": 2,
+            "max_hits": 5,
+            "min_turns": null,
+            "max_turns": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn move_detail_flattens_pokemon_move() {
+        let move_detail = get_stub_move_detail();
+
+        assert_eq!(
+            move_detail.pokemon_move.name.as_deref(),
+            Some("thunderbolt")
+        );
+        assert_eq!(move_detail.pokemon_move.power, Some(90));
+    }
+
+    #[test]
+    fn move_detail_get_renderable_priority() {
+        assert_eq!(get_stub_move_detail().get_renderable_priority(), "+1");
+    }
+
+    #[test]
+    fn move_detail_get_renderable_stat_changes() {
+        assert_eq!(
+            get_stub_move_detail().get_renderable_stat_changes(),
+            "Special Defense -1, Speed +2"
+        );
+    }
+
+    #[test]
+    fn move_detail_substitute_effect_chance() {
+        assert_eq!(
+            get_stub_move_detail()
+                .substitute_effect_chance("Has a $effect_chance% chance to paralyze the target."),
+            "Has a 10% chance to paralyze the target."
+        );
+    }
+
+    #[test]
+    fn move_meta_data_get_renderable() {
+        let move_meta_data = get_stub_move_meta_data();
+
+        assert_eq!(
+            move_meta_data.get_renderable_ailment(),
+            Some(String::from("Paralysis (10%)"))
+        );
+        assert_eq!(
+            move_meta_data.get_renderable_hits(),
+            Some(String::from("2 - 5"))
+        );
+        assert_eq!(move_meta_data.get_renderable_turns(), None);
+    }
+}
@@ -10,6 +10,14 @@ impl NamedApiResource {
     pub fn get_name_or_stub(&self) -> String {
         self.name.as_ref().unwrap_or(&"".to_string()).to_string()
     }
+
+    /// PokéAPI URLs end with the resource id, e.g. `.../pokemon-species/133/`.
+    pub fn get_id(&self) -> Option<i32> {
+        self.url
+            .as_ref()
+            .and_then(|url| url.trim_end_matches('/').rsplit('/').next())
+            .and_then(|id| id.parse().ok())
+    }
 }
 
 #[cfg(test)]
@@ -33,4 +41,17 @@ mod tests {
 
         assert_eq!(named_api_resource.get_name_or_stub(), String::from(""))
     }
+
+    #[test]
+    fn named_api_resource_get_id() {
+        let named_api_resource = NamedApiResource {
+            name: Some(String::from("eevee")),
+            url: Some(String::from(
+                "https://pokeapi.co/api/v2/pokemon-species/133/",
+            )),
+        };
+
+        assert_eq!(named_api_resource.get_id(), Some(133));
+        assert_eq!(get_stub_named_api_resource(None).get_id(), None);
+    }
 }
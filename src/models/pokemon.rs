@@ -1,12 +1,17 @@
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use tui::{text::Span, widgets::Row};
+use tui::{
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::Row,
+};
 
 use crate::utils::PrepareForDisplay;
 
 use super::{
+    stat_spread::{calculate_stat_range, get_stat_abbreviation, StatSpread, STAT_NAMES},
     NamedApiResource, PokemonAbility, PokemonHeldItem, PokemonMove, PokemonSprites, PokemonStat,
-    PokemonType, VersionGameIndex,
+    PokemonType, VersionGameIndex, VersionGroup,
 };
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -87,28 +92,120 @@ impl Pokemon {
             .unwrap_or(vec![])
     }
 
-    pub fn get_renderable_stats(&self) -> Vec<Row> {
+    pub fn get_base_stat(&self, stat_name: &str) -> Option<i32> {
         self.stats
-            .as_ref()
-            .and_then(|stats| {
-                Some(
-                    stats
-                        .par_iter()
-                        .map(|stat| stat.get_renderable_as_row())
-                        .collect(),
-                )
+            .iter()
+            .flatten()
+            .find(|stat| stat.get_name() == Some(stat_name))?
+            .base_stat
+    }
+
+    /// Names of the moves the Pokemon learns in the version group, whichever way it learns them.
+    pub fn get_move_names(&self, selected_version_group: &str) -> Vec<String> {
+        self.moves
+            .iter()
+            .flatten()
+            .filter(|pokemon_move| {
+                pokemon_move
+                    .get_renderable_version_group_details(selected_version_group)
+                    .is_some_and(|version_group_details| !version_group_details.is_empty())
             })
-            .unwrap_or(vec![])
+            .filter_map(|pokemon_move| pokemon_move.de_move.as_ref()?.name.clone())
+            .collect()
+    }
+
+    /// A row per stat, then the base stat total.
+    pub fn get_renderable_stats(&self, bar_width: u16) -> Vec<Row<'static>> {
+        let mut rows: Vec<Row> = self
+            .stats
+            .iter()
+            .flatten()
+            .map(|stat| stat.get_renderable_as_row(bar_width))
+            .collect();
+        let total: i32 = self
+            .stats
+            .iter()
+            .flatten()
+            .filter_map(|stat| stat.base_stat)
+            .sum();
+
+        rows.push(
+            Row::new(vec![
+                Span::styled(
+                    String::from("Total").append_padding(),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::raw(total.to_string()),
+            ])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+        );
+
+        rows
+    }
+
+    /// A row per stat with the spread applied, next to the range the stat can have at
+    /// levels 50 and 100.
+    pub fn get_renderable_calculated_stats(&self, stat_spread: &StatSpread) -> Vec<Row<'static>> {
+        STAT_NAMES
+            .iter()
+            .enumerate()
+            .map(|(stat_index, stat_name)| {
+                let base_stat = self.get_base_stat(stat_name).unwrap_or_default();
+                let get_range = |level| {
+                    let (min, max) = calculate_stat_range(stat_name, base_stat, level);
+                    format!("{}-{}", min, max)
+                };
+
+                Row::new(vec![
+                    Spans::from(Span::styled(
+                        get_stat_abbreviation(stat_name)
+                            .to_string()
+                            .append_padding(),
+                        Style::default().fg(Color::Blue),
+                    )),
+                    Spans::from(base_stat.to_string()),
+                    Spans::from(stat_spread.ivs[stat_index].to_string()),
+                    Spans::from(stat_spread.evs[stat_index].to_string()),
+                    Spans::from(stat_spread.nature.get_renderable_effect_on(stat_name)),
+                    Spans::from(Span::styled(
+                        stat_spread.calculate(stat_index, base_stat).to_string(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )),
+                    Spans::from(get_range(50)),
+                    Spans::from(get_range(100)),
+                ])
+            })
+            .collect()
+    }
+
+    /// The item on the given row of the held items table, which has a row per version.
+    pub fn get_held_item(
+        &self,
+        selected_version_group: &VersionGroup,
+        index: usize,
+    ) -> Option<&NamedApiResource> {
+        self.held_items
+            .iter()
+            .flatten()
+            .flat_map(|held_item| {
+                held_item
+                    .get_version_details(selected_version_group)
+                    .into_iter()
+                    .map(move |_| held_item)
+            })
+            .nth(index)
+            .and_then(|held_item| held_item.item.as_ref())
     }
 
-    pub fn get_renderable_held_items(&self, selected_version: &str) -> Vec<Row> {
+    pub fn get_renderable_held_items(&self, selected_version_group: &VersionGroup) -> Vec<Row<'_>> {
         self.held_items
             .as_ref()
             .and_then(|held_items| {
                 let mut prepared_held_items: Vec<Row> = vec![];
 
                 held_items.iter().for_each(|held_item| {
-                    prepared_held_items.extend(held_item.get_renderable_as_rows(selected_version))
+                    prepared_held_items
+                        .extend(held_item.get_renderable_as_rows(selected_version_group))
                 });
 
                 Some(prepared_held_items)
@@ -120,13 +217,17 @@ impl Pokemon {
 #[cfg(test)]
 mod tests {
     use tui::{
-        style::{Color, Style},
-        text::Span,
+        style::{Color, Modifier, Style},
+        text::{Span, Spans},
         widgets::Row,
     };
 
     use crate::models::{
-        NamedApiResource, PokemonHeldItem, PokemonHeldItemVersion, PokemonStat, PokemonType,
+        pokemon_move::PokemonMoveVersion,
+        stat_spread::{Nature, StatSpread},
+        version_group::fixture::get_stub_version_group,
+        NamedApiResource, PokemonHeldItem, PokemonHeldItemVersion, PokemonMove, PokemonStat,
+        PokemonType,
     };
 
     use super::Pokemon;
@@ -230,19 +331,56 @@ mod tests {
     fn pokemon_get_renderable_stats() {
         let pokemon = get_stub_pokemon();
         assert_eq!(
-            pokemon.get_renderable_stats(),
-            vec![Row::new(vec![
-                Span::styled("\u{A0}Speed", Style::default().fg(Color::Blue)),
-                Span::raw("15"),
-            ])]
+            pokemon.get_renderable_stats(17),
+            vec![
+                Row::new(vec![
+                    Spans::from(Span::styled("\u{A0}Spe", Style::default().fg(Color::Blue))),
+                    Spans::from("15"),
+                    Spans::from(Span::styled("█", Style::default().fg(Color::Red))),
+                    Spans::from(Span::styled("", Style::default().fg(Color::Green))),
+                ]),
+                Row::new(vec![
+                    Span::styled("\u{A0}Total", Style::default().fg(Color::Blue)),
+                    Span::raw("15"),
+                ])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+            ]
         )
     }
 
+    #[test]
+    fn pokemon_get_renderable_calculated_stats() {
+        let pokemon = get_stub_pokemon();
+        let stat_spread = StatSpread {
+            nature: Nature::Jolly,
+            ..StatSpread::default()
+        };
+        let rows = pokemon.get_renderable_calculated_stats(&stat_spread);
+
+        assert_eq!(rows.len(), 6);
+        assert_eq!(
+            rows[5],
+            Row::new(vec![
+                Spans::from(Span::styled("\u{A0}Spe", Style::default().fg(Color::Blue))),
+                Spans::from("15"),
+                Spans::from("31"),
+                Spans::from("0"),
+                Spans::from(Span::styled("+", Style::default().fg(Color::Green))),
+                Spans::from(Span::styled(
+                    "38",
+                    Style::default().add_modifier(Modifier::BOLD)
+                )),
+                Spans::from("18-73"),
+                Spans::from("31-141"),
+            ])
+        );
+    }
+
     #[test]
     fn pokemon_get_renderable_held_items() {
         let pokemon = get_stub_pokemon();
         assert_eq!(
-            pokemon.get_renderable_held_items("x-y"),
+            pokemon.get_renderable_held_items(&get_stub_version_group("x-y", &["x", "y"])),
             vec![Row::new(vec![
                 Span::styled("\u{A0}Sharp fang", Style::default().fg(Color::Blue)),
                 Span::raw("20%"),
@@ -250,4 +388,48 @@ mod tests {
             ]),]
         )
     }
+
+    #[test]
+    fn pokemon_get_held_item() {
+        let pokemon = get_stub_pokemon();
+
+        assert_eq!(
+            pokemon
+                .get_held_item(&get_stub_version_group("x-y", &["x", "y"]), 0)
+                .map(|item| item.get_name_or_stub()),
+            Some(String::from("sharp fang"))
+        );
+        assert!(pokemon
+            .get_held_item(&get_stub_version_group("x-y", &["x", "y"]), 1)
+            .is_none());
+        assert!(pokemon
+            .get_held_item(&get_stub_version_group("red-blue", &["red", "blue"]), 0)
+            .is_none());
+    }
+
+    #[test]
+    fn pokemon_get_move_names() {
+        let mut pokemon = get_stub_pokemon();
+        let get_move = |name: &str, version_group: &str| PokemonMove {
+            de_move: Some(NamedApiResource {
+                name: Some(String::from(name)),
+                url: None,
+            }),
+            version_group_details: Some(vec![PokemonMoveVersion {
+                level_learned_at: Some(1),
+                move_learn_method: None,
+                version_group: Some(NamedApiResource {
+                    name: Some(String::from(version_group)),
+                    url: None,
+                }),
+            }]),
+        };
+        pokemon.moves = Some(vec![
+            get_move("thunderbolt", "x-y"),
+            get_move("volt-tackle", "red-blue"),
+        ]);
+
+        assert_eq!(pokemon.get_move_names("x-y"), vec!["thunderbolt"]);
+        assert!(pokemon.get_move_names("sun-moon").is_empty());
+    }
 }
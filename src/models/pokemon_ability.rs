@@ -1,10 +1,17 @@
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use tui::{text::Span, widgets::Row};
+use tui::{
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::Row,
+};
 
-use crate::utils::PrepareForDisplay;
+use crate::utils::{get_renderable_generation, PrepareForDisplay};
 
-use super::{FlavorTextEntry, NamedApiResource, VerboseEffect};
+use super::{
+    flavor_text_entry::get_renderable_flavor_texts_by_version_group, FlavorTextEntry,
+    NamedApiResource, VerboseEffect,
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PokemonAbility {
@@ -44,6 +51,9 @@ pub struct PokemonAbilityExt {
     pub name: Option<String>,
     pub effect_entries: Option<Vec<VerboseEffect>>,
     pub flavor_text_entries: Option<Vec<FlavorTextEntry>>,
+    pub generation: Option<NamedApiResource>,
+    pub effect_changes: Option<Vec<AbilityEffectChange>>,
+    pub pokemon: Option<Vec<AbilityPokemon>>,
 }
 
 impl PokemonAbilityExt {
@@ -72,11 +82,136 @@ impl PokemonAbilityExt {
             })
             .unwrap_or(String::new())
     }
+
+    /// The long English effect and the generation the ability was introduced in.
+    pub fn get_renderable_details(&self) -> Vec<Spans<'static>> {
+        let effect = get_english_long_effect(self.effect_entries.as_deref());
+        let generation = self
+            .generation
+            .as_ref()
+            .map(|generation| get_renderable_generation(&generation.get_name_or_stub()));
+
+        [("Generation", generation), ("Effect", effect)]
+            .into_iter()
+            .filter_map(|(label, value)| {
+                Some(Spans::from(vec![
+                    Span::styled(
+                        format!("{}: ", label).append_padding(),
+                        Style::default().fg(Color::Blue),
+                    ),
+                    Span::raw(value?),
+                ]))
+            })
+            .collect()
+    }
+
+    pub fn get_renderable_flavor_texts(&self) -> Vec<Spans<'static>> {
+        get_renderable_flavor_texts_by_version_group(
+            self.flavor_text_entries.as_deref().unwrap_or_default(),
+        )
+    }
+
+    pub fn get_renderable_effect_changes(&self) -> Vec<Spans<'static>> {
+        self.effect_changes
+            .iter()
+            .flatten()
+            .map(|effect_change| effect_change.get_renderable())
+            .collect()
+    }
+
+    pub fn get_pokemon(&self, index: usize) -> Option<&NamedApiResource> {
+        self.pokemon
+            .as_ref()?
+            .get(index)
+            .and_then(|ability_pokemon| ability_pokemon.pokemon.as_ref())
+    }
+
+    pub fn get_pokemon_count(&self) -> usize {
+        self.pokemon.as_ref().map_or(0, Vec::len)
+    }
+
+    pub fn get_renderable_pokemon(&self) -> Vec<Row<'static>> {
+        self.pokemon
+            .iter()
+            .flatten()
+            .map(|ability_pokemon| ability_pokemon.get_renderable_as_row())
+            .collect()
+    }
+}
+
+/// How the ability worked before the version group it changed in.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AbilityEffectChange {
+    /// Only `effect` is set, there's no `short_effect` for past effects.
+    pub effect_entries: Option<Vec<VerboseEffect>>,
+    pub version_group: Option<NamedApiResource>,
+}
+
+impl AbilityEffectChange {
+    pub fn get_renderable(&self) -> Spans<'static> {
+        let version_group = self
+            .version_group
+            .as_ref()
+            .map(|version_group| version_group.get_name_or_stub().split_capitalize())
+            .unwrap_or_default();
+        let effect = get_english_long_effect(self.effect_entries.as_deref()).unwrap_or_default();
+
+        Spans::from(vec![
+            Span::styled(
+                format!("Before {}: ", version_group).append_padding(),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw(effect),
+        ])
+    }
+}
+
+/// The long English effect on one line, PokéAPI wraps it with newlines.
+fn get_english_long_effect(effect_entries: Option<&[VerboseEffect]>) -> Option<String> {
+    effect_entries?
+        .iter()
+        .find(|verbose_effect| verbose_effect.get_language() == "en")
+        .and_then(|verbose_effect| verbose_effect.effect.as_ref())
+        .map(|effect| effect.split_whitespace().collect::<Vec<&str>>().join(" "))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AbilityPokemon {
+    pub is_hidden: Option<bool>,
+    pub slot: Option<i32>,
+    pub pokemon: Option<NamedApiResource>,
+}
+
+impl AbilityPokemon {
+    pub fn get_renderable_as_row(&self) -> Row<'static> {
+        let name = self
+            .pokemon
+            .as_ref()
+            .map(|pokemon| {
+                pokemon
+                    .get_name_or_stub()
+                    .split_capitalize()
+                    .append_padding()
+            })
+            .unwrap_or_default();
+        let slot = if self.is_hidden.unwrap_or(false) {
+            Span::styled("Hidden", Style::default().fg(Color::Yellow))
+        } else {
+            Span::raw("Normal")
+        };
+
+        Row::new(vec![Span::raw(name), slot])
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use tui::{text::Span, widgets::Row};
+    use serde_json::json;
+    use tui::{
+        style::{Color, Style},
+        text::{Span, Spans},
+        widgets::Row,
+    };
 
     use crate::models::NamedApiResource;
 
@@ -106,9 +241,45 @@ mod tests {
                 }),
             }]),
             flavor_text_entries: None,
+            generation: None,
+            effect_changes: None,
+            pokemon: None,
         }
     }
 
+    fn get_stub_pokemon_ability_ext_detail() -> PokemonAbilityExt {
+        serde_json::from_value(json!({
+            "id": 9,
+            "name": "static",
+            "generation": { "name": "generation-iii", "url": null },
+            "effect_entries": [{
+                "effect": "Whenever a move makes contact with this Pokémon,\nthe move's user has a 30% chance of being paralyzed.",
+                "short_effect": "Has a 30% chance of paralyzing attacking Pokémon on contact.",
+                "language": { "name": "en", "url": null }
+            }],
+            "effect_changes": [{
+                "effect_entries": [{
+                    "effect": "Has no overworld effect.",
+                    "language": { "name": "en", "url": null }
+                }],
+                "version_group": { "name": "black-white", "url": null }
+            }],
+            "pokemon": [
+                {
+                    "is_hidden": false,
+                    "slot": 1,
+                    "pokemon": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" }
+                },
+                {
+                    "is_hidden": true,
+                    "slot": 3,
+                    "pokemon": { "name": "pichu", "url": "https://pokeapi.co/api/v2/pokemon/172/" }
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
     #[test]
     fn pokemon_ability_get_renderable_is_hidden_work_with_hidden() {
         assert_eq!(
@@ -153,4 +324,76 @@ mod tests {
             get_stub_pokemon_ability_ext().get_renderable_effect_entry()
         )
     }
+
+    #[test]
+    fn pokemon_ability_ext_get_renderable_details() {
+        assert_eq!(
+            get_stub_pokemon_ability_ext_detail().get_renderable_details(),
+            vec![
+                Spans::from(vec![
+                    Span::styled("\u{A0}Generation: ", Style::default().fg(Color::Blue)),
+                    Span::raw("Generation III"),
+                ]),
+                Spans::from(vec![
+                    Span::styled("\u{A0}Effect: ", Style::default().fg(Color::Blue)),
+                    Span::raw("Whenever a move makes contact with this Pokémon, the move's user has a 30% chance of being paralyzed."),
+                ]),
+            ]
+        )
+    }
+
+    #[test]
+    fn pokemon_ability_ext_get_renderable_details_keeps_numeral() {
+        let mut pokemon_ability_ext = get_stub_pokemon_ability_ext();
+        pokemon_ability_ext.effect_entries = None;
+        pokemon_ability_ext.generation = Some(NamedApiResource {
+            name: Some(String::from("generation-viii")),
+            url: None,
+        });
+
+        assert_eq!(
+            pokemon_ability_ext.get_renderable_details(),
+            vec![Spans::from(vec![
+                Span::styled("\u{A0}Generation: ", Style::default().fg(Color::Blue)),
+                Span::raw("Generation VIII"),
+            ])]
+        )
+    }
+
+    #[test]
+    fn pokemon_ability_ext_get_renderable_effect_changes() {
+        assert_eq!(
+            get_stub_pokemon_ability_ext_detail().get_renderable_effect_changes(),
+            vec![Spans::from(vec![
+                Span::styled(
+                    "\u{A0}Before Black White: ",
+                    Style::default().fg(Color::Blue)
+                ),
+                Span::raw("Has no overworld effect."),
+            ])]
+        )
+    }
+
+    #[test]
+    fn pokemon_ability_ext_get_pokemon() {
+        let pokemon_ability_ext = get_stub_pokemon_ability_ext_detail();
+
+        assert_eq!(pokemon_ability_ext.get_pokemon_count(), 2);
+        assert_eq!(
+            pokemon_ability_ext
+                .get_pokemon(1)
+                .map(|pokemon| pokemon.get_name_or_stub()),
+            Some(String::from("pichu"))
+        );
+        assert_eq!(
+            pokemon_ability_ext.get_renderable_pokemon(),
+            vec![
+                Row::new(vec![Span::raw("\u{A0}Pikachu"), Span::raw("Normal")]),
+                Row::new(vec![
+                    Span::raw("\u{A0}Pichu"),
+                    Span::styled("Hidden", Style::default().fg(Color::Yellow)),
+                ]),
+            ]
+        )
+    }
 }
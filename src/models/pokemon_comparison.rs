@@ -0,0 +1,395 @@
+use std::collections::HashSet;
+
+use tui::{
+    style::{Color, Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Cell, Row},
+};
+
+use crate::utils::PrepareForDisplay;
+
+use super::{
+    stat_spread::{get_stat_abbreviation, STAT_NAMES},
+    ExtendedPokemonInfo, TypeMatchups,
+};
+
+/// How many Pokemon fit side by side on the comparison page.
+pub const MAX_COMPARED_POKEMON: usize = 4;
+
+/// Pokemon laid out side by side, in the order they were marked.
+pub struct PokemonComparison {
+    pub pokemon: Vec<ExtendedPokemonInfo>,
+}
+
+impl PokemonComparison {
+    pub fn get_renderable_names(&self) -> Vec<Span<'static>> {
+        self.pokemon
+            .iter()
+            .map(|pokemon| {
+                Span::styled(
+                    pokemon
+                        .pokemon
+                        .name
+                        .clone()
+                        .unwrap_or_default()
+                        .split_capitalize(),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            })
+            .collect()
+    }
+
+    /// Types, base stats with the best of every stat highlighted, abilities and weaknesses.
+    pub fn get_renderable_overview(&self) -> Vec<Row<'_>> {
+        let mut rows = vec![self.get_renderable_row(
+            "Types",
+            self.pokemon
+                .iter()
+                .map(|pokemon| Text::from(Spans::from(pokemon.get_renderable_types())))
+                .collect(),
+        )];
+
+        for stat_name in STAT_NAMES {
+            let stats: Vec<i32> = self
+                .pokemon
+                .iter()
+                .map(|pokemon| pokemon.pokemon.get_base_stat(stat_name).unwrap_or_default())
+                .collect();
+            rows.push(self.get_renderable_stats_row(get_stat_abbreviation(stat_name), stats));
+        }
+
+        let totals: Vec<i32> = self
+            .pokemon
+            .iter()
+            .map(|pokemon| {
+                STAT_NAMES
+                    .iter()
+                    .filter_map(|stat_name| pokemon.pokemon.get_base_stat(stat_name))
+                    .sum()
+            })
+            .collect();
+        rows.push(self.get_renderable_stats_row("Total", totals));
+
+        rows.push(
+            self.get_renderable_row(
+                "Abilities",
+                self.pokemon
+                    .iter()
+                    .map(|pokemon| Text::from(get_renderable_abilities(pokemon)))
+                    .collect(),
+            ),
+        );
+        rows.push(
+            self.get_renderable_row(
+                "Weak To",
+                self.pokemon
+                    .iter()
+                    .map(|pokemon| {
+                        Text::from(
+                            TypeMatchups::new(pokemon.get_types(), None)
+                                .get_renderable_weaknesses(),
+                        )
+                    })
+                    .collect(),
+            ),
+        );
+
+        rows
+    }
+
+    /// The lines the overview takes, so the moves can have the rest of the page.
+    pub fn get_overview_height(&self) -> u16 {
+        let abilities_height = self
+            .pokemon
+            .iter()
+            .map(|pokemon| get_renderable_abilities(pokemon).len())
+            .max()
+            .unwrap_or_default();
+        let weaknesses_height = self
+            .pokemon
+            .iter()
+            .map(|pokemon| {
+                TypeMatchups::new(pokemon.get_types(), None)
+                    .get_renderable_weaknesses()
+                    .len()
+            })
+            .max()
+            .unwrap_or_default();
+
+        // Types, every stat and the total take a line each
+        (STAT_NAMES.len() + 2 + abilities_height.max(1) + weaknesses_height.max(1)) as u16
+    }
+
+    fn get_renderable_row<'a>(&self, label: &str, cells: Vec<Text<'a>>) -> Row<'a> {
+        let height = cells.iter().map(Text::height).max().unwrap_or(1).max(1);
+        let mut row_cells = vec![Cell::from(Span::styled(
+            label.to_string().append_padding(),
+            Style::default().fg(Color::Blue),
+        ))];
+        row_cells.extend(cells.into_iter().map(Cell::from));
+
+        Row::new(row_cells).height(height as u16)
+    }
+
+    /// The best value is only highlighted when there's something to compare it with.
+    fn get_renderable_stats_row(&self, label: &str, stats: Vec<i32>) -> Row<'static> {
+        let best = stats.iter().copied().max().unwrap_or_default();
+        let cells = stats
+            .into_iter()
+            .map(|stat| {
+                let style = if stat == best && self.pokemon.len() > 1 {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+
+                Text::from(Span::styled(stat.to_string(), style))
+            })
+            .collect();
+
+        self.get_renderable_row(label, cells)
+    }
+
+    fn get_move_names(&self, selected_version_group: &str) -> Vec<HashSet<String>> {
+        self.pokemon
+            .iter()
+            .map(|pokemon| {
+                pokemon
+                    .pokemon
+                    .get_move_names(selected_version_group)
+                    .into_iter()
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Moves every compared Pokemon learns in the version group, sorted by name.
+    pub fn get_shared_moves(&self, selected_version_group: &str) -> Vec<String> {
+        let move_names = self.get_move_names(selected_version_group);
+        let mut shared_moves: Vec<String> = match move_names.split_first() {
+            Some((first, rest)) => first
+                .iter()
+                .filter(|move_name| rest.iter().all(|other| other.contains(*move_name)))
+                .cloned()
+                .collect(),
+            None => vec![],
+        };
+        shared_moves.sort();
+
+        shared_moves
+    }
+
+    /// For every compared Pokemon, the moves none of the others learn, sorted by name.
+    pub fn get_unique_moves(&self, selected_version_group: &str) -> Vec<Vec<String>> {
+        let move_names = self.get_move_names(selected_version_group);
+
+        move_names
+            .iter()
+            .enumerate()
+            .map(|(index, own)| {
+                let mut unique_moves: Vec<String> = own
+                    .iter()
+                    .filter(|move_name| {
+                        move_names.iter().enumerate().all(|(other_index, other)| {
+                            other_index == index || !other.contains(*move_name)
+                        })
+                    })
+                    .cloned()
+                    .collect();
+                unique_moves.sort();
+
+                unique_moves
+            })
+            .collect()
+    }
+
+    pub fn get_renderable_shared_moves(&self, selected_version_group: &str) -> Vec<Spans<'static>> {
+        let shared_moves = self.get_shared_moves(selected_version_group);
+        if shared_moves.is_empty() {
+            return vec![Spans::from(Span::styled(
+                String::from("None").append_padding(),
+                Style::default().fg(Color::DarkGray),
+            ))];
+        }
+
+        vec![Spans::from(
+            shared_moves
+                .into_iter()
+                .map(|move_name| move_name.split_capitalize())
+                .collect::<Vec<String>>()
+                .join(", ")
+                .append_padding(),
+        )]
+    }
+
+    /// A column per Pokemon, as long as the longest list of unique moves.
+    pub fn get_renderable_unique_moves(&self, selected_version_group: &str) -> Vec<Row<'static>> {
+        let unique_moves = self.get_unique_moves(selected_version_group);
+        let rows_count = unique_moves.iter().map(Vec::len).max().unwrap_or_default();
+
+        (0..rows_count)
+            .map(|index| {
+                Row::new(
+                    unique_moves
+                        .iter()
+                        .map(|moves| {
+                            moves
+                                .get(index)
+                                .map(|move_name| move_name.clone().split_capitalize())
+                                .unwrap_or_default()
+                        })
+                        .collect::<Vec<String>>(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// A line per ability, hidden abilities marked as such.
+fn get_renderable_abilities(pokemon: &ExtendedPokemonInfo) -> Vec<Spans<'static>> {
+    pokemon
+        .pokemon
+        .abilities
+        .iter()
+        .flatten()
+        .filter_map(|ability| {
+            let name = ability.ability.as_ref()?.name.clone()?.split_capitalize();
+
+            Some(match ability.is_hidden {
+                Some(true) => Spans::from(vec![
+                    Span::raw(name),
+                    Span::styled(" (Hidden)", Style::default().fg(Color::DarkGray)),
+                ]),
+                _ => Spans::from(name),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::{json, Value};
+    use tui::{
+        style::{Color, Modifier, Style},
+        text::{Span, Text},
+        widgets::{Cell, Row},
+    };
+
+    use crate::models::ExtendedPokemonInfo;
+
+    use super::PokemonComparison;
+
+    fn get_stub_pokemon(name: &str, speed: i32, moves: &[&str]) -> ExtendedPokemonInfo {
+        let moves: Vec<Value> = moves
+            .iter()
+            .map(|move_name| {
+                json!({
+                    "move": { "name": move_name, "url": null },
+                    "version_group_details": [{
+                        "level_learned_at": 1,
+                        "version_group": { "name": "x-y", "url": null }
+                    }]
+                })
+            })
+            .collect();
+
+        ExtendedPokemonInfo {
+            pokemon: serde_json::from_value(json!({
+                "name": name,
+                "abilities": [
+                    { "is_hidden": false, "ability": { "name": "static", "url": null } },
+                    { "is_hidden": true, "ability": { "name": "lightning-rod", "url": null } }
+                ],
+                "moves": moves,
+                "stats": [{ "base_stat": speed, "effort": 0, "stat": { "name": "speed", "url": null } }]
+            }))
+            .unwrap(),
+            abilities: HashMap::new(),
+            moves: HashMap::new(),
+            species: serde_json::from_value(json!({})).unwrap(),
+            encounters: vec![],
+            evolution_chain: None,
+            types: vec![],
+            form: None,
+        }
+    }
+
+    fn get_stub_comparison() -> PokemonComparison {
+        PokemonComparison {
+            pokemon: vec![
+                get_stub_pokemon("pikachu", 90, &["thunderbolt", "volt-tackle", "surf"]),
+                get_stub_pokemon("raichu", 110, &["thunderbolt", "surf", "thunder-punch"]),
+                get_stub_pokemon("pichu", 60, &["thunderbolt", "sweet-kiss"]),
+            ],
+        }
+    }
+
+    #[test]
+    fn pokemon_comparison_get_shared_moves() {
+        let comparison = get_stub_comparison();
+
+        assert_eq!(comparison.get_shared_moves("x-y"), vec!["thunderbolt"]);
+        assert!(comparison.get_shared_moves("red-blue").is_empty());
+    }
+
+    #[test]
+    fn pokemon_comparison_get_unique_moves() {
+        let comparison = get_stub_comparison();
+
+        assert_eq!(
+            comparison.get_unique_moves("x-y"),
+            vec![
+                vec![String::from("volt-tackle")],
+                vec![String::from("thunder-punch")],
+                vec![String::from("sweet-kiss")],
+            ]
+        );
+        assert_eq!(comparison.get_renderable_unique_moves("x-y").len(), 1);
+    }
+
+    #[test]
+    fn pokemon_comparison_highlights_the_best_stat() {
+        let comparison = get_stub_comparison();
+
+        assert_eq!(
+            comparison.get_renderable_stats_row("Spe", vec![90, 110, 60]),
+            Row::new(vec![
+                Cell::from(Span::styled("\u{A0}Spe", Style::default().fg(Color::Blue))),
+                Cell::from(Text::from(Span::raw("90"))),
+                Cell::from(Text::from(Span::styled(
+                    "110",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                ))),
+                Cell::from(Text::from(Span::raw("60"))),
+            ])
+        );
+    }
+
+    #[test]
+    fn pokemon_comparison_get_renderable_names() {
+        assert_eq!(
+            get_stub_comparison().get_renderable_names()[1],
+            Span::styled(
+                "Raichu",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            )
+        );
+    }
+
+    #[test]
+    fn pokemon_comparison_get_overview_height() {
+        // Types, 6 stats, the total, 2 abilities and a line for no weaknesses
+        assert_eq!(get_stub_comparison().get_overview_height(), 11);
+        assert_eq!(get_stub_comparison().get_renderable_overview().len(), 10);
+    }
+}
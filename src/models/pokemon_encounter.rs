@@ -4,7 +4,7 @@ use tui::{text::Span, widgets::Row};
 
 use crate::utils::PrepareForDisplay;
 
-use super::NamedApiResource;
+use super::{NamedApiResource, VersionGroup};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PokemonEncounterDetail {
@@ -12,6 +12,52 @@ pub struct PokemonEncounterDetail {
     pub max_level: Option<i32>,
     pub min_level: Option<i32>,
     pub method: Option<NamedApiResource>,
+    /// Time of day, season, swarm or radio states the encounter depends on.
+    pub condition_values: Option<Vec<NamedApiResource>>,
+}
+
+impl PokemonEncounterDetail {
+    pub fn get_renderable_method(&self) -> String {
+        self.method
+            .as_ref()
+            .map(|method| method.get_name_or_stub().split_capitalize())
+            .unwrap_or_default()
+    }
+
+    pub fn get_renderable_levels(&self) -> String {
+        match (self.min_level, self.max_level) {
+            (Some(min_level), Some(max_level)) if min_level != max_level => {
+                format!("{} - {}", min_level, max_level)
+            }
+            (Some(level), _) | (None, Some(level)) => level.to_string(),
+            (None, None) => String::new(),
+        }
+    }
+
+    pub fn get_renderable_chance(&self) -> String {
+        self.chance
+            .map(|chance| format!("{}%", chance))
+            .unwrap_or_default()
+    }
+
+    /// Condition values are named like `time-morning` or `swarm-no`.
+    pub fn get_renderable_conditions(&self) -> String {
+        self.condition_values
+            .iter()
+            .flatten()
+            .map(|condition_value| condition_value.get_name_or_stub().split_capitalize())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    pub fn get_renderable_as_row(&self) -> Row<'static> {
+        Row::new(vec![
+            self.get_renderable_method().append_padding(),
+            self.get_renderable_levels(),
+            self.get_renderable_chance(),
+            self.get_renderable_conditions(),
+        ])
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -79,6 +125,15 @@ impl PokemonEncounterVersionDetail {
 
         Span::raw(format!("{} - {}", min_level, max_level))
     }
+
+    /// Unlike the summary row, keeps each method's levels, chance and conditions apart.
+    pub fn get_renderable_encounter_details(&self) -> Vec<Row<'static>> {
+        self.encounter_details
+            .iter()
+            .flatten()
+            .map(|encounter_detail| encounter_detail.get_renderable_as_row())
+            .collect()
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -103,45 +158,39 @@ impl PokemonEncounter {
             .unwrap_or(Span::raw(""))
     }
 
-    pub fn get_renderable_as_rows(&self, selected_version_group: &str) -> Vec<Row> {
-        let version_detail_by_version_groups =
-            self.version_details.as_ref().and_then(|version_details| {
-                Some(
-                    version_details
-                        .par_iter()
-                        .filter(|version| {
-                            if selected_version_group
-                                .to_string()
-                                .split("-")
-                                .map(|item| item.to_string())
-                                .collect::<Vec<String>>()
-                                .contains(&version.version.as_ref().unwrap().get_name_or_stub())
-                            {
-                                return true;
-                            }
-
-                            false
+    /// The versions of the version group the Pokemon can be found in, one table row each.
+    pub fn get_version_details(
+        &self,
+        selected_version_group: &VersionGroup,
+    ) -> Vec<&PokemonEncounterVersionDetail> {
+        self.version_details
+            .as_ref()
+            .map(|version_details| {
+                version_details
+                    .par_iter()
+                    .filter(|version_detail| {
+                        version_detail.version.as_ref().is_some_and(|version| {
+                            selected_version_group.has_version(&version.get_name_or_stub())
                         })
-                        .collect::<Vec<&PokemonEncounterVersionDetail>>(),
-                )
-            });
-
-        if let Some(version_detail_by_version_groups) = version_detail_by_version_groups {
-            return version_detail_by_version_groups
-                .par_iter()
-                .map(|version_detail_by_version_group| {
-                    Row::new(vec![
-                        self.get_renderable_location_area(),
-                        version_detail_by_version_group.get_renderable_methods(),
-                        version_detail_by_version_group.get_renderable_version(),
-                        version_detail_by_version_group.get_renderable_max_chance(),
-                        version_detail_by_version_group.get_renderable_levels(),
-                    ])
-                })
-                .collect();
-        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-        vec![]
+    pub fn get_renderable_as_rows(&self, selected_version_group: &VersionGroup) -> Vec<Row<'_>> {
+        self.get_version_details(selected_version_group)
+            .par_iter()
+            .map(|version_detail_by_version_group| {
+                Row::new(vec![
+                    self.get_renderable_location_area(),
+                    version_detail_by_version_group.get_renderable_methods(),
+                    version_detail_by_version_group.get_renderable_version(),
+                    version_detail_by_version_group.get_renderable_max_chance(),
+                    version_detail_by_version_group.get_renderable_levels(),
+                ])
+            })
+            .collect()
     }
 }
 
@@ -149,12 +198,13 @@ impl PokemonEncounter {
 mod tests {
     use tui::{text::Span, widgets::Row};
 
-    use crate::models::NamedApiResource;
+    use crate::models::{version_group::fixture::get_stub_version_group, NamedApiResource};
 
     use super::{PokemonEncounter, PokemonEncounterDetail, PokemonEncounterVersionDetail};
 
     fn get_stubbed_pokemon_encounter_detail() -> PokemonEncounterDetail {
         PokemonEncounterDetail {
+            condition_values: None,
             chance: Some(50),
             min_level: Some(1),
             max_level: Some(10),
@@ -175,6 +225,7 @@ mod tests {
             encounter_details: Some(vec![
                 get_stubbed_pokemon_encounter_detail(),
                 PokemonEncounterDetail {
+                    condition_values: None,
                     chance: Some(50),
                     min_level: Some(20),
                     max_level: Some(40),
@@ -184,6 +235,7 @@ mod tests {
                     }),
                 },
                 PokemonEncounterDetail {
+                    condition_values: None,
                     chance: Some(50),
                     min_level: Some(11),
                     max_level: Some(35),
@@ -211,6 +263,7 @@ mod tests {
                         url: None,
                     }),
                     encounter_details: Some(vec![PokemonEncounterDetail {
+                        condition_values: None,
                         chance: Some(1),
                         min_level: Some(100),
                         max_level: Some(100),
@@ -260,11 +313,42 @@ mod tests {
         )
     }
 
+    #[test]
+    fn pokemon_encounter_version_detail_get_renderable_encounter_details() {
+        let mut encounter_version_detail = get_stubbed_pokemon_encounter_version_detail();
+        if let Some(encounter_details) = encounter_version_detail.encounter_details.as_mut() {
+            encounter_details[1].condition_values = Some(vec![
+                NamedApiResource {
+                    name: Some(String::from("time-morning")),
+                    url: None,
+                },
+                NamedApiResource {
+                    name: Some(String::from("swarm-no")),
+                    url: None,
+                },
+            ]);
+        }
+
+        assert_eq!(
+            encounter_version_detail.get_renderable_encounter_details(),
+            vec![
+                Row::new(vec!["\u{A0}Walk", "1 - 10", "50%", ""]),
+                Row::new(vec![
+                    "\u{A0}Headbutt",
+                    "20 - 40",
+                    "50%",
+                    "Time Morning, Swarm No"
+                ]),
+                Row::new(vec!["\u{A0}Walk", "11 - 35", "50%", ""]),
+            ]
+        )
+    }
+
     #[test]
     fn pokemon_encounter_get_renderable_rows() {
         let encounter = get_stubbed_pokemon_encounter();
         assert_eq!(
-            encounter.get_renderable_as_rows("x-y"),
+            encounter.get_renderable_as_rows(&get_stub_version_group("x-y", &["x", "y"])),
             vec![
                 Row::new(vec![
                     Span::raw("\u{A0}Kanto Route 3"),
@@ -0,0 +1,140 @@
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::Row,
+};
+
+use crate::utils::PrepareForDisplay;
+
+use super::{NamedApiResource, PokemonSprites, PokemonType, PokemonTypeExt};
+
+/// A look of a Pokemon that doesn't change its stats, like Unown's letters or Burmy's cloaks.
+/// Some, like Arceus' plates, change its types too.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PokemonForm {
+    pub id: Option<i32>,
+    pub name: Option<String>,
+    pub form_name: Option<String>,
+    pub is_default: Option<bool>,
+    pub is_mega: Option<bool>,
+    pub is_battle_only: Option<bool>,
+    pub pokemon: Option<NamedApiResource>,
+    pub sprites: Option<PokemonSprites>,
+    pub types: Option<Vec<PokemonType>>,
+}
+
+impl PokemonForm {
+    pub fn get_renderable_types(&self) -> Vec<Span<'_>> {
+        self.types
+            .as_ref()
+            .map(|types| {
+                types
+                    .par_iter()
+                    .map(|pokemon_type| pokemon_type.get_renderable())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// A form with the types it has, so its matchups can be worked out.
+pub struct ExtendedPokemonForm {
+    pub form: PokemonForm,
+    pub types: Vec<PokemonTypeExt>,
+}
+
+/// What a row of the forms table switches the Pokemon page to.
+#[derive(Clone, Debug)]
+pub enum FormChoice {
+    /// Another Pokemon of the species, with stats, abilities and moves of its own.
+    Variety(NamedApiResource),
+    /// Another look of the Pokemon on the page.
+    Form(NamedApiResource),
+}
+
+impl FormChoice {
+    pub fn get_resource(&self) -> &NamedApiResource {
+        match self {
+            FormChoice::Variety(resource) | FormChoice::Form(resource) => resource,
+        }
+    }
+
+    pub fn get_renderable_as_row(&self, is_current: bool) -> Row<'static> {
+        let mut name_style = Style::default().fg(Color::Blue);
+        if is_current {
+            name_style = Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD);
+        }
+        let kind = match self {
+            FormChoice::Variety(_) => "Variety",
+            FormChoice::Form(_) => "Form",
+        };
+
+        Row::new(vec![
+            Span::styled(
+                self.get_resource()
+                    .get_name_or_stub()
+                    .split_capitalize()
+                    .append_padding(),
+                name_style,
+            ),
+            Span::raw(kind),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tui::{
+        style::{Color, Modifier, Style},
+        text::Span,
+        widgets::Row,
+    };
+
+    use crate::models::NamedApiResource;
+
+    use super::{FormChoice, PokemonForm};
+
+    #[test]
+    fn pokemon_form_get_renderable_types() {
+        let form: PokemonForm = serde_json::from_value(json!({
+            "name": "arceus-fire",
+            "form_name": "fire",
+            "types": [{ "slot": 1, "type": { "name": "fire", "url": null } }]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            form.get_renderable_types(),
+            vec![Span::styled(
+                "Fire ",
+                Style::default().fg(Color::Rgb(255, 68, 34))
+            )]
+        );
+    }
+
+    #[test]
+    fn form_choice_get_renderable_as_row() {
+        let variety = FormChoice::Variety(NamedApiResource {
+            name: Some(String::from("raichu-alola")),
+            url: None,
+        });
+
+        assert_eq!(
+            variety.get_renderable_as_row(true),
+            Row::new(vec![
+                Span::styled(
+                    "\u{A0}Raichu Alola",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                ),
+                Span::raw("Variety"),
+            ])
+        );
+    }
+}
@@ -8,7 +8,7 @@ use tui::{
 
 use crate::utils::PrepareForDisplay;
 
-use super::NamedApiResource;
+use super::{NamedApiResource, VersionGroup};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PokemonHeldItem {
@@ -30,30 +30,20 @@ impl PokemonHeldItem {
             .unwrap_or(Span::raw(""))
     }
 
-    pub fn get_renderable_as_rows(&self, selected_version_group: &str) -> Vec<Row> {
+    /// The versions of the version group the item can be held in, one table row each.
+    pub fn get_version_details(
+        &self,
+        selected_version_group: &VersionGroup,
+    ) -> Vec<&PokemonHeldItemVersion> {
         self.version_details
             .as_ref()
             .and_then(|version_details| {
                 Some(
                     version_details
                         .par_iter()
-                        .filter_map(|item_version| {
-                            item_version.version.as_ref().and_then(|version| {
-                                if selected_version_group
-                                    .to_string()
-                                    .split("-")
-                                    .map(|item| item.to_string())
-                                    .collect::<Vec<String>>()
-                                    .contains(&version.get_name_or_stub())
-                                {
-                                    return Some(Row::new(vec![
-                                        self.get_renderable_item_name(),
-                                        item_version.get_renderable_rarity(),
-                                        item_version.get_renderable_version(),
-                                    ]));
-                                }
-
-                                None
+                        .filter(|item_version| {
+                            item_version.version.as_ref().is_some_and(|version| {
+                                selected_version_group.has_version(&version.get_name_or_stub())
                             })
                         })
                         .collect(),
@@ -61,6 +51,19 @@ impl PokemonHeldItem {
             })
             .unwrap_or(vec![])
     }
+
+    pub fn get_renderable_as_rows(&self, selected_version_group: &VersionGroup) -> Vec<Row<'_>> {
+        self.get_version_details(selected_version_group)
+            .into_iter()
+            .map(|item_version| {
+                Row::new(vec![
+                    self.get_renderable_item_name(),
+                    item_version.get_renderable_rarity(),
+                    item_version.get_renderable_version(),
+                ])
+            })
+            .collect()
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -94,7 +97,7 @@ mod tests {
         widgets::Row,
     };
 
-    use crate::models::NamedApiResource;
+    use crate::models::{version_group::fixture::get_stub_version_group, NamedApiResource};
 
     use super::{PokemonHeldItem, PokemonHeldItemVersion};
 
@@ -123,7 +126,7 @@ mod tests {
         let pokemon_held_item = get_stub_pokemon_held_item();
 
         assert_eq!(
-            pokemon_held_item.get_renderable_as_rows("x-y"),
+            pokemon_held_item.get_renderable_as_rows(&get_stub_version_group("x-y", &["x", "y"])),
             vec![Row::new(vec![
                 Span::styled("\u{A0}Sharp fang", Style::default().fg(Color::Blue)),
                 Span::raw("20%"),
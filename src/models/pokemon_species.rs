@@ -2,13 +2,13 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use tui::{
     style::{Color, Style},
-    text::Span,
+    text::{Span, Spans},
     widgets::Row,
 };
 
 use crate::utils::PrepareForDisplay;
 
-use super::{APIResource, FlavorTextEntry, NamedApiResource};
+use super::{APIResource, FlavorTextEntry, NamedApiResource, VersionGroup};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PokemonSpecies {
@@ -20,6 +20,8 @@ pub struct PokemonSpecies {
     pub evolution_chain: Option<APIResource>,
     pub flavor_text_entries: Option<Vec<FlavorTextEntry>>,
     pub pokedex_numbers: Option<Vec<PokedexNumber>>,
+    /// Pokemon of the species with stats of their own, like regional and Mega forms.
+    pub varieties: Option<Vec<PokemonSpeciesVariety>>,
 }
 
 impl PokemonSpecies {
@@ -49,6 +51,62 @@ impl PokemonSpecies {
             .unwrap_or(Span::raw(""))
     }
 
+    /// English entries of the versions in `selected_version_group`, versions sharing a text
+    /// get a single line. Groups without entries of their own, like spin-offs, fall back to
+    /// the latest entry.
+    pub fn get_renderable_flavor_texts(
+        &self,
+        selected_version_group: &VersionGroup,
+    ) -> Vec<Spans<'static>> {
+        let english_entries: Vec<&FlavorTextEntry> = self
+            .flavor_text_entries
+            .iter()
+            .flatten()
+            .filter(|flavor_text_entry| flavor_text_entry.get_language() == "en")
+            .collect();
+
+        let mut flavor_texts: Vec<(Vec<String>, String)> = vec![];
+        for flavor_text_entry in english_entries.iter().filter(|flavor_text_entry| {
+            selected_version_group.has_version(&flavor_text_entry.get_version())
+        }) {
+            let version = flavor_text_entry.get_version().split_capitalize();
+            let flavor_text = flavor_text_entry.get_normalized_flavor_text();
+
+            match flavor_texts
+                .iter_mut()
+                .find(|(_, text)| *text == flavor_text)
+            {
+                Some((versions, _)) => versions.push(version),
+                None => flavor_texts.push((vec![version], flavor_text)),
+            }
+        }
+
+        if flavor_texts.is_empty() {
+            if let Some(latest_entry) = english_entries.last() {
+                flavor_texts.push((
+                    vec![format!(
+                        "{} (latest)",
+                        latest_entry.get_version().split_capitalize()
+                    )],
+                    latest_entry.get_normalized_flavor_text(),
+                ));
+            }
+        }
+
+        flavor_texts
+            .into_iter()
+            .map(|(versions, flavor_text)| {
+                Spans::from(vec![
+                    Span::styled(
+                        format!("{}: ", versions.join(", ")).append_padding(),
+                        Style::default().fg(Color::Blue),
+                    ),
+                    Span::raw(flavor_text),
+                ])
+            })
+            .collect()
+    }
+
     pub fn get_renderable_pokedex_numbers(&self) -> Vec<Row> {
         self.pokedex_numbers
             .as_ref()
@@ -74,6 +132,12 @@ impl PokemonSpecies {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PokemonSpeciesVariety {
+    pub is_default: Option<bool>,
+    pub pokemon: Option<NamedApiResource>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PokedexNumber {
     entry_number: Option<i32>,
@@ -100,11 +164,13 @@ impl PokedexNumber {
 mod tests {
     use tui::{
         style::{Color, Style},
-        text::Span,
+        text::{Span, Spans},
         widgets::Row,
     };
 
-    use crate::models::NamedApiResource;
+    use crate::models::{
+        version_group::fixture::get_stub_version_group, FlavorTextEntry, NamedApiResource,
+    };
 
     use super::{PokedexNumber, PokemonSpecies};
 
@@ -129,11 +195,44 @@ mod tests {
             base_happiness: Some(50),
             is_legendary: Some(false),
             evolution_chain: None,
-            flavor_text_entries: None,
+            flavor_text_entries: Some(vec![
+                get_stub_flavor_text_entry(
+                    "x",
+                    "en",
+                    "Its tail is\nshaped like a\u{C}lightning bolt.",
+                ),
+                get_stub_flavor_text_entry("x", "fr", "Sa queue a la forme d'un éclair."),
+                get_stub_flavor_text_entry(
+                    "y",
+                    "en",
+                    "Its tail is\nshaped like a\u{C}lightning bolt.",
+                ),
+                get_stub_flavor_text_entry("sun", "en", "It stores electricity in its cheeks."),
+            ]),
             pokedex_numbers: Some(vec![get_stub_pokedex_number()]),
+            varieties: None,
         };
     }
 
+    fn get_stub_flavor_text_entry(
+        version: &str,
+        language: &str,
+        flavor_text: &str,
+    ) -> FlavorTextEntry {
+        FlavorTextEntry {
+            flavor_text: Some(flavor_text.to_string()),
+            version_group: None,
+            version: Some(NamedApiResource {
+                name: Some(version.to_string()),
+                url: None,
+            }),
+            language: Some(NamedApiResource {
+                name: Some(language.to_string()),
+                url: None,
+            }),
+        }
+    }
+
     #[test]
     fn pokemon_species_get_renderable_is_legendary() {
         let species = get_stub_species();
@@ -158,6 +257,27 @@ mod tests {
         assert_eq!(species.get_renderable_color(), Span::raw("Brown"));
     }
 
+    #[test]
+    fn pokemon_species_get_renderable_flavor_texts() {
+        let species = get_stub_species();
+
+        assert_eq!(
+            species.get_renderable_flavor_texts(&get_stub_version_group("x-y", &["x", "y"])),
+            vec![Spans::from(vec![
+                Span::styled("\u{A0}X, Y: ", Style::default().fg(Color::Blue)),
+                Span::raw("Its tail is shaped like a lightning bolt."),
+            ])]
+        );
+        assert_eq!(
+            species
+                .get_renderable_flavor_texts(&get_stub_version_group("colosseum", &["colosseum"])),
+            vec![Spans::from(vec![
+                Span::styled("\u{A0}Sun (latest): ", Style::default().fg(Color::Blue)),
+                Span::raw("It stores electricity in its cheeks."),
+            ])]
+        );
+    }
+
     #[test]
     fn pokemon_species_get_renderable_pokedex_numbers() {
         let species = get_stub_species();
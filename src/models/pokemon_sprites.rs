@@ -11,3 +11,116 @@ pub struct PokemonSprites {
     pub back_female: Option<String>,
     pub back_shiny_female: Option<String>,
 }
+
+impl PokemonSprites {
+    pub fn get_url(&self, variant: SpriteVariant) -> Option<&String> {
+        match variant {
+            SpriteVariant::FrontDefault => self.front_default.as_ref(),
+            SpriteVariant::BackDefault => self.back_default.as_ref(),
+            SpriteVariant::FrontShiny => self.front_shiny.as_ref(),
+            SpriteVariant::BackShiny => self.back_shiny.as_ref(),
+            SpriteVariant::FrontFemale => self.front_female.as_ref(),
+            SpriteVariant::BackFemale => self.back_female.as_ref(),
+            SpriteVariant::FrontShinyFemale => self.front_shiny_female.as_ref(),
+            SpriteVariant::BackShinyFemale => self.back_shiny_female.as_ref(),
+        }
+    }
+
+    /// The variant following `current` that actually has a sprite, wrapping around.
+    pub fn get_next_variant(&self, current: SpriteVariant) -> SpriteVariant {
+        let current_index = SpriteVariant::ALL
+            .iter()
+            .position(|variant| *variant == current)
+            .unwrap_or_default();
+
+        (1..=SpriteVariant::ALL.len())
+            .map(|step| SpriteVariant::ALL[(current_index + step) % SpriteVariant::ALL.len()])
+            .find(|variant| self.get_url(*variant).is_some())
+            .unwrap_or(current)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpriteVariant {
+    FrontDefault,
+    BackDefault,
+    FrontShiny,
+    BackShiny,
+    FrontFemale,
+    BackFemale,
+    FrontShinyFemale,
+    BackShinyFemale,
+}
+
+impl SpriteVariant {
+    pub const ALL: [SpriteVariant; 8] = [
+        SpriteVariant::FrontDefault,
+        SpriteVariant::BackDefault,
+        SpriteVariant::FrontShiny,
+        SpriteVariant::BackShiny,
+        SpriteVariant::FrontFemale,
+        SpriteVariant::BackFemale,
+        SpriteVariant::FrontShinyFemale,
+        SpriteVariant::BackShinyFemale,
+    ];
+
+    pub fn get_renderable_name(&self) -> &'static str {
+        match self {
+            SpriteVariant::FrontDefault => "Front",
+            SpriteVariant::BackDefault => "Back",
+            SpriteVariant::FrontShiny => "Front Shiny",
+            SpriteVariant::BackShiny => "Back Shiny",
+            SpriteVariant::FrontFemale => "Front Female",
+            SpriteVariant::BackFemale => "Back Female",
+            SpriteVariant::FrontShinyFemale => "Front Shiny Female",
+            SpriteVariant::BackShinyFemale => "Back Shiny Female",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PokemonSprites, SpriteVariant};
+
+    fn get_stub_pokemon_sprites() -> PokemonSprites {
+        PokemonSprites {
+            front_default: Some(String::from("front.png")),
+            front_shiny: Some(String::from("front_shiny.png")),
+            front_female: None,
+            front_shiny_female: None,
+            back_default: Some(String::from("back.png")),
+            back_shiny: None,
+            back_female: None,
+            back_shiny_female: None,
+        }
+    }
+
+    #[test]
+    fn pokemon_sprites_get_url() {
+        let pokemon_sprites = get_stub_pokemon_sprites();
+
+        assert_eq!(
+            pokemon_sprites.get_url(SpriteVariant::FrontShiny),
+            Some(&String::from("front_shiny.png"))
+        );
+        assert_eq!(pokemon_sprites.get_url(SpriteVariant::BackShiny), None);
+    }
+
+    #[test]
+    fn pokemon_sprites_get_next_variant() {
+        let pokemon_sprites = get_stub_pokemon_sprites();
+
+        assert_eq!(
+            pokemon_sprites.get_next_variant(SpriteVariant::FrontDefault),
+            SpriteVariant::BackDefault
+        );
+        assert_eq!(
+            pokemon_sprites.get_next_variant(SpriteVariant::BackDefault),
+            SpriteVariant::FrontShiny
+        );
+        assert_eq!(
+            pokemon_sprites.get_next_variant(SpriteVariant::FrontShiny),
+            SpriteVariant::FrontDefault
+        );
+    }
+}
@@ -1,13 +1,17 @@
 use serde::{Deserialize, Serialize};
 use tui::{
     style::{Color, Style},
-    text::Span,
+    text::{Span, Spans},
     widgets::Row,
 };
 
 use crate::utils::PrepareForDisplay;
 
-use super::NamedApiResource;
+use super::{stat_spread::get_stat_abbreviation, NamedApiResource};
+
+/// The highest base stat any Pokemon has, a full bar.
+pub const MAX_BASE_STAT: i32 = 255;
+const PARTIAL_BLOCKS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PokemonStat {
@@ -17,37 +21,72 @@ pub struct PokemonStat {
 }
 
 impl PokemonStat {
-    pub fn get_renderable_as_row(&self) -> Row {
-        let stat_name = self
-            .stat
-            .as_ref()
-            .and_then(|stat| stat.name.as_ref())
-            .and_then(|stat_name| Some(stat_name.to_string().split_capitalize().append_padding()))
-            .unwrap_or(String::new());
+    pub fn get_name(&self) -> Option<&str> {
+        self.stat.as_ref()?.name.as_deref()
+    }
 
+    pub fn get_renderable_as_row(&self, bar_width: u16) -> Row<'static> {
+        let stat_name = self
+            .get_name()
+            .map(|stat_name| {
+                get_stat_abbreviation(stat_name)
+                    .to_string()
+                    .append_padding()
+            })
+            .unwrap_or_default();
         let base_value = self
             .base_stat
-            .and_then(|base_stat| Some(base_stat.to_string()))
-            .unwrap_or(String::new());
+            .map(|base_stat| base_stat.to_string())
+            .unwrap_or_default();
+        let effort = match self.effort {
+            Some(effort) if effort > 0 => format!("+{}", effort),
+            _ => String::new(),
+        };
 
         Row::new(vec![
-            Span::styled(stat_name, Style::default().fg(Color::Blue)),
-            Span::raw(base_value),
+            Spans::from(Span::styled(stat_name, Style::default().fg(Color::Blue))),
+            Spans::from(base_value),
+            Spans::from(get_renderable_stat_bar(
+                self.base_stat.unwrap_or_default(),
+                bar_width,
+            )),
+            Spans::from(Span::styled(effort, Style::default().fg(Color::Green))),
         ])
     }
 }
 
+/// Colored like the stat is rated, from red for the lowest to cyan for the highest.
+pub fn get_stat_color(stat: i32) -> Color {
+    match stat {
+        i32::MIN..=49 => Color::Red,
+        50..=79 => Color::LightRed,
+        80..=99 => Color::Yellow,
+        100..=119 => Color::LightGreen,
+        120..=149 => Color::Green,
+        _ => Color::Cyan,
+    }
+}
+
+/// A bar `width` cells long at `MAX_BASE_STAT`, drawn in eighths of a cell.
+pub fn get_renderable_stat_bar(stat: i32, width: u16) -> Span<'static> {
+    let eighths =
+        stat.clamp(0, MAX_BASE_STAT) as usize * width as usize * 8 / MAX_BASE_STAT as usize;
+    let bar = format!("{}{}", "█".repeat(eighths / 8), PARTIAL_BLOCKS[eighths % 8]);
+
+    Span::styled(bar, Style::default().fg(get_stat_color(stat)))
+}
+
 #[cfg(test)]
 mod tests {
     use tui::{
         style::{Color, Style},
-        text::Span,
+        text::{Span, Spans},
         widgets::Row,
     };
 
     use crate::models::NamedApiResource;
 
-    use super::PokemonStat;
+    use super::{get_renderable_stat_bar, PokemonStat};
 
     fn get_stub_pokemon_stat() -> PokemonStat {
         PokemonStat {
@@ -55,7 +94,7 @@ mod tests {
                 name: Some(String::from("speed")),
                 url: None,
             }),
-            effort: Some(0),
+            effort: Some(2),
             base_stat: Some(15),
         }
     }
@@ -64,11 +103,29 @@ mod tests {
     fn pokemon_stat_get_renderable_as_row() {
         let pokemon_stat = get_stub_pokemon_stat();
         assert_eq!(
-            pokemon_stat.get_renderable_as_row(),
+            pokemon_stat.get_renderable_as_row(17),
             Row::new(vec![
-                Span::styled("\u{A0}Speed", Style::default().fg(Color::Blue)),
-                Span::raw("15"),
+                Spans::from(Span::styled("\u{A0}Spe", Style::default().fg(Color::Blue))),
+                Spans::from("15"),
+                Spans::from(Span::styled("█", Style::default().fg(Color::Red))),
+                Spans::from(Span::styled("+2", Style::default().fg(Color::Green))),
             ])
         )
     }
+
+    #[test]
+    fn get_renderable_stat_bar_draws_eighths() {
+        assert_eq!(
+            get_renderable_stat_bar(100, 10),
+            Span::styled("███▉", Style::default().fg(Color::LightGreen))
+        );
+        assert_eq!(
+            get_renderable_stat_bar(255, 4),
+            Span::styled("████", Style::default().fg(Color::Cyan))
+        );
+        assert_eq!(
+            get_renderable_stat_bar(0, 4),
+            Span::styled("", Style::default().fg(Color::Red))
+        );
+    }
 }
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
-use tui::text::Span;
+use tui::{
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::Row,
+};
 
-use crate::utils::get_styled_pokemon_type;
+use crate::utils::{get_styled_pokemon_type, parse_generation, PrepareForDisplay};
 
 use super::NamedApiResource;
 
@@ -22,8 +26,224 @@ impl PokemonType {
     }
 }
 
+/// Every type a move can have, in the order the games list them.
+pub const ATTACKING_TYPES: [&str; 18] = [
+    "normal", "fire", "water", "electric", "grass", "ice", "fighting", "poison", "ground",
+    "flying", "psychic", "bug", "rock", "ghost", "dragon", "dark", "steel", "fairy",
+];
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PokemonTypeExt {
+    pub id: Option<i32>,
+    pub name: Option<String>,
+    pub damage_relations: Option<TypeRelations>,
+    pub past_damage_relations: Option<Vec<PastTypeRelations>>,
+    pub pokemon: Option<Vec<TypePokemon>>,
+    pub moves: Option<Vec<NamedApiResource>>,
+}
+
+impl PokemonTypeExt {
+    /// How much damage a move of `attacking_type` deals to a Pokemon of this type.
+    pub fn get_damage_multiplier_from(&self, attacking_type: &str) -> f32 {
+        get_damage_multiplier(self.damage_relations.as_ref(), attacking_type)
+    }
+
+    /// Like `get_damage_multiplier_from`, with the chart of `generation`, before Steel
+    /// stopped resisting Ghost and Dark for example.
+    pub fn get_damage_multiplier_in(&self, attacking_type: &str, generation: i32) -> f32 {
+        get_damage_multiplier(self.get_damage_relations_in(generation), attacking_type)
+    }
+
+    /// Each past entry holds until the end of its generation, so the earliest one that isn't
+    /// over yet is the one in effect.
+    fn get_damage_relations_in(&self, generation: i32) -> Option<&TypeRelations> {
+        self.past_damage_relations
+            .iter()
+            .flatten()
+            .filter_map(|past_relations| {
+                let past_generation =
+                    parse_generation(past_relations.generation.as_ref()?.name.as_deref()?)?;
+
+                Some((past_generation, past_relations.damage_relations.as_ref()?))
+            })
+            .filter(|(past_generation, _)| *past_generation >= generation)
+            .min_by_key(|(past_generation, _)| *past_generation)
+            .map(|(_, damage_relations)| damage_relations)
+            .or(self.damage_relations.as_ref())
+    }
+
+    pub fn get_renderable_damage_relations(&self) -> Vec<Spans<'static>> {
+        self.damage_relations
+            .as_ref()
+            .map(|damage_relations| damage_relations.get_renderable())
+            .unwrap_or_default()
+    }
+
+    pub fn get_pokemon(&self, index: usize) -> Option<&NamedApiResource> {
+        self.pokemon
+            .as_ref()?
+            .get(index)
+            .and_then(|type_pokemon| type_pokemon.pokemon.as_ref())
+    }
+
+    pub fn get_renderable_pokemon(&self) -> Vec<Row<'static>> {
+        self.pokemon
+            .iter()
+            .flatten()
+            .map(|type_pokemon| type_pokemon.get_renderable_as_row())
+            .collect()
+    }
+
+    pub fn get_move(&self, index: usize) -> Option<&NamedApiResource> {
+        self.moves.as_ref()?.get(index)
+    }
+
+    pub fn get_renderable_moves(&self) -> Vec<Row<'static>> {
+        self.moves
+            .iter()
+            .flatten()
+            .map(|de_move| {
+                Row::new(vec![de_move
+                    .get_name_or_stub()
+                    .split_capitalize()
+                    .append_padding()])
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TypePokemon {
+    pub slot: Option<i32>,
+    pub pokemon: Option<NamedApiResource>,
+}
+
+impl TypePokemon {
+    pub fn get_renderable_as_row(&self) -> Row<'static> {
+        let name = self
+            .pokemon
+            .as_ref()
+            .map(|pokemon| pokemon.get_name_or_stub())
+            .unwrap_or_default();
+        let slot = match self.slot {
+            Some(1) => "Primary",
+            Some(_) => "Secondary",
+            None => "",
+        };
+
+        Row::new(vec![
+            name.split_capitalize().append_padding(),
+            slot.to_string(),
+        ])
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TypeRelations {
+    pub no_damage_to: Option<Vec<NamedApiResource>>,
+    pub half_damage_to: Option<Vec<NamedApiResource>>,
+    pub double_damage_to: Option<Vec<NamedApiResource>>,
+    pub no_damage_from: Option<Vec<NamedApiResource>>,
+    pub half_damage_from: Option<Vec<NamedApiResource>>,
+    pub double_damage_from: Option<Vec<NamedApiResource>>,
+}
+
+impl TypeRelations {
+    /// One line per relation the type has, offensive ones first.
+    pub fn get_renderable(&self) -> Vec<Spans<'static>> {
+        [
+            ("Super effective against", &self.double_damage_to),
+            ("Not very effective against", &self.half_damage_to),
+            ("No effect on", &self.no_damage_to),
+            ("Weak to", &self.double_damage_from),
+            ("Resists", &self.half_damage_from),
+            ("Immune to", &self.no_damage_from),
+        ]
+        .into_iter()
+        .filter_map(|(label, types)| {
+            let types = types.as_ref().filter(|types| !types.is_empty())?;
+            let mut spans = vec![Span::styled(
+                format!("{}: ", label),
+                Style::default().fg(Color::Blue),
+            )];
+            spans.extend(
+                types
+                    .iter()
+                    .map(|de_type| get_styled_pokemon_type(de_type.get_name_or_stub())),
+            );
+
+            Some(Spans::from(spans))
+        })
+        .collect()
+    }
+}
+
+/// The relations a type had up to and including `generation`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PastTypeRelations {
+    pub generation: Option<NamedApiResource>,
+    pub damage_relations: Option<TypeRelations>,
+}
+
+fn get_damage_multiplier(damage_relations: Option<&TypeRelations>, attacking_type: &str) -> f32 {
+    let damage_relations = match damage_relations {
+        Some(damage_relations) => damage_relations,
+        None => return 1.0,
+    };
+    let contains = |types: &Option<Vec<NamedApiResource>>| {
+        types
+            .iter()
+            .flatten()
+            .any(|de_type| de_type.name.as_deref() == Some(attacking_type))
+    };
+
+    if contains(&damage_relations.no_damage_from) {
+        0.0
+    } else if contains(&damage_relations.half_damage_from) {
+        0.5
+    } else if contains(&damage_relations.double_damage_from) {
+        2.0
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use serde_json::json;
+
+    use super::PokemonTypeExt;
+
+    /// A type that only has the given defensive relations.
+    pub fn get_stub_type_ext(
+        name: &str,
+        double_damage_from: &[&str],
+        half_damage_from: &[&str],
+        no_damage_from: &[&str],
+    ) -> PokemonTypeExt {
+        let to_resources = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| json!({ "name": name, "url": null }))
+                .collect::<Vec<_>>()
+        };
+
+        serde_json::from_value(json!({
+            "id": null,
+            "name": name,
+            "damage_relations": {
+                "double_damage_from": to_resources(double_damage_from),
+                "half_damage_from": to_resources(half_damage_from),
+                "no_damage_from": to_resources(no_damage_from)
+            }
+        }))
+        .unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
     use tui::{
         style::{Color, Style},
         text::Span,
@@ -31,7 +251,7 @@ mod tests {
 
     use crate::models::NamedApiResource;
 
-    use super::PokemonType;
+    use super::{fixture::get_stub_type_ext, PokemonType, PokemonTypeExt};
 
     fn get_stub_type(type_name: &str) -> PokemonType {
         PokemonType {
@@ -57,4 +277,43 @@ mod tests {
         let pokemon_type = get_stub_type("");
         assert_eq!(pokemon_type.get_renderable(), Span::raw(""))
     }
+
+    #[test]
+    fn pokemon_type_ext_get_damage_multiplier_from() {
+        let pokemon_type = get_stub_type_ext("flying", &["rock"], &["grass"], &["ground"]);
+
+        assert_eq!(pokemon_type.get_damage_multiplier_from("rock"), 2.0);
+        assert_eq!(pokemon_type.get_damage_multiplier_from("grass"), 0.5);
+        assert_eq!(pokemon_type.get_damage_multiplier_from("ground"), 0.0);
+        assert_eq!(pokemon_type.get_damage_multiplier_from("water"), 1.0);
+    }
+
+    #[test]
+    fn pokemon_type_ext_get_damage_multiplier_in() {
+        let pokemon_type: PokemonTypeExt = serde_json::from_value(json!({
+            "name": "steel",
+            "damage_relations": {
+                "half_damage_from": [{ "name": "steel", "url": null }]
+            },
+            "past_damage_relations": [
+                {
+                    "generation": { "name": "generation-v", "url": null },
+                    "damage_relations": {
+                        "half_damage_from": [
+                            { "name": "steel", "url": null },
+                            { "name": "ghost", "url": null },
+                            { "name": "dark", "url": null }
+                        ]
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(pokemon_type.get_damage_multiplier_in("ghost", 9), 1.0);
+        assert_eq!(pokemon_type.get_damage_multiplier_in("ghost", 6), 1.0);
+        assert_eq!(pokemon_type.get_damage_multiplier_in("ghost", 5), 0.5);
+        assert_eq!(pokemon_type.get_damage_multiplier_in("dark", 2), 0.5);
+        assert_eq!(pokemon_type.get_damage_multiplier_from("ghost"), 1.0);
+    }
 }
@@ -0,0 +1,504 @@
+use crate::utils::PrepareForDisplay;
+
+use super::{
+    stat_spread::{get_stat_abbreviation, MAX_EV, MAX_IV, MAX_LEVEL, MAX_TOTAL_EVS, STAT_NAMES},
+    NamedApiResource, Nature, Pokemon, PokemonMoveExt, StatSpread, Team, TeamMember, TeamMove,
+};
+
+/// Showdown leaves the level out for level 100 Pokemon.
+const SHOWDOWN_DEFAULT_LEVEL: i32 = 100;
+
+/// One Pokemon of a team in the Pokemon Showdown text format, with every name already
+/// turned into the one PokéAPI uses.
+#[derive(Debug, PartialEq)]
+pub struct ShowdownSet {
+    pub species: String,
+    pub item: Option<String>,
+    pub ability: Option<String>,
+    pub moves: Vec<String>,
+    pub stat_spread: StatSpread,
+}
+
+/// A team read from the Showdown format, with what had to be left out of it and why.
+pub struct ShowdownImport {
+    pub team: Team,
+    pub problems: Vec<String>,
+}
+
+/// Showdown writes names the way the games show them, like "Mr. Mime" or "King's Rock",
+/// PokéAPI has them lowercase and hyphenated, like "mr-mime" or "kings-rock".
+pub fn to_api_name(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .split(|character: char| character.is_whitespace() || character == '-')
+        .map(|word| {
+            word.chars()
+                .filter(|character| character.is_alphanumeric())
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+/// Sets are separated by blank lines. Lines Showdown knows but Pokerust has no use for,
+/// like "Shiny: Yes" or "Tera Type: Fire", are skipped, and so are the "=== [gen9ou] Name ==="
+/// lines an export of several teams starts each of them with.
+pub fn parse_team(text: &str) -> Vec<ShowdownSet> {
+    let mut sets = vec![];
+    let mut lines: Vec<&str> = vec![];
+
+    for line in text.lines().map(str::trim).chain([""]) {
+        let is_team_header = line.starts_with("===") && line.ends_with("===");
+        if !line.is_empty() && !is_team_header {
+            lines.push(line);
+            continue;
+        }
+
+        if let Some(set) = parse_set(&lines) {
+            sets.push(set);
+        }
+        lines.clear();
+    }
+
+    sets
+}
+
+fn parse_set(lines: &[&str]) -> Option<ShowdownSet> {
+    let (first_line, rest) = lines.split_first()?;
+    let (species, item) = parse_first_line(first_line)?;
+    let mut stat_spread = StatSpread {
+        level: SHOWDOWN_DEFAULT_LEVEL,
+        ..StatSpread::default()
+    };
+    let mut ability = None;
+    let mut moves = vec![];
+
+    for line in rest {
+        if let Some(move_name) = line.strip_prefix('-') {
+            // Hidden Power is a single move whatever its type
+            let move_name = move_name.split(" [").next().unwrap_or_default();
+            moves.push(to_api_name(move_name));
+        } else if let Some(value) = line.strip_prefix("Ability:") {
+            ability = Some(to_api_name(value));
+        } else if let Some(value) = line.strip_prefix("Level:") {
+            if let Ok(level) = value.trim().parse::<i32>() {
+                stat_spread.level = level.clamp(1, MAX_LEVEL);
+            }
+        } else if let Some(value) = line.strip_prefix("EVs:") {
+            parse_stat_values(value, &mut stat_spread.evs, MAX_EV);
+        } else if let Some(value) = line.strip_prefix("IVs:") {
+            parse_stat_values(value, &mut stat_spread.ivs, MAX_IV);
+        } else if let Some(nature) = line
+            .strip_suffix(" Nature")
+            .and_then(|nature| Nature::from_name(nature.trim()))
+        {
+            stat_spread.nature = nature;
+        }
+    }
+
+    Some(ShowdownSet {
+        species,
+        item,
+        ability,
+        moves,
+        stat_spread,
+    })
+}
+
+/// "Nickname (Species) (M) @ Item", where everything but the species is optional.
+fn parse_first_line(line: &str) -> Option<(String, Option<String>)> {
+    let (name, item) = match line.split_once(" @ ") {
+        Some((name, item)) => (name.trim(), Some(to_api_name(item))),
+        None => (line.trim(), None),
+    };
+    let name = name
+        .strip_suffix("(M)")
+        .or_else(|| name.strip_suffix("(F)"))
+        .unwrap_or(name)
+        .trim();
+    let species = match name
+        .strip_suffix(')')
+        .and_then(|name| name.rsplit_once(" ("))
+    {
+        Some((_, species)) => species,
+        None => name,
+    };
+
+    let species = to_api_name(species);
+    (!species.is_empty()).then_some((species, item))
+}
+
+/// "252 Atk / 4 SpD / 252 Spe", the stats that aren't listed keep their value.
+fn parse_stat_values(values: &str, stat_values: &mut [i32; 6], max_value: i32) {
+    for value in values.split('/') {
+        let mut parts = value.split_whitespace();
+        let (Some(number), Some(abbreviation)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let stat_index = STAT_NAMES.iter().position(|stat_name| {
+            get_stat_abbreviation(stat_name).eq_ignore_ascii_case(abbreviation)
+        });
+
+        if let (Some(stat_index), Ok(number)) = (stat_index, number.parse::<i32>()) {
+            stat_values[stat_index] = number.clamp(0, max_value);
+        }
+    }
+}
+
+impl ShowdownSet {
+    /// Keeps what `pokemon` can actually have, with a problem for everything else. Moves
+    /// the Pokemon learns, but not in `version_group`, are left out as well, and so are the
+    /// ones missing from the fetched `moves`.
+    pub fn into_member(
+        self,
+        pokemon: &Pokemon,
+        resource: NamedApiResource,
+        moves: &[PokemonMoveExt],
+        version_group: Option<&str>,
+    ) -> (TeamMember, Vec<String>) {
+        let pokemon_name = resource.get_name_or_stub().split_capitalize();
+        let mut problems = vec![];
+        let types = pokemon
+            .types
+            .iter()
+            .flatten()
+            .filter_map(|de_type| de_type.de_type.as_ref()?.name.clone())
+            .collect();
+        let abilities: Vec<&String> = pokemon
+            .abilities
+            .iter()
+            .flatten()
+            .filter_map(|ability| ability.ability.as_ref()?.name.as_ref())
+            .collect();
+        let ability = match self.ability {
+            Some(ability) if !abilities.contains(&&ability) => {
+                problems.push(format!(
+                    "{} can't have {}",
+                    pokemon_name,
+                    ability.split_capitalize()
+                ));
+                None
+            }
+            ability => ability,
+        };
+        let learnset: Vec<String> = pokemon
+            .moves
+            .iter()
+            .flatten()
+            .filter_map(|pokemon_move| pokemon_move.de_move.as_ref()?.name.clone())
+            .collect();
+        let version_group_learnset = version_group
+            .map(|version_group| (version_group, pokemon.get_move_names(version_group)));
+
+        let mut member = TeamMember::new(resource, types, ability);
+        member.item = self.item;
+        member.stat_spread = self.stat_spread;
+
+        // Each EV was capped while parsing, the total can only be checked once they're all in
+        if member.stat_spread.get_total_evs() > MAX_TOTAL_EVS {
+            problems.push(format!(
+                "{}'s EVs add up to more than {}, the last ones were lowered",
+                pokemon_name, MAX_TOTAL_EVS
+            ));
+
+            let evs = member.stat_spread.evs;
+            member.stat_spread.evs = [0; 6];
+            for (stat_index, ev) in evs.into_iter().enumerate() {
+                member.stat_spread.change_ev(stat_index, ev);
+            }
+        }
+
+        for move_name in self.moves {
+            if !learnset.contains(&move_name) {
+                problems.push(format!(
+                    "{} can't learn {}",
+                    pokemon_name,
+                    move_name.split_capitalize()
+                ));
+                continue;
+            }
+
+            if let Some((version_group, version_group_moves)) = version_group_learnset.as_ref() {
+                if !version_group_moves.contains(&move_name) {
+                    problems.push(format!(
+                        "{} can't learn {} in {}",
+                        pokemon_name,
+                        move_name.split_capitalize(),
+                        version_group.to_string().split_capitalize()
+                    ));
+                    continue;
+                }
+            }
+
+            // A move without its details would leave a gap in the team's coverage
+            let Some(team_move) = moves
+                .iter()
+                .find(|pokemon_move| pokemon_move.name.as_ref() == Some(&move_name))
+                .and_then(TeamMove::from_move)
+            else {
+                problems.push(format!(
+                    "There are no details of {}",
+                    move_name.split_capitalize()
+                ));
+                continue;
+            };
+            if !member.toggle_move(team_move) {
+                problems.push(format!("{} can only know four moves", pokemon_name));
+            }
+        }
+
+        (member, problems)
+    }
+}
+
+/// The team in the Showdown format, ready to be pasted into the teambuilder.
+pub fn export_team(team: &Team) -> String {
+    team.members
+        .iter()
+        .map(export_member)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn export_member(member: &TeamMember) -> String {
+    let species = member.get_name().split_capitalize().replace(' ', "-");
+    let mut lines = vec![match member.item.as_ref() {
+        Some(item) => format!("{} @ {}", species, item.clone().split_capitalize()),
+        None => species,
+    }];
+
+    if let Some(ability) = member.ability.as_ref() {
+        lines.push(format!("Ability: {}", ability.clone().split_capitalize()));
+    }
+    if member.stat_spread.level != SHOWDOWN_DEFAULT_LEVEL {
+        lines.push(format!("Level: {}", member.stat_spread.level));
+    }
+
+    let evs = member.stat_spread.get_renderable_evs();
+    if !evs.is_empty() {
+        lines.push(format!("EVs: {}", evs));
+    }
+    lines.push(format!("{} Nature", member.stat_spread.nature.get_name()));
+
+    let ivs = member.stat_spread.get_renderable_ivs();
+    if !ivs.is_empty() {
+        lines.push(format!("IVs: {}", ivs));
+    }
+    lines.extend(
+        member
+            .moves
+            .iter()
+            .map(|team_move| format!("- {}", team_move.name.clone().split_capitalize())),
+    );
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::models::{NamedApiResource, Nature, Pokemon, PokemonMoveExt, Team};
+
+    use super::{export_team, parse_team, to_api_name};
+
+    const STUB_TEAM: &str = "Sparky (Pikachu) (M) @ Light Ball
+Ability: Static
+Level: 50
+Shiny: Yes
+EVs: 4 HP / 252 Atk / 252 Spe
+Jolly Nature
+IVs: 0 SpA
+- Thunder Shock
+- Hidden Power [Ice]
+- Surf
+
+Mr. Mime
+Ability: Filter
+- Psychic
+";
+
+    fn get_stub_pokemon() -> Pokemon {
+        serde_json::from_value(json!({
+            "name": "pikachu",
+            "abilities": [{ "is_hidden": false, "ability": { "name": "static", "url": null } }],
+            "moves": [
+                {
+                    "move": { "name": "thunder-shock", "url": null },
+                    "version_group_details": [{
+                        "level_learned_at": 1,
+                        "move_learn_method": { "name": "level-up", "url": null },
+                        "version_group": { "name": "x-y", "url": null }
+                    }]
+                },
+                {
+                    "move": { "name": "surf", "url": null },
+                    "version_group_details": [{
+                        "level_learned_at": 0,
+                        "move_learn_method": { "name": "tutor", "url": null },
+                        "version_group": { "name": "ultra-sun-ultra-moon", "url": null }
+                    }]
+                }
+            ],
+            "types": [{ "slot": 1, "type": { "name": "electric", "url": null } }]
+        }))
+        .unwrap()
+    }
+
+    fn get_stub_moves() -> Vec<PokemonMoveExt> {
+        serde_json::from_value(json!([
+            {
+                "name": "thunder-shock",
+                "type": { "name": "electric", "url": null },
+                "damage_class": { "name": "special", "url": null }
+            },
+            {
+                "name": "surf",
+                "type": { "name": "water", "url": null },
+                "damage_class": { "name": "special", "url": null }
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn showdown_to_api_name() {
+        assert_eq!(to_api_name("Mr. Mime"), "mr-mime");
+        assert_eq!(to_api_name("King's Rock"), "kings-rock");
+        assert_eq!(to_api_name("U-turn"), "u-turn");
+        assert_eq!(to_api_name(" Landorus-Therian "), "landorus-therian");
+    }
+
+    #[test]
+    fn showdown_parse_team() {
+        let sets = parse_team(STUB_TEAM);
+
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].species, "pikachu");
+        assert_eq!(sets[0].item.as_deref(), Some("light-ball"));
+        assert_eq!(sets[0].ability.as_deref(), Some("static"));
+        assert_eq!(sets[0].moves, vec!["thunder-shock", "hidden-power", "surf"]);
+        assert_eq!(sets[0].stat_spread.level, 50);
+        assert_eq!(sets[0].stat_spread.nature, Nature::Jolly);
+        assert_eq!(sets[0].stat_spread.evs, [4, 252, 0, 0, 0, 252]);
+        assert_eq!(sets[0].stat_spread.ivs, [31, 31, 31, 0, 31, 31]);
+        assert_eq!(sets[1].species, "mr-mime");
+        assert_eq!(sets[1].item, None);
+        assert_eq!(sets[1].stat_spread.level, 100);
+    }
+
+    #[test]
+    fn showdown_parse_team_skips_team_headers() {
+        let sets = parse_team(&format!(
+            "=== [gen9ou] Sparks ===\n{}\n=== [gen9ou] Mimes ===\nMr. Mime",
+            STUB_TEAM
+        ));
+
+        let species: Vec<&str> = sets.iter().map(|set| set.species.as_str()).collect();
+        assert_eq!(species, vec!["pikachu", "mr-mime", "mr-mime"]);
+    }
+
+    #[test]
+    fn showdown_set_into_member() {
+        let set = parse_team(STUB_TEAM).remove(0);
+        let (member, problems) = set.into_member(
+            &get_stub_pokemon(),
+            NamedApiResource {
+                name: Some(String::from("pikachu")),
+                url: None,
+            },
+            &get_stub_moves(),
+            Some("x-y"),
+        );
+
+        assert_eq!(member.types, vec![String::from("electric")]);
+        assert_eq!(member.ability.as_deref(), Some("static"));
+        assert_eq!(member.moves.len(), 1);
+        assert_eq!(member.moves[0].move_type.as_deref(), Some("electric"));
+        assert_eq!(member.moves[0].damage_class.as_deref(), Some("special"));
+        assert_eq!(
+            problems,
+            vec![
+                String::from("Pikachu can't learn Hidden Power"),
+                String::from("Pikachu can't learn Surf in X Y"),
+            ]
+        );
+    }
+
+    #[test]
+    fn showdown_set_into_member_without_move_details() {
+        let set = parse_team("Pikachu\n- Thunder Shock").remove(0);
+        let (member, problems) = set.into_member(
+            &get_stub_pokemon(),
+            NamedApiResource {
+                name: Some(String::from("pikachu")),
+                url: None,
+            },
+            &[],
+            None,
+        );
+
+        assert!(member.moves.is_empty());
+        assert_eq!(
+            problems,
+            vec![String::from("There are no details of Thunder Shock")]
+        );
+    }
+
+    #[test]
+    fn showdown_set_into_member_caps_total_evs() {
+        let mut set = parse_team("Pikachu\nEVs: 252 HP / 252 Atk / 252 Spe").remove(0);
+        assert_eq!(set.stat_spread.evs, [252, 252, 0, 0, 0, 252]);
+
+        set.moves.clear();
+        let (member, problems) = set.into_member(
+            &get_stub_pokemon(),
+            NamedApiResource {
+                name: Some(String::from("pikachu")),
+                url: None,
+            },
+            &[],
+            None,
+        );
+
+        assert_eq!(member.stat_spread.evs, [252, 252, 0, 0, 0, 6]);
+        assert_eq!(
+            problems,
+            vec![String::from(
+                "Pikachu's EVs add up to more than 510, the last ones were lowered"
+            )]
+        );
+    }
+
+    #[test]
+    fn showdown_export_team() {
+        let set = parse_team(STUB_TEAM).remove(0);
+        let (member, _) = set.into_member(
+            &get_stub_pokemon(),
+            NamedApiResource {
+                name: Some(String::from("pikachu")),
+                url: None,
+            },
+            &get_stub_moves(),
+            None,
+        );
+        let mut team = Team::new(String::from("team-1"));
+        team.add_member(member);
+
+        assert_eq!(
+            export_team(&team),
+            "Pikachu @ Light Ball
+Ability: Static
+Level: 50
+EVs: 4 HP / 252 Atk / 252 Spe
+Jolly Nature
+IVs: 0 SpA
+- Thunder Shock
+- Surf
+"
+        );
+        assert_eq!(parse_team(&export_team(&team))[0].moves.len(), 2);
+    }
+}
@@ -0,0 +1,344 @@
+use serde::{Deserialize, Serialize};
+use tui::{
+    style::{Color, Style},
+    text::Span,
+};
+
+/// PokéAPI's stat names, in the order the games list them.
+pub const STAT_NAMES: [&str; 6] = [
+    "hp",
+    "attack",
+    "defense",
+    "special-attack",
+    "special-defense",
+    "speed",
+];
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 252;
+pub const MAX_TOTAL_EVS: i32 = 510;
+pub const MAX_LEVEL: i32 = 100;
+
+/// Natures are listed by the stat they raise, then the stat they lower, both in this order.
+const NATURE_STATS: [&str; 5] = [
+    "attack",
+    "defense",
+    "speed",
+    "special-attack",
+    "special-defense",
+];
+
+pub fn get_stat_abbreviation(stat_name: &str) -> &'static str {
+    match stat_name {
+        "hp" => "HP",
+        "attack" => "Atk",
+        "defense" => "Def",
+        "special-attack" => "SpA",
+        "special-defense" => "SpD",
+        "speed" => "Spe",
+        _ => "",
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Nature {
+    Hardy,
+    Lonely,
+    Brave,
+    Adamant,
+    Naughty,
+    Bold,
+    Docile,
+    Relaxed,
+    Impish,
+    Lax,
+    Timid,
+    Hasty,
+    Serious,
+    Jolly,
+    Naive,
+    Modest,
+    Mild,
+    Quiet,
+    Bashful,
+    Rash,
+    Calm,
+    Gentle,
+    Sassy,
+    Careful,
+    Quirky,
+}
+
+impl Nature {
+    pub const ALL: [Nature; 25] = [
+        Nature::Hardy,
+        Nature::Lonely,
+        Nature::Brave,
+        Nature::Adamant,
+        Nature::Naughty,
+        Nature::Bold,
+        Nature::Docile,
+        Nature::Relaxed,
+        Nature::Impish,
+        Nature::Lax,
+        Nature::Timid,
+        Nature::Hasty,
+        Nature::Serious,
+        Nature::Jolly,
+        Nature::Naive,
+        Nature::Modest,
+        Nature::Mild,
+        Nature::Quiet,
+        Nature::Bashful,
+        Nature::Rash,
+        Nature::Calm,
+        Nature::Gentle,
+        Nature::Sassy,
+        Nature::Careful,
+        Nature::Quirky,
+    ];
+
+    fn get_index(self) -> usize {
+        Nature::ALL
+            .iter()
+            .position(|nature| *nature == self)
+            .unwrap_or_default()
+    }
+
+    pub fn get_name(self) -> String {
+        format!("{:?}", self)
+    }
+
+    pub fn from_name(name: &str) -> Option<Nature> {
+        Nature::ALL
+            .into_iter()
+            .find(|nature| nature.get_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Neutral natures raise and lower the same stat, which cancels out.
+    pub fn get_changed_stats(self) -> Option<(&'static str, &'static str)> {
+        let index = self.get_index();
+        let increased = NATURE_STATS[index / NATURE_STATS.len()];
+        let decreased = NATURE_STATS[index % NATURE_STATS.len()];
+
+        (increased != decreased).then_some((increased, decreased))
+    }
+
+    /// In tenths, so the stat can be scaled without floating point like the games do.
+    pub fn get_multiplier(self, stat_name: &str) -> i32 {
+        match self.get_changed_stats() {
+            Some((increased, _)) if increased == stat_name => 11,
+            Some((_, decreased)) if decreased == stat_name => 9,
+            _ => 10,
+        }
+    }
+
+    pub fn get_renderable_name(self) -> String {
+        match self.get_changed_stats() {
+            Some((increased, decreased)) => format!(
+                "{} (+{} -{})",
+                self.get_name(),
+                get_stat_abbreviation(increased),
+                get_stat_abbreviation(decreased)
+            ),
+            None => self.get_name(),
+        }
+    }
+
+    pub fn get_renderable_effect_on(self, stat_name: &str) -> Span<'static> {
+        match self.get_multiplier(stat_name) {
+            11 => Span::styled("+", Style::default().fg(Color::Green)),
+            9 => Span::styled("-", Style::default().fg(Color::Red)),
+            _ => Span::raw(""),
+        }
+    }
+
+    pub fn next(self) -> Nature {
+        Nature::ALL[(self.get_index() + 1) % Nature::ALL.len()]
+    }
+}
+
+/// The actual value of a stat, as the games work it out since generation 3.
+pub fn calculate_stat(
+    stat_name: &str,
+    base_stat: i32,
+    level: i32,
+    iv: i32,
+    ev: i32,
+    nature: Nature,
+) -> i32 {
+    let scaled = (2 * base_stat + iv + ev / 4) * level / 100;
+
+    if stat_name == "hp" {
+        // Shedinja always has a single HP
+        if base_stat == 1 {
+            return 1;
+        }
+
+        return scaled + level + 10;
+    }
+
+    (scaled + 5) * nature.get_multiplier(stat_name) / 10
+}
+
+/// The lowest and highest value a stat can have at `level`, from no investment and a
+/// hindering nature up to full investment and a beneficial one.
+pub fn calculate_stat_range(stat_name: &str, base_stat: i32, level: i32) -> (i32, i32) {
+    let hindering = Nature::ALL
+        .into_iter()
+        .find(|nature| nature.get_multiplier(stat_name) == 9)
+        .unwrap_or(Nature::Hardy);
+    let beneficial = Nature::ALL
+        .into_iter()
+        .find(|nature| nature.get_multiplier(stat_name) == 11)
+        .unwrap_or(Nature::Hardy);
+
+    (
+        calculate_stat(stat_name, base_stat, level, 0, 0, hindering),
+        calculate_stat(stat_name, base_stat, level, MAX_IV, MAX_EV, beneficial),
+    )
+}
+
+/// Level, nature and the IVs and EVs of every stat, in the order of `STAT_NAMES`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct StatSpread {
+    pub level: i32,
+    pub nature: Nature,
+    pub ivs: [i32; 6],
+    pub evs: [i32; 6],
+}
+
+impl Default for StatSpread {
+    fn default() -> Self {
+        Self {
+            level: 50,
+            nature: Nature::Hardy,
+            ivs: [MAX_IV; 6],
+            evs: [0; 6],
+        }
+    }
+}
+
+impl StatSpread {
+    pub fn calculate(&self, stat_index: usize, base_stat: i32) -> i32 {
+        calculate_stat(
+            STAT_NAMES[stat_index],
+            base_stat,
+            self.level,
+            self.ivs[stat_index],
+            self.evs[stat_index],
+            self.nature,
+        )
+    }
+
+    pub fn get_total_evs(&self) -> i32 {
+        self.evs.iter().sum()
+    }
+
+    pub fn change_level(&mut self, delta: i32) {
+        self.level = (self.level + delta).clamp(1, MAX_LEVEL);
+    }
+
+    pub fn change_iv(&mut self, stat_index: usize, delta: i32) {
+        if let Some(iv) = self.ivs.get_mut(stat_index) {
+            *iv = (*iv + delta).clamp(0, MAX_IV);
+        }
+    }
+
+    /// The invested stats, like "252 Atk / 4 SpD / 252 Spe".
+    pub fn get_renderable_evs(&self) -> String {
+        get_renderable_values(&self.evs, |ev| ev > 0)
+    }
+
+    /// Only the stats that aren't at the maximum, which is what's usually assumed.
+    pub fn get_renderable_ivs(&self) -> String {
+        get_renderable_values(&self.ivs, |iv| iv < MAX_IV)
+    }
+
+    /// Never goes past the cap of a stat or what's left of the total.
+    pub fn change_ev(&mut self, stat_index: usize, delta: i32) {
+        let remaining = MAX_TOTAL_EVS - self.get_total_evs();
+
+        if let Some(ev) = self.evs.get_mut(stat_index) {
+            *ev = (*ev + delta.min(remaining)).clamp(0, MAX_EV);
+        }
+    }
+}
+
+fn get_renderable_values(values: &[i32; 6], is_shown: impl Fn(i32) -> bool) -> String {
+    values
+        .iter()
+        .zip(STAT_NAMES)
+        .filter(|(value, _)| is_shown(**value))
+        .map(|(value, stat_name)| format!("{} {}", value, get_stat_abbreviation(stat_name)))
+        .collect::<Vec<String>>()
+        .join(" / ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate_stat, calculate_stat_range, Nature, StatSpread, MAX_EV};
+
+    #[test]
+    fn nature_get_changed_stats() {
+        assert_eq!(
+            Nature::Adamant.get_changed_stats(),
+            Some(("attack", "special-attack"))
+        );
+        assert_eq!(Nature::Timid.get_changed_stats(), Some(("speed", "attack")));
+        assert_eq!(Nature::Serious.get_changed_stats(), None);
+        assert_eq!(Nature::Adamant.get_renderable_name(), "Adamant (+Atk -SpA)");
+    }
+
+    #[test]
+    fn nature_next_wraps_around() {
+        assert_eq!(Nature::Hardy.next(), Nature::Lonely);
+        assert_eq!(Nature::Quirky.next(), Nature::Hardy);
+    }
+
+    #[test]
+    fn calculate_stat_matches_the_games() {
+        // Garchomp at level 78 with an Adamant nature, from Bulbapedia's example
+        assert_eq!(calculate_stat("hp", 108, 78, 24, 74, Nature::Adamant), 289);
+        assert_eq!(
+            calculate_stat("attack", 130, 78, 12, 190, Nature::Adamant),
+            278
+        );
+        assert_eq!(
+            calculate_stat("special-attack", 80, 78, 16, 48, Nature::Adamant),
+            135
+        );
+        assert_eq!(calculate_stat("hp", 1, 100, 31, 252, Nature::Hardy), 1);
+    }
+
+    #[test]
+    fn calculate_stat_range_at_level_100() {
+        assert_eq!(calculate_stat_range("hp", 35, 100), (180, 274));
+        assert_eq!(calculate_stat_range("speed", 90, 100), (166, 306));
+    }
+
+    #[test]
+    fn stat_spread_change_ev_respects_caps() {
+        let mut stat_spread = StatSpread::default();
+
+        stat_spread.change_ev(0, 300);
+        stat_spread.change_ev(1, MAX_EV);
+        stat_spread.change_ev(2, MAX_EV);
+        stat_spread.change_ev(3, -4);
+
+        assert_eq!(stat_spread.evs, [252, 252, 6, 0, 0, 0]);
+        assert_eq!(stat_spread.get_total_evs(), 510);
+    }
+
+    #[test]
+    fn stat_spread_get_renderable_evs_and_ivs() {
+        let mut stat_spread = StatSpread::default();
+        stat_spread.change_ev(1, MAX_EV);
+        stat_spread.change_ev(5, MAX_EV);
+        stat_spread.change_ev(0, 4);
+        stat_spread.change_iv(5, -31);
+
+        assert_eq!(stat_spread.get_renderable_evs(), "4 HP / 252 Atk / 252 Spe");
+        assert_eq!(stat_spread.get_renderable_ivs(), "0 Spe");
+        assert_eq!(StatSpread::default().get_renderable_evs(), "");
+    }
+}
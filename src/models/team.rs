@@ -0,0 +1,472 @@
+use serde::{Deserialize, Serialize};
+use tui::{
+    style::{Color, Style},
+    text::{Span, Spans, Text},
+    widgets::{Cell, Row},
+};
+
+use crate::utils::{get_styled_pokemon_type, PrepareForDisplay};
+
+use super::{
+    pokemon_type::ATTACKING_TYPES, type_matchups::get_renderable_group, NamedApiResource,
+    PokemonMoveExt, PokemonTypeExt, StatSpread, TypeMatchups,
+};
+
+pub const MAX_TEAM_SIZE: usize = 6;
+pub const MAX_MOVES: usize = 4;
+
+/// A move a team member knows, with what's needed to work out the team's coverage.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TeamMove {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub move_type: Option<String>,
+    pub damage_class: Option<String>,
+}
+
+impl TeamMove {
+    pub fn from_move(pokemon_move: &PokemonMoveExt) -> Option<Self> {
+        Some(Self {
+            name: pokemon_move.name.clone()?,
+            move_type: pokemon_move
+                .pk_type
+                .as_ref()
+                .and_then(|move_type| move_type.name.clone()),
+            damage_class: pokemon_move
+                .damage_class
+                .as_ref()
+                .and_then(|damage_class| damage_class.name.clone()),
+        })
+    }
+
+    pub fn is_damaging(&self) -> bool {
+        matches!(self.damage_class.as_deref(), Some("physical" | "special"))
+    }
+}
+
+/// A Pokemon on a team. Its types are kept by name, so a saved team can be shown
+/// without fetching every member again.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TeamMember {
+    pub pokemon: NamedApiResource,
+    pub types: Vec<String>,
+    pub ability: Option<String>,
+    pub item: Option<String>,
+    pub moves: Vec<TeamMove>,
+    #[serde(default)]
+    pub stat_spread: StatSpread,
+}
+
+impl TeamMember {
+    pub fn new(pokemon: NamedApiResource, types: Vec<String>, ability: Option<String>) -> Self {
+        Self {
+            pokemon,
+            types,
+            ability,
+            item: None,
+            moves: vec![],
+            stat_spread: StatSpread::default(),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.pokemon.get_name_or_stub()
+    }
+
+    pub fn knows_move(&self, move_name: &str) -> bool {
+        self.moves
+            .iter()
+            .any(|team_move| team_move.name == move_name)
+    }
+
+    /// Teaches the move, or makes the member forget it when it already knows it.
+    /// Returns whether anything changed, which it doesn't once all move slots are taken.
+    pub fn toggle_move(&mut self, team_move: TeamMove) -> bool {
+        if self.knows_move(&team_move.name) {
+            self.moves
+                .retain(|known_move| known_move.name != team_move.name);
+            return true;
+        }
+
+        if self.moves.len() >= MAX_MOVES {
+            return false;
+        }
+
+        self.moves.push(team_move);
+        true
+    }
+
+    fn get_matchups(&self, types: &[PokemonTypeExt]) -> TypeMatchups {
+        TypeMatchups::with_types(
+            types.iter().filter(|de_type| {
+                de_type
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| self.types.contains(name))
+            }),
+            self.ability.as_deref(),
+        )
+    }
+
+    /// Two lines a member: what it is and what it knows, then how it's built.
+    pub fn get_renderable_as_row(&self) -> Row<'static> {
+        let paint_gray =
+            |string: &str| Span::styled(string.to_string(), Style::default().fg(Color::DarkGray));
+        let get_renderable_name = |name: Option<&String>, stub: &str| match name {
+            Some(name) => Span::raw(name.clone().split_capitalize()),
+            None => paint_gray(stub),
+        };
+        let moves: Vec<String> = self
+            .moves
+            .iter()
+            .map(|team_move| team_move.name.clone().split_capitalize())
+            .collect();
+        let get_renderable_moves = |moves: &[String]| match moves.is_empty() {
+            true => Span::raw(""),
+            false => Span::raw(moves.join(", ")),
+        };
+        let (first_moves, last_moves) = moves.split_at(moves.len().min(2));
+        let evs = self.stat_spread.get_renderable_evs();
+        let ivs = self.stat_spread.get_renderable_ivs();
+
+        Row::new(vec![
+            Cell::from(Text::from(vec![
+                Spans::from(Span::styled(
+                    self.get_name().split_capitalize().append_padding(),
+                    Style::default().fg(Color::Blue),
+                )),
+                Spans::from(
+                    std::iter::once(Span::raw("\u{A0}"))
+                        .chain(
+                            self.types
+                                .iter()
+                                .map(|de_type| get_styled_pokemon_type(de_type.clone())),
+                        )
+                        .collect::<Vec<Span>>(),
+                ),
+            ])),
+            Cell::from(Text::from(vec![
+                Spans::from(get_renderable_name(self.ability.as_ref(), "No ability")),
+                Spans::from(get_renderable_name(self.item.as_ref(), "No item")),
+            ])),
+            Cell::from(Text::from(vec![
+                Spans::from(self.stat_spread.nature.get_renderable_name()),
+                Spans::from(format!("Lv. {}", self.stat_spread.level)),
+            ])),
+            Cell::from(Text::from(match moves.is_empty() {
+                true => vec![Spans::from(paint_gray("No moves"))],
+                false => vec![
+                    Spans::from(get_renderable_moves(first_moves)),
+                    Spans::from(get_renderable_moves(last_moves)),
+                ],
+            })),
+            Cell::from(Text::from(vec![
+                Spans::from(match evs.is_empty() {
+                    true => paint_gray("No EVs"),
+                    false => Span::raw(evs),
+                }),
+                Spans::from(match ivs.is_empty() {
+                    true => Span::raw(""),
+                    false => Span::raw(format!("IVs: {}", ivs)),
+                }),
+            ])),
+        ])
+        .height(2)
+    }
+}
+
+/// New teams are named after how many there are, the first free one of "team-1", "team-2"...
+pub fn get_free_team_name(teams: &[Team]) -> String {
+    (1..)
+        .map(|number| format!("team-{}", number))
+        .find(|name| teams.iter().all(|team| &team.name != name))
+        .unwrap_or_default()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Team {
+    pub name: String,
+    /// Where the moves were picked, set by the first member.
+    pub version_group: Option<String>,
+    pub members: Vec<TeamMember>,
+}
+
+impl Team {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            version_group: None,
+            members: vec![],
+        }
+    }
+
+    pub fn get_member_index(&self, pokemon_name: &str) -> Option<usize> {
+        self.members
+            .iter()
+            .position(|member| member.get_name() == pokemon_name)
+    }
+
+    /// A Pokemon is only on a team once, and a team has room for six.
+    pub fn add_member(&mut self, member: TeamMember) -> bool {
+        if self.members.len() >= MAX_TEAM_SIZE
+            || self.get_member_index(&member.get_name()).is_some()
+        {
+            return false;
+        }
+
+        self.members.push(member);
+        true
+    }
+
+    pub fn get_renderable_members(&self) -> Vec<Row<'static>> {
+        self.members
+            .iter()
+            .map(|member| member.get_renderable_as_row())
+            .collect()
+    }
+
+    /// The most damage the team's damaging moves can deal to every type.
+    pub fn get_coverage(&self, types: &[PokemonTypeExt]) -> Vec<(&'static str, f32)> {
+        let move_types: Vec<&str> = self
+            .members
+            .iter()
+            .flat_map(|member| member.moves.iter())
+            .filter(|team_move| team_move.is_damaging())
+            .filter_map(|team_move| team_move.move_type.as_deref())
+            .collect();
+        if move_types.is_empty() {
+            return vec![];
+        }
+
+        ATTACKING_TYPES
+            .iter()
+            .filter_map(|defending_type| {
+                let defending_type_ext = types
+                    .iter()
+                    .find(|de_type| de_type.name.as_deref() == Some(defending_type))?;
+                let best = move_types
+                    .iter()
+                    .map(|move_type| defending_type_ext.get_damage_multiplier_from(move_type))
+                    .fold(0.0, f32::max);
+
+                Some((*defending_type, best))
+            })
+            .collect()
+    }
+
+    /// A line per multiplier, the types the team hits hardest first.
+    pub fn get_renderable_coverage(&self, types: &[PokemonTypeExt]) -> Vec<Spans<'static>> {
+        let coverage = self.get_coverage(types);
+        if coverage.is_empty() {
+            return vec![Spans::from(Span::styled(
+                "No damaging moves",
+                Style::default().fg(Color::DarkGray),
+            ))];
+        }
+
+        [2.0, 1.0, 0.5, 0.0]
+            .into_iter()
+            .filter_map(|multiplier| {
+                let covered_types: Vec<&'static str> = coverage
+                    .iter()
+                    .filter(|(_, best)| *best == multiplier)
+                    .map(|(defending_type, _)| *defending_type)
+                    .collect();
+
+                (!covered_types.is_empty()).then(|| get_renderable_group(multiplier, covered_types))
+            })
+            .collect()
+    }
+
+    /// Attacking types that at least two members are weak to, with how many are weak
+    /// and how many resist them, the most threatening first.
+    pub fn get_shared_weaknesses(
+        &self,
+        types: &[PokemonTypeExt],
+    ) -> Vec<(&'static str, usize, usize)> {
+        let matchups: Vec<TypeMatchups> = self
+            .members
+            .iter()
+            .map(|member| member.get_matchups(types))
+            .collect();
+        let count = |attacking_type: &str, is_counted: fn(f32) -> bool| {
+            matchups
+                .iter()
+                .filter_map(|matchups| matchups.get_multiplier(attacking_type))
+                .filter(|multiplier| is_counted(*multiplier))
+                .count()
+        };
+
+        let mut shared_weaknesses: Vec<(&'static str, usize, usize)> = ATTACKING_TYPES
+            .iter()
+            .map(|attacking_type| {
+                (
+                    *attacking_type,
+                    count(attacking_type, |multiplier| multiplier > 1.0),
+                    count(attacking_type, |multiplier| multiplier < 1.0),
+                )
+            })
+            .filter(|(_, weak, _)| *weak >= 2)
+            .collect();
+        shared_weaknesses.sort_by(
+            |(_, first_weak, first_resist), (_, second_weak, second_resist)| {
+                second_weak
+                    .cmp(first_weak)
+                    .then(first_resist.cmp(second_resist))
+            },
+        );
+
+        shared_weaknesses
+    }
+
+    pub fn get_renderable_shared_weaknesses(
+        &self,
+        types: &[PokemonTypeExt],
+    ) -> Vec<Spans<'static>> {
+        let shared_weaknesses = self.get_shared_weaknesses(types);
+        if shared_weaknesses.is_empty() {
+            return vec![Spans::from(Span::styled(
+                "None",
+                Style::default().fg(Color::DarkGray),
+            ))];
+        }
+
+        shared_weaknesses
+            .into_iter()
+            .map(|(attacking_type, weak, resist)| {
+                Spans::from(vec![
+                    Span::styled(
+                        format!("{} weak, {} resist ", weak, resist),
+                        Style::default().fg(Color::Red),
+                    ),
+                    get_styled_pokemon_type(attacking_type.to_string()),
+                ])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{
+        pokemon_type::fixture::get_stub_type_ext, NamedApiResource, PokemonTypeExt,
+    };
+
+    use super::{Team, TeamMember, TeamMove, MAX_MOVES};
+
+    fn get_stub_types() -> Vec<PokemonTypeExt> {
+        vec![
+            get_stub_type_ext(
+                "electric",
+                &["ground"],
+                &["electric", "flying", "steel"],
+                &[],
+            ),
+            get_stub_type_ext(
+                "water",
+                &["electric", "grass"],
+                &["fire", "water", "ice", "steel"],
+                &[],
+            ),
+            get_stub_type_ext(
+                "ground",
+                &["water", "grass", "ice"],
+                &["poison", "rock"],
+                &["electric"],
+            ),
+            get_stub_type_ext(
+                "flying",
+                &["electric", "ice", "rock"],
+                &["grass", "fighting", "bug"],
+                &["ground"],
+            ),
+        ]
+    }
+
+    fn get_stub_move(name: &str, move_type: &str, damage_class: &str) -> TeamMove {
+        TeamMove {
+            name: String::from(name),
+            move_type: Some(String::from(move_type)),
+            damage_class: Some(String::from(damage_class)),
+        }
+    }
+
+    fn get_stub_member(name: &str, types: &[&str]) -> TeamMember {
+        TeamMember::new(
+            NamedApiResource {
+                name: Some(String::from(name)),
+                url: None,
+            },
+            types.iter().map(|de_type| de_type.to_string()).collect(),
+            None,
+        )
+    }
+
+    #[test]
+    fn team_member_toggle_move() {
+        let mut member = get_stub_member("pikachu", &["electric"]);
+
+        for index in 0..MAX_MOVES {
+            assert!(member.toggle_move(get_stub_move(
+                &format!("move-{}", index),
+                "normal",
+                "physical"
+            )));
+        }
+        assert!(!member.toggle_move(get_stub_move("thunderbolt", "electric", "special")));
+        assert!(member.toggle_move(get_stub_move("move-0", "normal", "physical")));
+        assert!(!member.knows_move("move-0"));
+        assert_eq!(member.moves.len(), MAX_MOVES - 1);
+    }
+
+    #[test]
+    fn team_add_member() {
+        let mut team = Team::new(String::from("team-1"));
+
+        assert!(team.add_member(get_stub_member("pikachu", &["electric"])));
+        assert!(!team.add_member(get_stub_member("pikachu", &["electric"])));
+        for index in 1..6 {
+            assert!(team.add_member(get_stub_member(&format!("pokemon-{}", index), &[])));
+        }
+        assert!(!team.add_member(get_stub_member("raichu", &["electric"])));
+        assert_eq!(team.get_member_index("pokemon-2"), Some(2));
+    }
+
+    #[test]
+    fn team_get_coverage() {
+        let mut team = Team::new(String::from("team-1"));
+        let mut member = get_stub_member("pikachu", &["electric"]);
+        member.toggle_move(get_stub_move("thunderbolt", "electric", "special"));
+        member.toggle_move(get_stub_move("thunder-wave", "ground", "status"));
+        team.add_member(member);
+
+        assert!(Team::new(String::from("team-2"))
+            .get_coverage(&get_stub_types())
+            .is_empty());
+        assert_eq!(
+            team.get_coverage(&get_stub_types()),
+            vec![
+                ("water", 2.0),
+                ("electric", 0.5),
+                ("ground", 0.0),
+                ("flying", 2.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn team_get_shared_weaknesses() {
+        let mut team = Team::new(String::from("team-1"));
+        team.add_member(get_stub_member("pikachu", &["electric"]));
+        team.add_member(get_stub_member("gyarados", &["water", "flying"]));
+        team.add_member(get_stub_member("pelipper", &["water", "flying"]));
+        let mut rotom = get_stub_member("rotom", &["electric"]);
+        rotom.ability = Some(String::from("levitate"));
+        team.add_member(rotom);
+
+        assert_eq!(
+            team.get_shared_weaknesses(&get_stub_types()),
+            vec![("rock", 2, 0), ("electric", 2, 2)]
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use tui::{
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+};
+
+use crate::utils::{get_styled_pokemon_type, PrepareForDisplay};
+
+use super::{pokemon_type::ATTACKING_TYPES, PokemonTypeExt};
+
+/// How hard every attacking type hits a Pokemon, with both of its types and,
+/// when it's known, its ability taken into account.
+#[derive(Debug, PartialEq)]
+pub struct TypeMatchups {
+    multipliers: Vec<(&'static str, f32)>,
+}
+
+impl TypeMatchups {
+    pub fn new(types: &[PokemonTypeExt], ability: Option<&str>) -> Self {
+        Self::with_types(types.iter(), ability)
+    }
+
+    /// Like `new`, for types that aren't kept together in one slice.
+    pub fn with_types<'a>(
+        types: impl Iterator<Item = &'a PokemonTypeExt> + Clone,
+        ability: Option<&str>,
+    ) -> Self {
+        let multipliers = ATTACKING_TYPES
+            .iter()
+            .map(|attacking_type| {
+                let multiplier = types
+                    .clone()
+                    .map(|de_type| de_type.get_damage_multiplier_from(attacking_type))
+                    .product();
+
+                (
+                    *attacking_type,
+                    apply_ability(ability, attacking_type, multiplier),
+                )
+            })
+            .collect();
+
+        Self { multipliers }
+    }
+
+    pub fn get_multiplier(&self, attacking_type: &str) -> Option<f32> {
+        self.multipliers
+            .iter()
+            .find(|(de_type, _)| *de_type == attacking_type)
+            .map(|(_, multiplier)| *multiplier)
+    }
+
+    /// Attacking types sharing a multiplier, the most effective ones first.
+    pub fn get_grouped(&self) -> Vec<(f32, Vec<&'static str>)> {
+        let mut multipliers = self.multipliers.clone();
+        multipliers.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        let mut groups: Vec<(f32, Vec<&'static str>)> = vec![];
+        for (attacking_type, multiplier) in multipliers {
+            match groups.last_mut() {
+                Some((last_multiplier, types)) if *last_multiplier == multiplier => {
+                    types.push(attacking_type)
+                }
+                _ => groups.push((multiplier, vec![attacking_type])),
+            }
+        }
+
+        groups
+    }
+
+    /// The attacking types whose multiplier differs in `other`, with the multiplier from `other`.
+    pub fn get_changes(&self, other: &TypeMatchups) -> Vec<(&'static str, f32)> {
+        other
+            .multipliers
+            .iter()
+            .filter(|(attacking_type, multiplier)| {
+                self.get_multiplier(attacking_type) != Some(*multiplier)
+            })
+            .copied()
+            .collect()
+    }
+
+    pub fn get_renderable(&self) -> Vec<Spans<'static>> {
+        self.get_grouped()
+            .into_iter()
+            .map(|(multiplier, types)| get_renderable_group(multiplier, types))
+            .collect()
+    }
+
+    /// Like `get_renderable`, but only the attacking types that hit harder than neutral.
+    pub fn get_renderable_weaknesses(&self) -> Vec<Spans<'static>> {
+        self.get_grouped()
+            .into_iter()
+            .filter(|(multiplier, _)| *multiplier > 1.0)
+            .map(|(multiplier, types)| get_renderable_group(multiplier, types))
+            .collect()
+    }
+
+    /// A line with the attacking types `ability` changes, `other` being the matchups with it.
+    pub fn get_renderable_ability_changes(
+        &self,
+        ability: &str,
+        other: &TypeMatchups,
+    ) -> Spans<'static> {
+        let mut spans = vec![Span::styled(
+            format!("With {}: ", ability.to_string().split_capitalize()),
+            Style::default().fg(Color::Blue),
+        )];
+        for (attacking_type, multiplier) in self.get_changes(other) {
+            spans.push(get_styled_pokemon_type(attacking_type.to_string()));
+            spans.push(get_styled_multiplier(multiplier));
+        }
+
+        Spans::from(spans)
+    }
+}
+
+/// Only abilities that change how much damage a type deals, regardless of the move used.
+fn apply_ability(ability: Option<&str>, attacking_type: &str, multiplier: f32) -> f32 {
+    match (ability, attacking_type) {
+        (Some("levitate" | "earth-eater"), "ground")
+        | (Some("flash-fire" | "well-baked-body"), "fire")
+        | (Some("volt-absorb" | "lightning-rod" | "motor-drive"), "electric")
+        | (Some("water-absorb" | "storm-drain" | "dry-skin"), "water")
+        | (Some("sap-sipper"), "grass") => 0.0,
+        (Some("wonder-guard"), _) if multiplier <= 1.0 => 0.0,
+        (Some("thick-fat"), "fire" | "ice")
+        | (Some("heatproof" | "water-bubble"), "fire")
+        | (Some("purifying-salt"), "ghost") => multiplier * 0.5,
+        (Some("dry-skin"), "fire") => multiplier * 1.25,
+        (Some("fluffy"), "fire") => multiplier * 2.0,
+        _ => multiplier,
+    }
+}
+
+pub fn get_renderable_group(multiplier: f32, types: Vec<&'static str>) -> Spans<'static> {
+    let mut spans = vec![get_styled_multiplier(multiplier)];
+    spans.extend(
+        types
+            .into_iter()
+            .map(|de_type| get_styled_pokemon_type(de_type.to_string())),
+    );
+
+    Spans::from(spans)
+}
+
+fn get_styled_multiplier(multiplier: f32) -> Span<'static> {
+    let label = match (multiplier * 8.0).round() as i32 {
+        0 => String::from("0x"),
+        1 => String::from("⅛x"),
+        2 => String::from("¼x"),
+        4 => String::from("½x"),
+        eighths if eighths % 8 == 0 => format!("{}x", eighths / 8),
+        _ => format!("{}x", multiplier),
+    };
+    let style = if multiplier == 0.0 {
+        Style::default().fg(Color::DarkGray)
+    } else if multiplier >= 4.0 {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else if multiplier > 1.0 {
+        Style::default().fg(Color::Red)
+    } else if multiplier <= 0.25 {
+        Style::default()
+            .fg(Color::Green)
+            .add_modifier(Modifier::BOLD)
+    } else if multiplier < 1.0 {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default()
+    };
+
+    Span::styled(format!("{:<5}", label), style)
+}
+
+#[cfg(test)]
+mod tests {
+    use tui::{
+        style::{Color, Modifier, Style},
+        text::Span,
+    };
+
+    use crate::models::{pokemon_type::fixture::get_stub_type_ext, PokemonTypeExt};
+
+    use super::{get_styled_multiplier, TypeMatchups};
+
+    fn get_stub_types() -> Vec<PokemonTypeExt> {
+        vec![
+            get_stub_type_ext(
+                "grass",
+                &["fire", "ice", "poison", "flying", "bug"],
+                &["water", "electric", "grass", "ground"],
+                &[],
+            ),
+            get_stub_type_ext(
+                "flying",
+                &["electric", "ice", "rock"],
+                &["grass", "fighting", "bug"],
+                &["ground"],
+            ),
+        ]
+    }
+
+    #[test]
+    fn type_matchups_new() {
+        let type_matchups = TypeMatchups::new(&get_stub_types(), None);
+
+        assert_eq!(type_matchups.get_multiplier("ice"), Some(4.0));
+        assert_eq!(type_matchups.get_multiplier("fire"), Some(2.0));
+        assert_eq!(type_matchups.get_multiplier("electric"), Some(1.0));
+        assert_eq!(type_matchups.get_multiplier("water"), Some(0.5));
+        assert_eq!(type_matchups.get_multiplier("grass"), Some(0.25));
+        assert_eq!(type_matchups.get_multiplier("ground"), Some(0.0));
+    }
+
+    #[test]
+    fn type_matchups_new_with_ability() {
+        let type_matchups = TypeMatchups::new(&get_stub_types(), Some("thick-fat"));
+
+        assert_eq!(type_matchups.get_multiplier("ice"), Some(2.0));
+        assert_eq!(type_matchups.get_multiplier("fire"), Some(1.0));
+    }
+
+    #[test]
+    fn type_matchups_get_grouped() {
+        let type_matchups = TypeMatchups::new(&get_stub_types(), None);
+        let grouped = type_matchups.get_grouped();
+
+        assert_eq!(grouped.first(), Some(&(4.0, vec!["ice"])));
+        assert_eq!(grouped.last(), Some(&(0.0, vec!["ground"])));
+        assert_eq!(
+            grouped
+                .iter()
+                .map(|(multiplier, _)| *multiplier)
+                .collect::<Vec<f32>>(),
+            vec![4.0, 2.0, 1.0, 0.5, 0.25, 0.0]
+        );
+    }
+
+    #[test]
+    fn type_matchups_get_changes() {
+        let types = get_stub_types();
+        let type_matchups = TypeMatchups::new(&types, None);
+
+        assert_eq!(
+            type_matchups.get_changes(&TypeMatchups::new(&types, Some("flash-fire"))),
+            vec![("fire", 0.0)]
+        );
+        assert!(type_matchups
+            .get_changes(&TypeMatchups::new(&types, Some("overgrow")))
+            .is_empty());
+    }
+
+    #[test]
+    fn type_matchups_get_renderable_weaknesses() {
+        let type_matchups = TypeMatchups::new(&get_stub_types(), None);
+        let weaknesses = type_matchups.get_renderable_weaknesses();
+
+        assert_eq!(weaknesses.len(), 2);
+        assert_eq!(
+            weaknesses[0].0[1],
+            Span::styled("Ice ", Style::default().fg(Color::Rgb(102, 204, 255)))
+        );
+    }
+
+    #[test]
+    fn type_matchups_get_styled_multiplier() {
+        assert_eq!(
+            get_styled_multiplier(4.0),
+            Span::styled(
+                "4x   ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+            )
+        );
+        assert_eq!(
+            get_styled_multiplier(0.5),
+            Span::styled("½x   ", Style::default().fg(Color::Green))
+        );
+        assert_eq!(
+            get_styled_multiplier(1.25),
+            Span::styled("1.25x", Style::default().fg(Color::Red))
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::parse_generation;
+
+use super::NamedApiResource;
+
+/// Which versions a version group is made of. The names only look like they tell:
+/// `lets-go-pikachu-lets-go-eevee` or `black-2-white-2` can't be split back into versions.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VersionGroup {
+    pub id: Option<i32>,
+    pub name: Option<String>,
+    pub order: Option<i32>,
+    pub generation: Option<NamedApiResource>,
+    pub versions: Option<Vec<NamedApiResource>>,
+}
+
+impl VersionGroup {
+    pub fn get_name_or_stub(&self) -> String {
+        self.name.clone().unwrap_or_default()
+    }
+
+    pub fn has_version(&self, version: &str) -> bool {
+        self.versions
+            .iter()
+            .flatten()
+            .any(|group_version| group_version.name.as_deref() == Some(version))
+    }
+
+    pub fn get_generation(&self) -> Option<i32> {
+        parse_generation(self.generation.as_ref()?.name.as_deref()?)
+    }
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use serde_json::json;
+
+    use super::VersionGroup;
+
+    /// A version group made of the given versions.
+    pub fn get_stub_version_group(name: &str, versions: &[&str]) -> VersionGroup {
+        let versions: Vec<_> = versions
+            .iter()
+            .map(|version| json!({ "name": version, "url": null }))
+            .collect();
+
+        serde_json::from_value(json!({ "name": name, "versions": versions })).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::NamedApiResource;
+
+    use super::fixture::get_stub_version_group;
+
+    #[test]
+    fn version_group_has_version() {
+        let version_group = get_stub_version_group(
+            "lets-go-pikachu-lets-go-eevee",
+            &["lets-go-pikachu", "lets-go-eevee"],
+        );
+
+        assert!(version_group.has_version("lets-go-eevee"));
+        assert!(!version_group.has_version("go"));
+        assert!(!version_group.has_version("pikachu"));
+    }
+
+    #[test]
+    fn version_group_get_generation() {
+        let get_generation = |generation: &str| {
+            let mut version_group = get_stub_version_group("x-y", &["x", "y"]);
+            version_group.generation = Some(NamedApiResource {
+                name: Some(generation.to_string()),
+                url: None,
+            });
+
+            version_group.get_generation()
+        };
+
+        assert_eq!(get_generation("generation-i"), Some(1));
+        assert_eq!(get_generation("generation-iv"), Some(4));
+        assert_eq!(get_generation("generation-vi"), Some(6));
+        assert_eq!(get_generation("generation-ix"), Some(9));
+        assert_eq!(get_generation("gen-4"), None);
+        assert_eq!(get_stub_version_group("x-y", &[]).get_generation(), None);
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use async_trait::async_trait;
+use hyper::body::Bytes;
+
+use crate::{
+    error::{FetchError, FetchResult},
+    source::PokeApiSource,
+};
+
+const API_PATH_PREFIX: &str = "api/v2/";
+const INDEX_FILE_NAME: &str = "index.json";
+
+/// Read-only PokéAPI data laid out like the api-data repository, e.g. `api/v2/pokemon/25/index.json`.
+pub struct LocalSnapshot {
+    api_root: PathBuf,
+}
+
+impl LocalSnapshot {
+    pub fn new(root: PathBuf) -> Self {
+        let nested_api_root = root.join(API_PATH_PREFIX);
+        let api_root = if nested_api_root.is_dir() {
+            nested_api_root
+        } else {
+            root
+        };
+
+        Self { api_root }
+    }
+
+    pub fn api_root(&self) -> &Path {
+        &self.api_root
+    }
+
+    /// Maps absolute (`https://pokeapi.co/api/v2/pokemon/25/`) as well as snapshot-relative
+    /// (`/api/v2/pokemon/25/`) URLs onto the snapshot directory. Query strings are dropped,
+    /// since snapshot lists are never paginated.
+    fn resolve_path(&self, uri: &str) -> PathBuf {
+        let without_query = uri.split('?').next().unwrap_or_default();
+        let resource_path = match (
+            without_query.find(API_PATH_PREFIX),
+            without_query.find("://"),
+        ) {
+            (Some(index), _) => &without_query[index + API_PATH_PREFIX.len()..],
+            (None, Some(index)) => {
+                let without_scheme = &without_query[index + 3..];
+                without_scheme
+                    .find('/')
+                    .map(|index| &without_scheme[index..])
+                    .unwrap_or_default()
+            }
+            (None, None) => without_query,
+        };
+
+        resource_path
+            .split('/')
+            .filter(|segment| !segment.is_empty() && *segment != "." && *segment != "..")
+            .fold(self.api_root.clone(), |path, segment| path.join(segment))
+            .join(INDEX_FILE_NAME)
+    }
+}
+
+#[async_trait]
+impl PokeApiSource for LocalSnapshot {
+    async fn get(&self, uri: &str) -> FetchResult<Bytes> {
+        tokio::fs::read(self.resolve_path(uri))
+            .await
+            .map(Bytes::from)
+            .map_err(|err| match err.kind() {
+                // A resource missing from the snapshot is what a 404 would be online
+                ErrorKind::NotFound => FetchError::Status {
+                    uri: uri.to_string(),
+                    status: 404,
+                },
+                _ => FetchError::Network {
+                    uri: uri.to_string(),
+                    message: err.to_string(),
+                },
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use crate::{error::FetchError, source::PokeApiSource};
+
+    use super::LocalSnapshot;
+
+    fn get_stub_snapshot(name: &str) -> (PathBuf, LocalSnapshot) {
+        let root = env::temp_dir().join(format!("pokerust-tui-snapshot-{}", name));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("api/v2/pokemon/25")).unwrap();
+        fs::write(
+            root.join("api/v2/pokemon/25/index.json"),
+            "{\"name\":\"pikachu\"}",
+        )
+        .unwrap();
+
+        let snapshot = LocalSnapshot::new(root.clone());
+
+        (root, snapshot)
+    }
+
+    #[test]
+    fn local_snapshot_resolve_path() {
+        let (root, snapshot) = get_stub_snapshot("resolve");
+        let expected = root.join("api/v2/pokemon/25/index.json");
+
+        assert_eq!(
+            snapshot.resolve_path("https://pokeapi.co/api/v2/pokemon/25/"),
+            expected
+        );
+        assert_eq!(snapshot.resolve_path("/api/v2/pokemon/25/"), expected);
+        assert_eq!(
+            snapshot.resolve_path("https://pokeapi.co/api/v2/pokemon?limit=100000&offset=0"),
+            root.join("api/v2/pokemon/index.json")
+        );
+        assert_eq!(
+            snapshot.resolve_path("http://localhost:8000/pokemon/25/"),
+            expected
+        );
+        assert_eq!(
+            snapshot.resolve_path("/api/v2/../../etc/passwd"),
+            root.join("api/v2/etc/passwd/index.json")
+        );
+    }
+
+    #[test]
+    fn local_snapshot_accepts_api_root_directly() {
+        let (root, _) = get_stub_snapshot("api-root");
+        let snapshot = LocalSnapshot::new(root.join("api/v2"));
+
+        assert_eq!(snapshot.api_root(), root.join("api/v2"));
+    }
+
+    #[tokio::test]
+    async fn local_snapshot_get() {
+        let (_, snapshot) = get_stub_snapshot("get");
+
+        assert_eq!(
+            snapshot
+                .get("https://pokeapi.co/api/v2/pokemon/25/")
+                .await
+                .unwrap(),
+            &b"{\"name\":\"pikachu\"}"[..]
+        );
+        assert_eq!(
+            snapshot.get("/api/v2/pokemon/26/").await,
+            Err(FetchError::Status {
+                uri: String::from("/api/v2/pokemon/26/"),
+                status: 404
+            })
+        );
+    }
+}
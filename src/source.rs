@@ -0,0 +1,476 @@
+use std::{fmt::Debug, future::Future, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use hyper::body::Bytes;
+use serde::de::DeserializeOwned;
+use tokio::{
+    sync::{
+        mpsc::{self, Sender},
+        Semaphore,
+    },
+    time::sleep,
+};
+
+use crate::{
+    error::{FetchError, FetchResult},
+    models::{
+        ContestEffect, EvolutionChain, Item, ListWrapper, Location, LocationArea, Machine,
+        MoveDetail, Pokemon, PokemonAbilityExt, PokemonEncounter, PokemonForm, PokemonMoveExt,
+        PokemonSpecies, PokemonTypeExt, Region, VersionGroup,
+    },
+};
+
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 8;
+
+/// Anything PokéAPI resources can be read from: the network, a local snapshot or canned fixtures.
+/// Only `get` has to be implemented, the typed accessors decode its JSON.
+#[async_trait]
+pub trait PokeApiSource: Send + Sync {
+    async fn get(&self, uri: &str) -> FetchResult<Bytes>;
+
+    async fn list(&self, uri: &str) -> FetchResult<ListWrapper> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn pokemon(&self, uri: &str) -> FetchResult<Pokemon> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn species(&self, uri: &str) -> FetchResult<PokemonSpecies> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn pokemon_form(&self, uri: &str) -> FetchResult<PokemonForm> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn evolution_chain(&self, uri: &str) -> FetchResult<EvolutionChain> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn encounters(&self, uri: &str) -> FetchResult<Vec<PokemonEncounter>> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn pokemon_move(&self, uri: &str) -> FetchResult<PokemonMoveExt> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn ability(&self, uri: &str) -> FetchResult<PokemonAbilityExt> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn pokemon_type(&self, uri: &str) -> FetchResult<PokemonTypeExt> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn move_detail(&self, uri: &str) -> FetchResult<MoveDetail> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn machine(&self, uri: &str) -> FetchResult<Machine> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn contest_effect(&self, uri: &str) -> FetchResult<ContestEffect> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn item(&self, uri: &str) -> FetchResult<Item> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn version_group(&self, uri: &str) -> FetchResult<VersionGroup> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn location(&self, uri: &str) -> FetchResult<Location> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn location_area(&self, uri: &str) -> FetchResult<LocationArea> {
+        decode(uri, self.get(uri).await)
+    }
+
+    async fn region(&self, uri: &str) -> FetchResult<Region> {
+        decode(uri, self.get(uri).await)
+    }
+}
+
+fn decode<T>(uri: &str, bytes: FetchResult<Bytes>) -> FetchResult<T>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_slice(&bytes?).map_err(|err| FetchError::Decode {
+        uri: uri.to_string(),
+        message: err.to_string(),
+    })
+}
+
+/// How hard a batch of requests may hit the API.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FetchPolicy {
+    pub max_concurrent_requests: usize,
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for every following one.
+    pub retry_delay: Duration,
+}
+
+impl Default for FetchPolicy {
+    fn default() -> Self {
+        Self {
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            max_retries: 3,
+            retry_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Outcome of a batch fetch: whatever could be loaded, plus an error for every item that couldn't.
+/// Both keep the order of the items they were fetched for.
+#[derive(Debug)]
+pub struct FetchedResources<R> {
+    pub resources: Vec<R>,
+    pub failures: Vec<FetchError>,
+}
+
+pub async fn fetch_external<T, R, F, Fut>(
+    source: &Arc<dyn PokeApiSource>,
+    data: &[T],
+    policy: FetchPolicy,
+    fetcher: F,
+) -> FetchedResources<R>
+where
+    T: Clone + Send + Sync + 'static,
+    R: Send + Debug + 'static,
+    F: Fn(Arc<dyn PokeApiSource>, &T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = FetchResult<R>> + Send + 'static,
+{
+    let mut fetched = FetchedResources {
+        resources: vec![],
+        failures: vec![],
+    };
+    let (tx, mut rx) = mpsc::channel(32);
+    let fetcher = Arc::new(fetcher);
+    let permits = Arc::new(Semaphore::new(policy.max_concurrent_requests.max(1)));
+
+    for (index, item) in data.iter().enumerate() {
+        let source = Arc::clone(source);
+        let fetcher = Arc::clone(&fetcher);
+        let permits = Arc::clone(&permits);
+        let item = item.clone();
+        let fetch = async move {
+            // The semaphore is never closed, so acquiring can't fail
+            let _permit = permits.acquire_owned().await.ok();
+            let result = fetch_with_retries(policy, || fetcher(Arc::clone(&source), &item)).await;
+
+            (index, result)
+        };
+        spawn_fetcher(fetch, tx.clone()).await;
+    }
+
+    drop(tx);
+
+    // Tasks finish in any order, the index puts each result back where its item was
+    let mut results: Vec<Option<FetchResult<R>>> = data.iter().map(|_| None).collect();
+    while let Some((index, result)) = rx.recv().await {
+        results[index] = Some(result);
+    }
+
+    for result in results.into_iter().flatten() {
+        match result {
+            Ok(resource) => fetched.resources.push(resource),
+            Err(err) => fetched.failures.push(err),
+        }
+    }
+
+    fetched
+}
+
+/// Repeats `fetch` while it fails with a transient error, waiting for `Retry-After`
+/// when the server sent one and backing off exponentially otherwise.
+async fn fetch_with_retries<R, F, Fut>(policy: FetchPolicy, fetch: F) -> FetchResult<R>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = FetchResult<R>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match fetch().await {
+            Err(err) if err.is_transient() && attempt < policy.max_retries => {
+                let backoff = policy.retry_delay * 2u32.pow(attempt);
+                sleep(err.retry_after().unwrap_or(backoff)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+async fn spawn_fetcher<T, Fut>(fetch: Fut, tx: Sender<(usize, FetchResult<T>)>)
+where
+    T: Send + Debug + 'static,
+    Fut: Future<Output = (usize, FetchResult<T>)> + Send + 'static,
+{
+    tokio::spawn(async move {
+        // The receiver is only gone once the whole batch was abandoned
+        let _ = tx.send(fetch.await).await;
+    });
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use async_trait::async_trait;
+    use hyper::body::Bytes;
+    use serde_json::Value;
+
+    use crate::error::{FetchError, FetchResult};
+
+    use super::PokeApiSource;
+
+    /// Serves canned JSON keyed by the exact URL, anything else is answered with a 404.
+    /// Queued errors for a URL are returned first, one per request.
+    #[derive(Default)]
+    pub struct FixtureSource {
+        responses: HashMap<String, Bytes>,
+        errors: Mutex<HashMap<String, Vec<FetchError>>>,
+    }
+
+    impl FixtureSource {
+        pub fn with_response(self, uri: &str, body: Value) -> Self {
+            self.with_bytes(uri, body.to_string().into_bytes())
+        }
+
+        pub fn with_bytes(mut self, uri: &str, bytes: Vec<u8>) -> Self {
+            self.responses.insert(uri.to_string(), Bytes::from(bytes));
+            self
+        }
+
+        pub fn with_error(self, uri: &str, error: FetchError) -> Self {
+            self.errors
+                .lock()
+                .unwrap()
+                .entry(uri.to_string())
+                .or_default()
+                .push(error);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl PokeApiSource for FixtureSource {
+        async fn get(&self, uri: &str) -> FetchResult<Bytes> {
+            if let Some(errors) = self.errors.lock().unwrap().get_mut(uri) {
+                if !errors.is_empty() {
+                    return Err(errors.remove(0));
+                }
+            }
+
+            self.responses
+                .get(uri)
+                .cloned()
+                .ok_or_else(|| FetchError::Status {
+                    uri: uri.to_string(),
+                    status: 404,
+                })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use hyper::body::Bytes;
+    use serde_json::json;
+
+    use crate::error::{FetchError, FetchResult};
+
+    use super::{fetch_external, fixture::FixtureSource, FetchPolicy, PokeApiSource};
+
+    /// Echoes the URI back, later for lower `move/{id}`s so requests finish out of order,
+    /// and remembers how many were in flight at once.
+    #[derive(Default)]
+    struct ConcurrencySource {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl PokeApiSource for ConcurrencySource {
+        async fn get(&self, uri: &str) -> FetchResult<Bytes> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+
+            let id: usize = uri.trim_start_matches("move/").parse().unwrap();
+            for _ in id..=10 {
+                tokio::task::yield_now().await;
+            }
+
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(Bytes::from(uri.to_string()))
+        }
+    }
+
+    fn get_stub_policy() -> FetchPolicy {
+        FetchPolicy {
+            max_concurrent_requests: 2,
+            max_retries: 2,
+            retry_delay: Duration::ZERO,
+        }
+    }
+
+    fn get_stub_network_error(uri: &str) -> FetchError {
+        FetchError::Network {
+            uri: uri.to_string(),
+            message: String::from("connection reset"),
+        }
+    }
+
+    #[tokio::test]
+    async fn poke_api_source_decodes_typed_resources() {
+        let source = FixtureSource::default()
+            .with_response("species", json!({ "capture_rate": 190 }))
+            .with_response("broken", json!({ "capture_rate": "many" }));
+
+        assert_eq!(
+            source.species("species").await.unwrap().capture_rate,
+            Some(190)
+        );
+        assert!(matches!(
+            source.species("broken").await,
+            Err(FetchError::Decode { .. })
+        ));
+        assert_eq!(
+            source.species("missing").await.unwrap_err(),
+            FetchError::Status {
+                uri: String::from("missing"),
+                status: 404
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_external_collects_resources() {
+        let source: Arc<dyn PokeApiSource> = Arc::new(
+            FixtureSource::default()
+                .with_response("move/1", json!({ "name": "pound" }))
+                .with_response("move/2", json!({ "name": "karate-chop" })),
+        );
+
+        let fetched = fetch_external(
+            &source,
+            &["move/1", "move/2"],
+            get_stub_policy(),
+            |source, url| {
+                let url = url.to_string();
+                async move { source.pokemon_move(&url).await }
+            },
+        )
+        .await;
+        let moves = fetched
+            .resources
+            .into_iter()
+            .map(|pokemon_move| pokemon_move.name.unwrap())
+            .collect::<Vec<String>>();
+
+        assert_eq!(moves, vec!["pound", "karate-chop"]);
+        assert!(fetched.failures.is_empty());
+    }
+
+    #[tokio::test]
+    async fn fetch_external_reports_failures() {
+        let source: Arc<dyn PokeApiSource> =
+            Arc::new(FixtureSource::default().with_response("move/1", json!({ "name": "pound" })));
+
+        let fetched = fetch_external(
+            &source,
+            &["move/1", "move/3"],
+            get_stub_policy(),
+            |source, url| {
+                let url = url.to_string();
+                async move { source.pokemon_move(&url).await }
+            },
+        )
+        .await;
+
+        assert_eq!(fetched.resources.len(), 1);
+        assert_eq!(
+            fetched.failures,
+            vec![FetchError::Status {
+                uri: String::from("move/3"),
+                status: 404
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_external_retries_transient_errors() {
+        let source: Arc<dyn PokeApiSource> = Arc::new(
+            FixtureSource::default()
+                .with_response("move/1", json!({ "name": "pound" }))
+                .with_error("move/1", get_stub_network_error("move/1"))
+                .with_error(
+                    "move/1",
+                    FetchError::RateLimited {
+                        uri: String::from("move/1"),
+                        retry_after: Some(Duration::ZERO),
+                    },
+                )
+                .with_response("move/2", json!({ "name": "karate-chop" }))
+                .with_error("move/2", get_stub_network_error("move/2"))
+                .with_error("move/2", get_stub_network_error("move/2"))
+                .with_error("move/2", get_stub_network_error("move/2")),
+        );
+
+        let fetched = fetch_external(
+            &source,
+            &["move/1", "move/2", "move/3"],
+            get_stub_policy(),
+            |source, url| {
+                let url = url.to_string();
+                async move { source.pokemon_move(&url).await }
+            },
+        )
+        .await;
+
+        assert_eq!(fetched.resources.len(), 1);
+        assert_eq!(fetched.resources[0].name.as_deref(), Some("pound"));
+        assert_eq!(
+            fetched.failures,
+            vec![
+                get_stub_network_error("move/2"),
+                FetchError::Status {
+                    uri: String::from("move/3"),
+                    status: 404
+                }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_external_keeps_order_and_limits_concurrency() {
+        let concurrency_source = Arc::new(ConcurrencySource::default());
+        let source: Arc<dyn PokeApiSource> = concurrency_source.clone();
+        let urls: Vec<String> = (1..=10).map(|id| format!("move/{}", id)).collect();
+
+        let fetched = fetch_external(&source, &urls, get_stub_policy(), |source, url| {
+            let url = url.to_string();
+            async move { source.get(&url).await }
+        })
+        .await;
+
+        assert_eq!(fetched.resources, urls);
+        assert_eq!(concurrency_source.max_in_flight.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,238 @@
+use png::{Decoder, DecodingError, Transformations};
+use tui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+
+const UPPER_HALF_BLOCK: &str = "▀";
+const LOWER_HALF_BLOCK: &str = "▄";
+/// Pixels more transparent than this are left out, sprites only use fully on or off alpha anyway.
+const MIN_VISIBLE_ALPHA: u8 = 128;
+
+/// A decoded RGBA image, cropped to its visible pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sprite {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<[u8; 4]>,
+}
+
+impl Sprite {
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodingError> {
+        let mut decoder = Decoder::new(bytes);
+        decoder.set_transformations(Transformations::EXPAND | Transformations::STRIP_16);
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let frame_info = reader.next_frame(&mut buffer)?;
+
+        let pixels = buffer[..frame_info.buffer_size()]
+            .chunks(frame_info.color_type.samples())
+            .map(|samples| match *samples {
+                [gray] => [gray, gray, gray, u8::MAX],
+                [gray, alpha] => [gray, gray, gray, alpha],
+                [red, green, blue] => [red, green, blue, u8::MAX],
+                [red, green, blue, alpha, ..] => [red, green, blue, alpha],
+                _ => [0; 4],
+            })
+            .collect();
+
+        Ok(Self {
+            width: frame_info.width as usize,
+            height: frame_info.height as usize,
+            pixels,
+        }
+        .cropped())
+    }
+
+    fn get_visible_pixel(&self, x: usize, y: usize) -> Option<Color> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let [red, green, blue, alpha] = self.pixels[y * self.width + x];
+        if alpha < MIN_VISIBLE_ALPHA {
+            return None;
+        }
+
+        Some(Color::Rgb(red, green, blue))
+    }
+
+    /// PokéAPI sprites are mostly empty canvas around a small Pokemon.
+    fn cropped(self) -> Self {
+        let visible: Vec<(usize, usize)> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|(x, y)| self.get_visible_pixel(*x, *y).is_some())
+            .collect();
+
+        let (min_x, max_x, min_y, max_y) = match (
+            visible.iter().map(|(x, _)| *x).min(),
+            visible.iter().map(|(x, _)| *x).max(),
+            visible.iter().map(|(_, y)| *y).min(),
+            visible.iter().map(|(_, y)| *y).max(),
+        ) {
+            (Some(min_x), Some(max_x), Some(min_y), Some(max_y)) => (min_x, max_x, min_y, max_y),
+            _ => return self,
+        };
+
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+        let pixels = (min_y..=max_y)
+            .flat_map(|y| {
+                let row_start = y * self.width;
+                self.pixels[row_start + min_x..row_start + min_x + width].to_vec()
+            })
+            .collect();
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// Draws a sprite with half blocks, so every cell holds two vertically stacked pixels.
+/// Sprites larger than the area are scaled down, smaller ones are centered.
+pub struct SpriteWidget<'a> {
+    sprite: &'a Sprite,
+}
+
+impl<'a> SpriteWidget<'a> {
+    pub fn new(sprite: &'a Sprite) -> Self {
+        Self { sprite }
+    }
+}
+
+impl Widget for SpriteWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let area_width = area.width as usize;
+        let area_height = area.height as usize * 2;
+        let scale = self
+            .sprite
+            .width
+            .div_ceil(area_width)
+            .max(self.sprite.height.div_ceil(area_height))
+            .max(1);
+        let scaled_width = self.sprite.width.div_ceil(scale);
+        let scaled_height = self.sprite.height.div_ceil(scale);
+        let offset_x = (area_width - scaled_width) / 2;
+        let offset_y = (area_height - scaled_height) / 2;
+
+        let get_pixel = |x: usize, y: usize| {
+            if x < offset_x || y < offset_y {
+                return None;
+            }
+
+            self.sprite
+                .get_visible_pixel((x - offset_x) * scale, (y - offset_y) * scale)
+        };
+
+        for row in 0..area.height {
+            for column in 0..area.width {
+                let top = get_pixel(column as usize, row as usize * 2);
+                let bottom = get_pixel(column as usize, row as usize * 2 + 1);
+                let cell = buf.get_mut(area.x + column, area.y + row);
+
+                match (top, bottom) {
+                    (Some(top), Some(bottom)) => {
+                        cell.set_symbol(UPPER_HALF_BLOCK).set_fg(top).set_bg(bottom);
+                    }
+                    (Some(top), None) => {
+                        cell.set_symbol(UPPER_HALF_BLOCK).set_fg(top);
+                    }
+                    (None, Some(bottom)) => {
+                        cell.set_symbol(LOWER_HALF_BLOCK).set_fg(bottom);
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod fixture {
+    use png::{BitDepth, ColorType, Encoder};
+
+    /// Encodes RGBA `pixels` laid out row by row as a PNG.
+    pub fn get_stub_png(width: u32, height: u32, pixels: &[[u8; 4]]) -> Vec<u8> {
+        let mut bytes = vec![];
+        let mut encoder = Encoder::new(&mut bytes, width, height);
+        encoder.set_color(ColorType::Rgba);
+        encoder.set_depth(BitDepth::Eight);
+
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&pixels.concat()).unwrap();
+        writer.finish().unwrap();
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+
+    use super::{fixture::get_stub_png, Sprite, SpriteWidget};
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+    const CLEAR: [u8; 4] = [0, 0, 0, 0];
+
+    fn get_stub_sprite() -> Sprite {
+        #[rustfmt::skip]
+        let pixels = [
+            CLEAR, CLEAR, CLEAR,
+            CLEAR, RED, BLUE,
+            CLEAR, BLUE, CLEAR,
+            CLEAR, CLEAR, CLEAR,
+        ];
+
+        Sprite::decode(&get_stub_png(3, 4, &pixels)).unwrap()
+    }
+
+    #[test]
+    fn sprite_decode_crops_transparent_margin() {
+        let sprite = get_stub_sprite();
+
+        assert_eq!((sprite.width, sprite.height), (2, 2));
+        assert_eq!(sprite.pixels, vec![RED, BLUE, BLUE, CLEAR]);
+    }
+
+    #[test]
+    fn sprite_decode_rejects_invalid_data() {
+        assert!(Sprite::decode(b"not a png").is_err());
+    }
+
+    #[test]
+    fn sprite_widget_render() {
+        let sprite = get_stub_sprite();
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+
+        SpriteWidget::new(&sprite).render(area, &mut buf);
+
+        let left = buf.get(0, 0);
+        assert_eq!(left.symbol, "▀");
+        assert_eq!(left.fg, Color::Rgb(255, 0, 0));
+        assert_eq!(left.bg, Color::Rgb(0, 0, 255));
+        let right = buf.get(1, 0);
+        assert_eq!(right.symbol, "▀");
+        assert_eq!(right.fg, Color::Rgb(0, 0, 255));
+        assert_eq!(right.bg, Color::Reset);
+    }
+
+    #[test]
+    fn sprite_widget_render_scales_down() {
+        let sprite = get_stub_sprite();
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+
+        SpriteWidget::new(&sprite).render(area, &mut buf);
+
+        let cell = buf.get(0, 0);
+        assert_eq!(cell.symbol, "▀");
+        assert_eq!(cell.fg, Color::Rgb(255, 0, 0));
+    }
+}
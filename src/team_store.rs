@@ -0,0 +1,118 @@
+use std::{
+    env, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use crate::models::Team;
+
+const DATA_DIR_NAME: &str = "pokerust-tui";
+const TEAMS_FILE_NAME: &str = "teams.json";
+
+/// Teams kept as a single JSON file, rewritten whenever one of them changes.
+pub struct TeamStore {
+    path: PathBuf,
+}
+
+impl TeamStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn with_default_location() -> Option<Self> {
+        Self::default_path().map(Self::new)
+    }
+
+    pub fn default_path() -> Option<PathBuf> {
+        env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+            .map(|data_home| data_home.join(DATA_DIR_NAME).join(TEAMS_FILE_NAME))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> io::Result<Vec<Team>> {
+        match fs::read_to_string(&self.path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|err| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid teams file {}: {}", self.path.display(), err),
+                )
+            }),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(vec![]),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, teams: &[Team]) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let contents = serde_json::to_string_pretty(teams)?;
+
+        fs::write(&self.path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use crate::models::{NamedApiResource, Team, TeamMember};
+
+    use super::TeamStore;
+
+    fn get_stub_store_path(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("pokerust-tui-teams-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir.join("teams.json")
+    }
+
+    #[test]
+    fn team_store_load_without_file() {
+        let store = TeamStore::new(get_stub_store_path("missing"));
+
+        assert!(store.load().unwrap().is_empty());
+    }
+
+    #[test]
+    fn team_store_save_and_load() {
+        let store = TeamStore::new(get_stub_store_path("save"));
+        let mut team = Team::new(String::from("team-1"));
+        team.version_group = Some(String::from("x-y"));
+        let mut member = TeamMember::new(
+            NamedApiResource {
+                name: Some(String::from("pikachu")),
+                url: Some(String::from("https://pokeapi.co/api/v2/pokemon/25/")),
+            },
+            vec![String::from("electric")],
+            Some(String::from("static")),
+        );
+        member.item = Some(String::from("light-ball"));
+        member.stat_spread.level = 100;
+        team.add_member(member);
+
+        store.save(&[team]).unwrap();
+        let teams = store.load().unwrap();
+
+        assert_eq!(teams.len(), 1);
+        assert_eq!(teams[0].version_group, Some(String::from("x-y")));
+        assert_eq!(teams[0].members[0].get_name(), "pikachu");
+        assert_eq!(teams[0].members[0].item, Some(String::from("light-ball")));
+        assert_eq!(teams[0].members[0].stat_spread.level, 100);
+    }
+
+    #[test]
+    fn team_store_load_invalid_file() {
+        let path = get_stub_store_path("invalid");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "not json").unwrap();
+
+        assert!(TeamStore::new(path).load().is_err());
+    }
+}
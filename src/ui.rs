@@ -1,4 +1,4 @@
-use std::{io::Stdout, vec};
+use std::{borrow::Cow, io::Stdout, vec};
 
 use rayon::prelude::*;
 use tui::{
@@ -6,77 +6,184 @@ use tui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Row, Table, Wrap},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Paragraph, Row, Table, Wrap},
     Frame,
 };
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    app::{App, CurrentMainPageState, SelectedPart},
-    models::ExtendedPokemonInfo,
-    utils::PrepareForDisplay,
+    app::{
+        App, BasicInfoFocus, CurrentMainPageState, FailedFetch, ListMode, LocationFocus,
+        SelectedPart, StatCalculatorFocus, TypeFocus,
+    },
+    models::{
+        stat_spread::MAX_TOTAL_EVS, team::MAX_TEAM_SIZE, DamageCalculator, ExtendedItemInfo,
+        ExtendedLocationInfo, ExtendedMoveInfo, ExtendedPokemonInfo, PokemonAbilityExt,
+        PokemonTypeExt, SpriteVariant, StatSpread, VersionGroup,
+    },
+    sprite::{Sprite, SpriteWidget},
+    utils::{get_styled_pokemon_type, PrepareForDisplay},
 };
 
 type CrosstermFrame<'a> = Frame<'a, CrosstermBackend<Stdout>>;
 
 pub fn render(frame: &mut CrosstermFrame, app: &mut App) {
     let (list_area, search_area, main_area) = prepare_chunks(frame);
-    let version_group_selection_area = prepare_version_group_selection_area(main_area);
-    let (basic_info_area, right_area) = prepare_main_block_chunks(main_area);
     let (list_style, main_style) = get_styles(app);
 
     render_list(frame, app, list_area, list_style);
     render_search(frame, app, search_area, list_style);
     render_main_block(frame, app, main_area, main_style);
 
-    if app.loading {
-        return;
+    if !app.loading {
+        if app.current_move.is_some() {
+            render_current_move(frame, app, main_area);
+        } else if app.current_ability.is_some() {
+            render_current_ability(frame, app, main_area);
+        } else {
+            match app.list_mode {
+                ListMode::Pokemon => render_current_pokemon(frame, app, main_area),
+                ListMode::Types => render_current_type(frame, app, main_area),
+                ListMode::Items => render_current_item(frame, app, main_area),
+                ListMode::Locations => render_current_location(frame, app, main_area),
+                ListMode::Teams => render_current_team(frame, app, main_area),
+            }
+        }
+    }
+
+    if let Some(failed_fetch) = app.failed_fetch.as_ref() {
+        render_failed_fetch_popup(frame, failed_fetch);
     }
+}
+
+fn render_current_pokemon(frame: &mut CrosstermFrame, app: &mut App, main_area: Rect) {
+    let version_group_selection_area = prepare_version_group_selection_area(main_area);
+    let (basic_info_area, right_area) = prepare_main_block_chunks(main_area);
+    let is_sprite_loading = app.is_sprite_loading();
 
     if let Some(current_pokemon) = app.current_pokemon.as_mut() {
         match app.current_main_page_state {
             CurrentMainPageState::BasicInfo => {
-                let selected_version_group = app
-                    .selected_version_group
-                    .as_ref()
-                    .unwrap()
-                    .name
-                    .as_ref()
-                    .unwrap();
+                let selected_version_group = app.selected_version_group.as_ref().unwrap();
 
                 let basic_info_table = get_renderable_basic_info_table(current_pokemon);
-                let pokemon_stats_table = get_renderable_pokemon_stats_table(current_pokemon);
+                let (type_matchups_paragraph, type_matchups_height) =
+                    get_renderable_type_matchups_paragraph(current_pokemon, basic_info_area.width);
+                let pokemon_stats_table =
+                    get_renderable_pokemon_stats_table(current_pokemon, basic_info_area.width);
                 let (pokemon_held_items_table, held_items_count) =
                     get_renderable_pokemon_held_items_table(
                         current_pokemon,
                         selected_version_group,
+                        &app.basic_info_focus,
                     );
                 let (pokemon_encounters_table, encounters_count) =
                     get_renderable_pokemon_encounters_table(
                         current_pokemon,
                         selected_version_group,
+                        &app.basic_info_focus,
+                    );
+                let (encounter_details_table, encounter_details_count) =
+                    get_renderable_pokemon_encounter_details_table(
+                        current_pokemon,
+                        selected_version_group,
+                        &app.basic_info_focus,
+                        app.pokemon_encounters_state.0.selected(),
                     );
+                let encounter_details_height = if encounter_details_count > 0 {
+                    encounter_details_count as u16 + 2
+                } else {
+                    0
+                };
                 let pokedex_numbers_table = get_renderable_pokedex_numbers_table(current_pokemon);
                 let (abilities_table, abilities_count) =
-                    get_renderable_pokemon_abilities_table(current_pokemon);
-                let (moves_table, moves_count) =
-                    get_renderable_pokemon_moves_table(current_pokemon, selected_version_group);
+                    get_renderable_pokemon_abilities_table(current_pokemon, &app.basic_info_focus);
+                let (evolution_chain_table, evolution_stages_count) =
+                    get_renderable_evolution_chain_table(current_pokemon, &app.basic_info_focus);
+                let (forms_table, forms_count) =
+                    get_renderable_pokemon_forms_table(current_pokemon, &app.basic_info_focus);
+                let (moves_table, moves_count) = get_renderable_pokemon_moves_table(
+                    current_pokemon,
+                    &selected_version_group.get_name_or_stub(),
+                    &app.basic_info_focus,
+                );
                 app.rendered_moves_count = Some(moves_count);
 
-                let (abilities_area, encounters_area, moves_area) = prepare_main_block_right_chunks(
+                let (pokedex_entry_paragraph, pokedex_entry_height) =
+                    get_renderable_pokedex_entry_paragraph(
+                        current_pokemon,
+                        selected_version_group,
+                        right_area.width,
+                    );
+
+                let (
+                    pokedex_entry_area,
+                    abilities_area,
+                    encounters_area,
+                    evolution_chain_area,
+                    forms_area,
+                    moves_area,
+                ) = prepare_main_block_right_chunks(
                     right_area,
-                    encounters_count as u16,
+                    pokedex_entry_height,
+                    encounters_count as u16 + encounter_details_height,
                     abilities_count as u16,
+                    evolution_stages_count as u16,
+                    forms_count as u16,
+                );
+                let (
+                    sprite_area,
+                    basic_info_area,
+                    type_matchups_area,
+                    pokemon_stats_area,
+                    held_items_area,
+                    pokedex_numbers_area,
+                ) = prepare_basic_info_chunks(
+                    basic_info_area,
+                    type_matchups_height,
+                    held_items_count as u16,
                 );
-                let (basic_info_area, pokemon_stats_area, held_items_area, pokedex_numbers_area) =
-                    prepare_basic_info_chunks(basic_info_area, held_items_count as u16);
 
+                render_sprite(
+                    frame,
+                    app.sprite.as_ref(),
+                    app.sprite_variant,
+                    is_sprite_loading,
+                    sprite_area,
+                );
                 frame.render_widget(basic_info_table, basic_info_area);
+                frame.render_widget(type_matchups_paragraph, type_matchups_area);
                 frame.render_widget(pokemon_stats_table, pokemon_stats_area);
                 frame.render_widget(pokedex_numbers_table, pokedex_numbers_area);
-                frame.render_widget(pokemon_held_items_table, held_items_area);
-                frame.render_widget(pokemon_encounters_table, encounters_area);
-                frame.render_widget(abilities_table, abilities_area);
+                frame.render_stateful_widget(
+                    pokemon_held_items_table,
+                    held_items_area,
+                    &mut app.pokemon_held_items_state.0,
+                );
+                let (encounters_area, encounter_details_area) =
+                    prepare_encounters_chunks(encounters_area, encounter_details_height);
+                frame.render_stateful_widget(
+                    pokemon_encounters_table,
+                    encounters_area,
+                    &mut app.pokemon_encounters_state.0,
+                );
+                frame.render_widget(encounter_details_table, encounter_details_area);
+                frame.render_widget(pokedex_entry_paragraph, pokedex_entry_area);
+                frame.render_stateful_widget(
+                    abilities_table,
+                    abilities_area,
+                    &mut app.pokemon_abilities_state.0,
+                );
+                frame.render_stateful_widget(
+                    evolution_chain_table,
+                    evolution_chain_area,
+                    &mut app.evolution_chain_state.0,
+                );
+                frame.render_stateful_widget(
+                    forms_table,
+                    forms_area,
+                    &mut app.pokemon_forms_state.0,
+                );
                 frame.render_stateful_widget(
                     moves_table,
                     moves_area,
@@ -86,10 +193,334 @@ pub fn render(frame: &mut CrosstermFrame, app: &mut App) {
             CurrentMainPageState::VersionGroupSelection => {
                 render_version_groups_selection_list(frame, app, version_group_selection_area)
             }
+            CurrentMainPageState::StatCalculator => {
+                let (settings_area, stats_area) =
+                    prepare_stat_calculator_chunks(version_group_selection_area);
+
+                frame.render_widget(
+                    get_renderable_stat_calculator_settings_paragraph(
+                        current_pokemon,
+                        &app.stat_spread,
+                    ),
+                    settings_area,
+                );
+                frame.render_stateful_widget(
+                    get_renderable_stat_calculator_table(
+                        current_pokemon,
+                        &app.stat_spread,
+                        &app.stat_calculator_focus,
+                    ),
+                    stats_area,
+                    &mut app.stat_calculator_state.0,
+                );
+            }
+            CurrentMainPageState::Comparison => {
+                render_comparison(frame, app, version_group_selection_area)
+            }
+            CurrentMainPageState::DamageCalculator => {
+                if let Some(damage_calculator) = app.damage_calculator.as_ref() {
+                    let (settings_area, result_area) =
+                        prepare_damage_calculator_chunks(version_group_selection_area);
+                    let mut result_lines = damage_calculator.get_renderable_result();
+                    result_lines.push(Spans::from(Span::styled(
+                        "Up/Down: setting, +/-: change, Esc: back",
+                        Style::default().fg(Color::DarkGray),
+                    )));
+
+                    frame.render_stateful_widget(
+                        get_renderable_damage_settings_table(damage_calculator),
+                        settings_area,
+                        &mut app.damage_settings_state.0,
+                    );
+                    frame.render_widget(
+                        get_renderable_titled_paragraph("\u{A0}Result", result_lines),
+                        result_area,
+                    );
+                }
+            }
         }
     }
 }
 
+fn render_comparison(frame: &mut CrosstermFrame, app: &mut App, area: Rect) {
+    let selected_version_group = app
+        .selected_version_group
+        .as_ref()
+        .map(|version_group| version_group.get_name_or_stub())
+        .unwrap_or_default();
+
+    if let Some(comparison) = app.comparison.as_ref() {
+        let (overview_area, shared_moves_area, unique_moves_area) =
+            prepare_comparison_chunks(area, comparison.get_overview_height());
+        let mut widths = vec![Constraint::Length(10)];
+        widths.extend(
+            comparison
+                .pokemon
+                .iter()
+                .map(|_| Constraint::Ratio(1, comparison.pokemon.len() as u32)),
+        );
+        let mut overview_header = vec![Span::raw("")];
+        overview_header.extend(comparison.get_renderable_names());
+
+        let overview_table = Table::new(comparison.get_renderable_overview())
+            .header(Row::new(overview_header))
+            .block(Block::default().title(Spans::from(Span::styled(
+                format!(
+                    "\u{A0}Comparison in {}",
+                    selected_version_group.clone().split_capitalize()
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            ))))
+            .widths(&widths)
+            .column_spacing(1);
+        let unique_moves_table =
+            Table::new(comparison.get_renderable_unique_moves(&selected_version_group))
+                .header(Row::new(comparison.get_renderable_names()))
+                .block(
+                    Block::default().title(get_focusable_table_title("\u{A0}Unique Moves", true)),
+                )
+                .widths(&widths[1..])
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                .column_spacing(1);
+
+        frame.render_widget(overview_table, overview_area);
+        frame.render_widget(
+            get_renderable_titled_paragraph(
+                format!(
+                    "\u{A0}Shared Moves ({})",
+                    comparison.get_shared_moves(&selected_version_group).len()
+                ),
+                comparison.get_renderable_shared_moves(&selected_version_group),
+            ),
+            shared_moves_area,
+        );
+        frame.render_stateful_widget(
+            unique_moves_table,
+            unique_moves_area,
+            &mut app.comparison_moves_state.0,
+        );
+    }
+}
+
+fn render_current_type(frame: &mut CrosstermFrame, app: &mut App, main_area: Rect) {
+    if let Some(current_type) = app.current_type.as_ref() {
+        let type_area = prepare_version_group_selection_area(main_area);
+        let (damage_relations_paragraph, damage_relations_height) =
+            get_renderable_damage_relations_paragraph(current_type, type_area.width);
+        let type_pokemon_table = get_renderable_type_pokemon_table(current_type, &app.type_focus);
+        let type_moves_table = get_renderable_type_moves_table(current_type, &app.type_focus);
+        let (damage_relations_area, type_pokemon_area, type_moves_area) =
+            prepare_type_chunks(type_area, damage_relations_height);
+
+        frame.render_widget(damage_relations_paragraph, damage_relations_area);
+        frame.render_stateful_widget(
+            type_pokemon_table,
+            type_pokemon_area,
+            &mut app.type_pokemon_state.0,
+        );
+        frame.render_stateful_widget(
+            type_moves_table,
+            type_moves_area,
+            &mut app.type_moves_state.0,
+        );
+    }
+}
+
+fn render_current_move(frame: &mut CrosstermFrame, app: &mut App, main_area: Rect) {
+    if let Some(current_move) = app.current_move.as_ref() {
+        let move_area = prepare_version_group_selection_area(main_area);
+        let (details_table, details_count) = get_renderable_move_details_table(current_move);
+        let (details_area, effects_area, flavor_texts_area, machines_area, learners_area) =
+            prepare_move_chunks(move_area, details_count as u16);
+
+        frame.render_widget(details_table, details_area);
+        frame.render_widget(
+            get_renderable_titled_paragraph("\u{A0}Effect", current_move.get_renderable_effects()),
+            effects_area,
+        );
+        frame.render_widget(
+            get_renderable_titled_paragraph(
+                "\u{A0}Descriptions",
+                current_move.get_renderable_flavor_texts(),
+            ),
+            flavor_texts_area,
+        );
+        frame.render_widget(
+            get_renderable_titled_paragraph(
+                "\u{A0}Machines",
+                current_move.get_renderable_machines(),
+            ),
+            machines_area,
+        );
+        frame.render_stateful_widget(
+            get_renderable_move_learners_table(current_move),
+            learners_area,
+            &mut app.move_learners_state.0,
+        );
+    }
+}
+
+fn render_current_ability(frame: &mut CrosstermFrame, app: &mut App, main_area: Rect) {
+    if let Some(current_ability) = app.current_ability.as_ref() {
+        let ability_area = prepare_version_group_selection_area(main_area);
+        let (details_area, flavor_texts_area, effect_changes_area, pokemon_area) =
+            prepare_ability_chunks(ability_area);
+
+        frame.render_widget(
+            get_renderable_titled_paragraph(
+                current_ability.get_renderable_name(),
+                current_ability.get_renderable_details(),
+            ),
+            details_area,
+        );
+        frame.render_widget(
+            get_renderable_titled_paragraph(
+                "\u{A0}Descriptions",
+                current_ability.get_renderable_flavor_texts(),
+            ),
+            flavor_texts_area,
+        );
+        frame.render_widget(
+            get_renderable_titled_paragraph(
+                "\u{A0}Effect Changes",
+                current_ability.get_renderable_effect_changes(),
+            ),
+            effect_changes_area,
+        );
+        frame.render_stateful_widget(
+            get_renderable_ability_pokemon_table(current_ability),
+            pokemon_area,
+            &mut app.ability_pokemon_state.0,
+        );
+    }
+}
+
+fn render_current_item(frame: &mut CrosstermFrame, app: &mut App, main_area: Rect) {
+    if let Some(current_item) = app.current_item.as_ref() {
+        let item_area = prepare_version_group_selection_area(main_area);
+        let (details_table, details_count) = get_renderable_item_details_table(current_item);
+        let (sprite_area, details_area, effects_area, holders_area, machines_area) =
+            prepare_item_chunks(item_area, details_count as u16);
+
+        match current_item.sprite.as_ref() {
+            Some(sprite) => frame.render_widget(SpriteWidget::new(sprite), sprite_area),
+            None => frame.render_widget(
+                Paragraph::new("No sprite").alignment(Alignment::Center),
+                sprite_area,
+            ),
+        }
+        frame.render_widget(details_table, details_area);
+        frame.render_widget(
+            get_renderable_titled_paragraph(
+                "\u{A0}Effect",
+                current_item.item.get_renderable_effects(),
+            ),
+            effects_area,
+        );
+        frame.render_stateful_widget(
+            get_renderable_item_holders_table(current_item),
+            holders_area,
+            &mut app.item_holders_state.0,
+        );
+        frame.render_widget(
+            get_renderable_titled_paragraph(
+                "\u{A0}Machines",
+                current_item.get_renderable_machines(),
+            ),
+            machines_area,
+        );
+    }
+}
+
+fn render_current_location(frame: &mut CrosstermFrame, app: &mut App, main_area: Rect) {
+    if let Some(current_location) = app.current_location.as_ref() {
+        let location_area = prepare_version_group_selection_area(main_area);
+        let (title_area, areas_area, encounters_area) = prepare_location_chunks(location_area);
+
+        frame.render_widget(
+            get_renderable_titled_paragraph(current_location.get_renderable_title(), vec![]),
+            title_area,
+        );
+        frame.render_stateful_widget(
+            get_renderable_location_areas_table(current_location, &app.location_focus),
+            areas_area,
+            &mut app.location_areas_state.0,
+        );
+        frame.render_stateful_widget(
+            get_renderable_location_encounters_table(current_location, &app.location_focus),
+            encounters_area,
+            &mut app.location_encounters_state.0,
+        );
+    }
+}
+
+fn render_current_team(frame: &mut CrosstermFrame, app: &mut App, main_area: Rect) {
+    let team_area = prepare_version_group_selection_area(main_area);
+    let team = match app.get_current_team() {
+        Some(team) => team,
+        None => {
+            frame.render_widget(
+                get_renderable_titled_paragraph(
+                    "\u{A0}No team selected",
+                    vec![Spans::from(
+                        String::from("n: New team, Enter on the list: Open a team")
+                            .append_padding(),
+                    )],
+                ),
+                team_area,
+            );
+            return;
+        }
+    };
+    let (title_area, members_area, coverage_area, shared_weaknesses_area) =
+        prepare_team_chunks(team_area);
+    let (coverage, shared_weaknesses) = match app.team_types.is_empty() {
+        true => (vec![], vec![]),
+        false => (
+            team.get_renderable_coverage(&app.team_types),
+            team.get_renderable_shared_weaknesses(&app.team_types),
+        ),
+    };
+    let version_group = team
+        .version_group
+        .clone()
+        .map(|version_group| format!(" in {}", version_group.split_capitalize()))
+        .unwrap_or_default();
+
+    frame.render_widget(
+        get_renderable_titled_paragraph(
+            format!(
+                "\u{A0}{} ({}/{}){}",
+                team.name.clone().split_capitalize(),
+                team.members.len(),
+                MAX_TEAM_SIZE,
+                version_group
+            ),
+            vec![Spans::from(Span::styled(
+                String::from(
+                    "t: Add or remove a Pokemon on its page, a: Give it a move, ability or item, x: Remove, d: Delete team",
+                )
+                .append_padding(),
+                Style::default().fg(Color::DarkGray),
+            ))],
+        ),
+        title_area,
+    );
+    frame.render_stateful_widget(
+        get_renderable_team_members_table(team.get_renderable_members()),
+        members_area,
+        &mut app.team_members_state.0,
+    );
+    frame.render_widget(
+        get_renderable_titled_paragraph("\u{A0}Coverage", coverage),
+        coverage_area,
+    );
+    frame.render_widget(
+        get_renderable_titled_paragraph("\u{A0}Shared Weaknesses", shared_weaknesses),
+        shared_weaknesses_area,
+    );
+}
+
 fn prepare_chunks(frame: &CrosstermFrame) -> (Rect, Rect, Rect) {
     let main_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -117,14 +548,26 @@ fn prepare_main_block_chunks(area: Rect) -> (Rect, Rect) {
 
 fn prepare_main_block_right_chunks(
     area: Rect,
+    pokedex_entry_height: u16,
     encounters_count: u16,
     abilities_count: u16,
-) -> (Rect, Rect, Rect) {
+    evolution_stages_count: u16,
+    forms_count: u16,
+) -> (Rect, Rect, Rect, Rect, Rect, Rect) {
+    let evolution_chain_height = if evolution_stages_count > 0 {
+        evolution_stages_count + 3
+    } else {
+        0
+    };
+    let forms_height = if forms_count > 0 { forms_count + 2 } else { 0 };
     let main_block_chunks = Layout::default()
         .constraints(
             [
+                Constraint::Length(pokedex_entry_height),
                 Constraint::Length(abilities_count + 3),
                 Constraint::Length(encounters_count + 3),
+                Constraint::Length(evolution_chain_height),
+                Constraint::Length(forms_height),
                 Constraint::Percentage(90),
             ]
             .as_ref(),
@@ -136,6 +579,204 @@ fn prepare_main_block_right_chunks(
         main_block_chunks[0],
         main_block_chunks[1],
         main_block_chunks[2],
+        main_block_chunks[3],
+        main_block_chunks[4],
+        main_block_chunks[5],
+    )
+}
+
+/// The details of the highlighted encounter open right under the encounters table.
+fn prepare_encounters_chunks(area: Rect, encounter_details_height: u16) -> (Rect, Rect) {
+    let encounters_chunks = Layout::default()
+        .constraints(
+            [
+                Constraint::Length(area.height.saturating_sub(encounter_details_height)),
+                Constraint::Length(encounter_details_height),
+            ]
+            .as_ref(),
+        )
+        .direction(Direction::Vertical)
+        .split(area);
+
+    (encounters_chunks[0], encounters_chunks[1])
+}
+
+fn prepare_type_chunks(area: Rect, damage_relations_height: u16) -> (Rect, Rect, Rect) {
+    let type_chunks = Layout::default()
+        .constraints(
+            [
+                Constraint::Length(damage_relations_height),
+                Constraint::Percentage(100),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+    let tables_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(type_chunks[1]);
+
+    (type_chunks[0], tables_chunks[0], tables_chunks[1])
+}
+
+/// Details and effects on the left, texts and machines in the middle, learners on the right.
+fn prepare_move_chunks(area: Rect, details_count: u16) -> (Rect, Rect, Rect, Rect, Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(40),
+                Constraint::Percentage(35),
+                Constraint::Percentage(25),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+    let left_chunks = Layout::default()
+        .constraints(
+            [
+                Constraint::Length(details_count + 2),
+                Constraint::Percentage(100),
+            ]
+            .as_ref(),
+        )
+        .split(columns[0]);
+    let middle_chunks = Layout::default()
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)].as_ref())
+        .split(columns[1]);
+
+    (
+        left_chunks[0],
+        left_chunks[1],
+        middle_chunks[0],
+        middle_chunks[1],
+        columns[2],
+    )
+}
+
+/// Effect, descriptions and effect changes on the left, the Pokemon that have it on the right.
+fn prepare_ability_chunks(area: Rect) -> (Rect, Rect, Rect, Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
+        .split(area);
+    let left_chunks = Layout::default()
+        .constraints(
+            [
+                Constraint::Percentage(35),
+                Constraint::Percentage(45),
+                Constraint::Percentage(20),
+            ]
+            .as_ref(),
+        )
+        .split(columns[0]);
+
+    (left_chunks[0], left_chunks[1], left_chunks[2], columns[1])
+}
+
+/// Sprite, details and effect on the left, holders and machines on the right.
+fn prepare_item_chunks(area: Rect, details_count: u16) -> (Rect, Rect, Rect, Rect, Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(area);
+    let left_chunks = Layout::default()
+        .constraints(
+            [
+                Constraint::Length(8),
+                Constraint::Length(details_count + 2),
+                Constraint::Percentage(100),
+            ]
+            .as_ref(),
+        )
+        .split(columns[0]);
+    let right_chunks = Layout::default()
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
+        .split(columns[1]);
+
+    (
+        left_chunks[0],
+        left_chunks[1],
+        left_chunks[2],
+        right_chunks[0],
+        right_chunks[1],
+    )
+}
+
+/// The location's name on top, its areas on the left and the encounters of one area on the right.
+fn prepare_location_chunks(area: Rect) -> (Rect, Rect, Rect) {
+    let location_chunks = Layout::default()
+        .constraints([Constraint::Length(2), Constraint::Percentage(100)].as_ref())
+        .split(area);
+    let tables_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(25), Constraint::Percentage(75)].as_ref())
+        .split(location_chunks[1]);
+
+    (location_chunks[0], tables_chunks[0], tables_chunks[1])
+}
+
+/// Level and nature on top, the stats under them.
+fn prepare_stat_calculator_chunks(area: Rect) -> (Rect, Rect) {
+    let stat_calculator_chunks = Layout::default()
+        .constraints([Constraint::Length(5), Constraint::Percentage(100)].as_ref())
+        .split(area);
+
+    (stat_calculator_chunks[0], stat_calculator_chunks[1])
+}
+
+/// Settings on the left, the result on the right.
+fn prepare_damage_calculator_chunks(area: Rect) -> (Rect, Rect) {
+    let damage_calculator_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(48), Constraint::Min(0)].as_ref())
+        .split(area);
+
+    (damage_calculator_chunks[0], damage_calculator_chunks[1])
+}
+
+/// The overview takes what it needs, the moves share what's left.
+fn prepare_comparison_chunks(area: Rect, overview_height: u16) -> (Rect, Rect, Rect) {
+    let comparison_chunks = Layout::default()
+        .constraints(
+            [
+                Constraint::Length(overview_height + 3),
+                Constraint::Length(6),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+
+    (
+        comparison_chunks[0],
+        comparison_chunks[1],
+        comparison_chunks[2],
+    )
+}
+
+/// The members take two lines each, coverage and shared weaknesses sit side by side under them.
+fn prepare_team_chunks(area: Rect) -> (Rect, Rect, Rect, Rect) {
+    let team_chunks = Layout::default()
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(MAX_TEAM_SIZE as u16 * 2 + 2),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+    let matchups_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(team_chunks[2]);
+
+    (
+        team_chunks[0],
+        team_chunks[1],
+        matchups_chunks[0],
+        matchups_chunks[1],
     )
 }
 
@@ -149,12 +790,18 @@ fn prepare_version_group_selection_area(area: Rect) -> Rect {
     main_block_chunks[0]
 }
 
-fn prepare_basic_info_chunks(area: Rect, held_items_count: u16) -> (Rect, Rect, Rect, Rect) {
+fn prepare_basic_info_chunks(
+    area: Rect,
+    type_matchups_height: u16,
+    held_items_count: u16,
+) -> (Rect, Rect, Rect, Rect, Rect, Rect) {
     let left_block_chunks = Layout::default()
         .constraints(
             [
+                Constraint::Length(12),
                 Constraint::Length(13),
-                Constraint::Length(8),
+                Constraint::Length(type_matchups_height),
+                Constraint::Length(9),
                 Constraint::Length(held_items_count + 2),
                 Constraint::Percentage(90),
             ]
@@ -167,9 +814,41 @@ fn prepare_basic_info_chunks(area: Rect, held_items_count: u16) -> (Rect, Rect,
         left_block_chunks[1],
         left_block_chunks[2],
         left_block_chunks[3],
+        left_block_chunks[4],
+        left_block_chunks[5],
     )
 }
 
+fn render_sprite(
+    frame: &mut CrosstermFrame,
+    sprite: Option<&Sprite>,
+    sprite_variant: SpriteVariant,
+    is_sprite_loading: bool,
+    area: Rect,
+) {
+    let block = Block::default().title(Spans::from(Span::styled(
+        format!("\u{A0}Sprite: {} (s)", sprite_variant.get_renderable_name()),
+        Style::default().add_modifier(Modifier::BOLD),
+    )));
+    let sprite_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    match sprite {
+        Some(sprite) => frame.render_widget(SpriteWidget::new(sprite), sprite_area),
+        None => {
+            let text = if is_sprite_loading {
+                "Loading..."
+            } else {
+                "No sprite"
+            };
+            frame.render_widget(
+                Paragraph::new(text).alignment(Alignment::Center),
+                sprite_area,
+            );
+        }
+    }
+}
+
 fn render_version_groups_selection_list(frame: &mut CrosstermFrame, app: &mut App, area: Rect) {
     let version_groups_to_render: Vec<ListItem> = app
         .version_groups
@@ -199,22 +878,49 @@ fn render_version_groups_selection_list(frame: &mut CrosstermFrame, app: &mut Ap
 }
 
 fn render_list(frame: &mut CrosstermFrame, app: &mut App, area: Rect, style: Style) {
-    let pokemon_items_to_render: Vec<ListItem> = app
-        .pokemon_list
+    let list_mode = app.list_mode;
+    let items_to_render: Vec<ListItem> = app
+        .get_active_list()
         .items_to_render
         .par_iter()
-        .map(|pokemon| {
-            let name: &str = pokemon.name.as_ref().unwrap().as_ref();
-            let name = name.to_string().split_capitalize();
+        .map(|item| {
+            let name = item.get_name_or_stub();
 
-            ListItem::new(name)
+            match list_mode {
+                // Pokemon marked for comparison stand out
+                ListMode::Pokemon if app.is_marked(&name) => ListItem::new(Span::styled(
+                    name.split_capitalize(),
+                    Style::default().fg(Color::Green),
+                )),
+                ListMode::Pokemon => ListItem::new(name.split_capitalize()),
+                ListMode::Types => ListItem::new(Spans::from(get_styled_pokemon_type(name))),
+                ListMode::Items | ListMode::Locations | ListMode::Teams => {
+                    ListItem::new(name.split_capitalize())
+                }
+            }
         })
         .collect();
+    let title = match (list_mode, app.current_region.as_ref()) {
+        (ListMode::Pokemon, _) => String::from("Pokemon list (Tab: Types)"),
+        (ListMode::Types, _) => String::from("Type list (Tab: Items)"),
+        (ListMode::Items, _) => String::from("Item list (Tab: Locations)"),
+        (ListMode::Locations, None) => String::from("Region list (Tab: Teams)"),
+        (ListMode::Locations, Some(region)) => format!(
+            "{} locations (Esc: Regions, Tab: Teams)",
+            region.get_name_or_stub().split_capitalize()
+        ),
+        (ListMode::Teams, _) => String::from("Team list (Tab: Pokemon)"),
+    };
+    // Teams come from the data dir, everything else from the startup lists
+    let title = match app.is_lists_loading() && list_mode != ListMode::Teams {
+        true => format!("{} Loading...", title),
+        false => title,
+    };
 
-    let list = List::new(pokemon_items_to_render)
+    let list = List::new(items_to_render)
         .block(
             Block::default()
-                .title("Pokemon list")
+                .title(title)
                 .borders(Borders::ALL)
                 .border_style(style)
                 .border_type(BorderType::Rounded),
@@ -222,7 +928,7 @@ fn render_list(frame: &mut CrosstermFrame, app: &mut App, area: Rect, style: Sty
         .highlight_style(Style::default().add_modifier(Modifier::BOLD))
         .highlight_symbol("> ");
 
-    frame.render_stateful_widget(list, area, &mut app.pokemon_list.state);
+    frame.render_stateful_widget(list, area, &mut app.get_active_list_mut().state);
 }
 
 fn render_search(frame: &mut CrosstermFrame, app: &App, area: Rect, style: Style) {
@@ -242,13 +948,19 @@ fn render_search(frame: &mut CrosstermFrame, app: &App, area: Rect, style: Style
 }
 
 fn render_main_block(frame: &mut CrosstermFrame, app: &App, area: Rect, style: Style) {
-    let main_block = Block::default()
+    let mut main_block = Block::default()
         .borders(Borders::ALL)
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded)
         .border_style(style);
+    if let Some(team_status) = app.team_status.as_ref() {
+        main_block = main_block.title(Span::styled(
+            format!(" {} ", team_status),
+            Style::default().fg(Color::Red),
+        ));
+    }
 
-    if app.current_pokemon.is_none() || app.loading {
+    if !app.has_current_item() || app.loading {
         let paragraph = Paragraph::new(get_main_block_text(frame, app))
             .block(main_block)
             .alignment(Alignment::Center)
@@ -260,6 +972,43 @@ fn render_main_block(frame: &mut CrosstermFrame, app: &App, area: Rect, style: S
     }
 }
 
+fn render_failed_fetch_popup(frame: &mut CrosstermFrame, failed_fetch: &FailedFetch) {
+    let area = prepare_popup_area(frame.size(), 60, 7);
+    let text = vec![
+        Spans::from(failed_fetch.error.to_string()),
+        Spans::from(""),
+        Spans::from(Span::styled(
+            "Press \"r\" to retry or \"Esc\" to dismiss.",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+    ];
+    let popup = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title("Something went wrong")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red))
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+fn prepare_popup_area(area: Rect, percent_width: u16, height: u16) -> Rect {
+    let width = area.width * percent_width / 100;
+    let height = height.min(area.height);
+
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
 fn get_styles(app: &App) -> (Style, Style) {
     let highlighted = Style::default().fg(Color::Blue);
     let default = Style::default();
@@ -269,16 +1018,111 @@ fn get_styles(app: &App) -> (Style, Style) {
     }
 }
 
-fn get_renderable_pokemon_stats_table(current_pokemon: &ExtendedPokemonInfo) -> Table {
-    Table::new(current_pokemon.pokemon.get_renderable_stats())
+/// The bars take whatever the name, value and EV yield columns leave.
+fn get_renderable_pokemon_stats_table<'a>(
+    current_pokemon: &ExtendedPokemonInfo,
+    width: u16,
+) -> Table<'a> {
+    let bar_width = width.saturating_sub(6 + 4 + 3 + 3);
+
+    Table::new(current_pokemon.pokemon.get_renderable_stats(bar_width))
         .block(Block::default().title(Spans::from(Span::styled(
-            "\u{A0}Base Stats",
+            "\u{A0}Base Stats (c: Calculator)",
             Style::default().add_modifier(Modifier::BOLD),
         ))))
-        .widths(&[Constraint::Percentage(60), Constraint::Percentage(40)])
+        .widths(&[
+            Constraint::Length(6),
+            Constraint::Length(4),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .column_spacing(1)
+}
+
+fn get_renderable_stat_calculator_settings_paragraph<'a>(
+    current_pokemon: &ExtendedPokemonInfo,
+    stat_spread: &StatSpread,
+) -> Paragraph<'a> {
+    let paint_blue = |string: &'a str| Span::styled(string, Style::default().fg(Color::Blue));
+    let lines = vec![
+        Spans::from(vec![
+            paint_blue("\u{A0}Level "),
+            Span::raw(format!("{} ([ ]) ", stat_spread.level)),
+            paint_blue("Nature "),
+            Span::raw(format!("{} (n) ", stat_spread.nature.get_renderable_name())),
+            paint_blue("EVs "),
+            Span::raw(format!("{}/{}", stat_spread.get_total_evs(), MAX_TOTAL_EVS)),
+        ]),
+        Spans::from(""),
+        Spans::from(Span::styled(
+            "\u{A0}Up/Down: stat, Tab: IVs or EVs, +/-: change, Esc: back",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    get_renderable_titled_paragraph(
+        format!(
+            "\u{A0}Stat Calculator: {}",
+            current_pokemon.pokemon.get_renderable_name().content
+        ),
+        lines,
+    )
+}
+
+fn get_renderable_damage_settings_table(damage_calculator: &DamageCalculator) -> Table<'static> {
+    Table::new(damage_calculator.get_renderable_settings())
+        .block(Block::default().title(get_focusable_table_title("\u{A0}Damage Calculator", true)))
+        .widths(&[Constraint::Length(24), Constraint::Length(22)])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
         .column_spacing(1)
 }
 
+fn get_renderable_stat_calculator_table<'a>(
+    current_pokemon: &ExtendedPokemonInfo,
+    stat_spread: &StatSpread,
+    focus: &StatCalculatorFocus,
+) -> Table<'a> {
+    let focused_style = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let (iv_style, ev_style) = match focus {
+        StatCalculatorFocus::Ivs => (focused_style, Style::default()),
+        StatCalculatorFocus::Evs => (Style::default(), focused_style),
+    };
+
+    Table::new(
+        current_pokemon
+            .pokemon
+            .get_renderable_calculated_stats(stat_spread),
+    )
+    .header(
+        Row::new(vec![
+            Span::raw("\u{A0}Stat"),
+            Span::raw("Base"),
+            Span::styled("IV", iv_style),
+            Span::styled("EV", ev_style),
+            Span::raw("Nat."),
+            Span::raw("Actual"),
+            Span::raw("Lv. 50"),
+            Span::raw("Lv. 100"),
+        ])
+        .style(Style::default().fg(Color::Blue)),
+    )
+    .block(Block::default().title(get_focusable_table_title("\u{A0}Stats", true)))
+    .widths(&[
+        Constraint::Length(6),
+        Constraint::Length(5),
+        Constraint::Length(4),
+        Constraint::Length(4),
+        Constraint::Length(5),
+        Constraint::Length(7),
+        Constraint::Length(9),
+        Constraint::Length(9),
+    ])
+    .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+    .column_spacing(1)
+}
+
 fn get_renderable_basic_info_table(current_pokemon: &ExtendedPokemonInfo) -> Table {
     Table::new(current_pokemon.get_renderable_basic_info_items())
         .block(Block::default().title(Spans::from(Span::styled(
@@ -291,22 +1135,24 @@ fn get_renderable_basic_info_table(current_pokemon: &ExtendedPokemonInfo) -> Tab
 
 fn get_renderable_pokemon_held_items_table<'a>(
     current_pokemon: &'a ExtendedPokemonInfo,
-    selected_version_group: &str,
+    selected_version_group: &VersionGroup,
+    focus: &BasicInfoFocus,
 ) -> (Table<'a>, usize) {
     let held_items = current_pokemon
         .pokemon
         .get_renderable_held_items(selected_version_group);
     let held_items_count = held_items.len();
     let table = Table::new(held_items)
-        .block(Block::default().title(Spans::from(Span::styled(
+        .block(Block::default().title(get_focusable_table_title(
             "\u{A0}Held items",
-            Style::default().add_modifier(Modifier::BOLD),
-        ))))
+            matches!(focus, BasicInfoFocus::HeldItems),
+        )))
         .widths(&[
             Constraint::Length(14),
             Constraint::Length(6),
             Constraint::Percentage(100),
         ])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
         .column_spacing(1);
 
     (table, held_items_count)
@@ -314,15 +1160,16 @@ fn get_renderable_pokemon_held_items_table<'a>(
 
 fn get_renderable_pokemon_encounters_table<'a>(
     current_pokemon: &'a ExtendedPokemonInfo,
-    selected_version_group: &str,
+    selected_version_group: &VersionGroup,
+    focus: &BasicInfoFocus,
 ) -> (Table<'a>, usize) {
     let encounters = current_pokemon.get_renderable_encounters(selected_version_group);
     let encounters_count = encounters.len();
     let table = Table::new(encounters)
-        .block(Block::default().title(Spans::from(Span::styled(
+        .block(Block::default().title(get_focusable_table_title(
             "\u{A0}Encounters",
-            Style::default().add_modifier(Modifier::BOLD),
-        ))))
+            matches!(focus, BasicInfoFocus::Encounters),
+        )))
         .header(
             Row::new(vec![
                 "\u{A0}Location",
@@ -340,12 +1187,45 @@ fn get_renderable_pokemon_encounters_table<'a>(
             Constraint::Percentage(15),
             Constraint::Percentage(15),
         ])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
         .column_spacing(1);
 
     (table, encounters_count)
 }
 
-fn get_renderable_pokemon_abilities_table(current_pokemon: &ExtendedPokemonInfo) -> (Table, usize) {
+/// Only shown while an encounter is highlighted, there's no room to list them all.
+fn get_renderable_pokemon_encounter_details_table<'a>(
+    current_pokemon: &ExtendedPokemonInfo,
+    selected_version_group: &VersionGroup,
+    focus: &BasicInfoFocus,
+    selected_encounter: Option<usize>,
+) -> (Table<'a>, usize) {
+    let encounter_details = match (focus, selected_encounter) {
+        (BasicInfoFocus::Encounters, Some(selected_encounter)) => current_pokemon
+            .get_renderable_encounter_details(selected_version_group, selected_encounter),
+        _ => vec![],
+    };
+    let encounter_details_count = encounter_details.len();
+    let table = Table::new(encounter_details)
+        .header(
+            Row::new(vec!["\u{A0}Method", "Levels", "Chance", "Conditions"])
+                .style(Style::default().fg(Color::Blue)),
+        )
+        .widths(&[
+            Constraint::Percentage(30),
+            Constraint::Percentage(15),
+            Constraint::Percentage(10),
+            Constraint::Percentage(45),
+        ])
+        .column_spacing(1);
+
+    (table, encounter_details_count)
+}
+
+fn get_renderable_pokemon_abilities_table<'a>(
+    current_pokemon: &'a ExtendedPokemonInfo,
+    focus: &BasicInfoFocus,
+) -> (Table<'a>, usize) {
     let abilities = current_pokemon.get_renderable_abilities();
     let abilities_count = abilities.len();
     let table = Table::new(abilities)
@@ -353,23 +1233,309 @@ fn get_renderable_pokemon_abilities_table(current_pokemon: &ExtendedPokemonInfo)
             Row::new(vec!["\u{A0}Name", "Effect", "Is Hidden"])
                 .style(Style::default().fg(Color::Blue)),
         )
-        .block(Block::default().title(Spans::from(Span::styled(
+        .block(Block::default().title(get_focusable_table_title(
             "\u{A0}Abilities",
-            Style::default().add_modifier(Modifier::BOLD),
-        ))))
+            matches!(focus, BasicInfoFocus::Abilities),
+        )))
         .widths(&[
             Constraint::Percentage(10),
             Constraint::Percentage(75),
             Constraint::Percentage(15),
         ])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
         .column_spacing(1);
 
     (table, abilities_count)
 }
 
+/// The height is estimated from the text width, as the paragraph wraps it to `width`.
+fn get_renderable_pokedex_entry_paragraph<'a>(
+    current_pokemon: &ExtendedPokemonInfo,
+    selected_version_group: &VersionGroup,
+    width: u16,
+) -> (Paragraph<'a>, u16) {
+    let flavor_texts = current_pokemon
+        .species
+        .get_renderable_flavor_texts(selected_version_group);
+    let lines_count: u16 = flavor_texts
+        .iter()
+        .map(|flavor_text| (flavor_text.width() as u16).max(1).div_ceil(width.max(1)))
+        .sum();
+    let height = if lines_count > 0 { lines_count + 2 } else { 0 };
+
+    let paragraph = Paragraph::new(flavor_texts)
+        .block(Block::default().title(Spans::from(Span::styled(
+            "\u{A0}Pokédex Entry",
+            Style::default().add_modifier(Modifier::BOLD),
+        ))))
+        .wrap(Wrap { trim: true });
+
+    (paragraph, height)
+}
+
+fn get_renderable_type_matchups_paragraph<'a>(
+    current_pokemon: &ExtendedPokemonInfo,
+    width: u16,
+) -> (Paragraph<'a>, u16) {
+    let type_matchups = current_pokemon.get_renderable_type_matchups();
+    let lines_count: u16 = type_matchups
+        .iter()
+        .map(|line| (line.width() as u16).max(1).div_ceil(width.max(1)))
+        .sum();
+    let height = if lines_count > 0 { lines_count + 2 } else { 0 };
+
+    let paragraph = Paragraph::new(type_matchups)
+        .block(Block::default().title(Spans::from(Span::styled(
+            "\u{A0}Damage Taken",
+            Style::default().add_modifier(Modifier::BOLD),
+        ))))
+        .wrap(Wrap { trim: true });
+
+    (paragraph, height)
+}
+
+fn get_renderable_damage_relations_paragraph<'a>(
+    current_type: &PokemonTypeExt,
+    width: u16,
+) -> (Paragraph<'a>, u16) {
+    let damage_relations = current_type.get_renderable_damage_relations();
+    let lines_count: u16 = damage_relations
+        .iter()
+        .map(|line| (line.width() as u16).max(1).div_ceil(width.max(1)))
+        .sum();
+
+    let paragraph = Paragraph::new(damage_relations)
+        .block(Block::default().title(Spans::from(vec![
+            Span::styled(
+                "\u{A0}Damage Relations of ",
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            get_styled_pokemon_type(current_type.name.clone().unwrap_or_default()),
+        ])))
+        .wrap(Wrap { trim: true });
+
+    (paragraph, lines_count + 2)
+}
+
+fn get_renderable_type_pokemon_table<'a>(
+    current_type: &PokemonTypeExt,
+    focus: &TypeFocus,
+) -> Table<'a> {
+    Table::new(current_type.get_renderable_pokemon())
+        .header(Row::new(vec!["\u{A0}Pokemon", "Slot"]).style(Style::default().fg(Color::Blue)))
+        .block(Block::default().title(get_focusable_table_title(
+            "\u{A0}Pokemon",
+            matches!(focus, TypeFocus::Pokemon),
+        )))
+        .widths(&[Constraint::Percentage(70), Constraint::Percentage(30)])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1)
+}
+
+fn get_renderable_type_moves_table<'a>(
+    current_type: &PokemonTypeExt,
+    focus: &TypeFocus,
+) -> Table<'a> {
+    Table::new(current_type.get_renderable_moves())
+        .block(Block::default().title(get_focusable_table_title(
+            "\u{A0}Moves",
+            matches!(focus, TypeFocus::Moves),
+        )))
+        .widths(&[Constraint::Percentage(100)])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1)
+}
+
+fn get_renderable_move_details_table<'a>(current_move: &ExtendedMoveInfo) -> (Table<'a>, usize) {
+    let details = current_move.get_renderable_details();
+    let details_count = details.len();
+    let table = Table::new(details)
+        .block(Block::default().title(Spans::from(Span::styled(
+            current_move.get_renderable_name(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ))))
+        .widths(&[Constraint::Percentage(40), Constraint::Percentage(60)])
+        .column_spacing(1);
+
+    (table, details_count)
+}
+
+fn get_renderable_item_details_table<'a>(current_item: &ExtendedItemInfo) -> (Table<'a>, usize) {
+    let details = current_item.item.get_renderable_details();
+    let details_count = details.len();
+    let table = Table::new(details)
+        .block(Block::default().title(Spans::from(Span::styled(
+            current_item.item.get_renderable_name(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ))))
+        .widths(&[Constraint::Percentage(40), Constraint::Percentage(60)])
+        .column_spacing(1);
+
+    (table, details_count)
+}
+
+fn get_renderable_item_holders_table<'a>(current_item: &ExtendedItemInfo) -> Table<'a> {
+    Table::new(current_item.item.get_renderable_holders())
+        .header(Row::new(vec!["\u{A0}Pokemon", "Rarity"]).style(Style::default().fg(Color::Blue)))
+        .block(Block::default().title(get_focusable_table_title("\u{A0}Held By", true)))
+        .widths(&[Constraint::Percentage(30), Constraint::Percentage(70)])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1)
+}
+
+fn get_renderable_location_areas_table<'a>(
+    current_location: &ExtendedLocationInfo,
+    focus: &LocationFocus,
+) -> Table<'a> {
+    Table::new(current_location.get_renderable_areas())
+        .block(Block::default().title(get_focusable_table_title(
+            "\u{A0}Areas",
+            matches!(focus, LocationFocus::Areas),
+        )))
+        .widths(&[Constraint::Percentage(100)])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1)
+}
+
+fn get_renderable_location_encounters_table<'a>(
+    current_location: &ExtendedLocationInfo,
+    focus: &LocationFocus,
+) -> Table<'a> {
+    Table::new(current_location.get_renderable_encounters())
+        .header(
+            Row::new(vec![
+                "\u{A0}Pokemon",
+                "Version",
+                "Method",
+                "Levels",
+                "Chance",
+                "Conditions",
+            ])
+            .style(Style::default().fg(Color::Blue)),
+        )
+        .block(Block::default().title(get_focusable_table_title(
+            "\u{A0}Encounters",
+            matches!(focus, LocationFocus::Encounters),
+        )))
+        .widths(&[
+            Constraint::Length(14),
+            Constraint::Length(12),
+            Constraint::Length(14),
+            Constraint::Length(7),
+            Constraint::Length(6),
+            Constraint::Percentage(100),
+        ])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1)
+}
+
+fn get_renderable_titled_paragraph<'a>(
+    title: impl Into<Cow<'a, str>>,
+    lines: Vec<Spans<'a>>,
+) -> Paragraph<'a> {
+    Paragraph::new(lines)
+        .block(Block::default().title(Spans::from(Span::styled(
+            title,
+            Style::default().add_modifier(Modifier::BOLD),
+        ))))
+        .wrap(Wrap { trim: true })
+}
+
+fn get_renderable_team_members_table(members: Vec<Row>) -> Table {
+    Table::new(members)
+        .header(
+            Row::new(vec![
+                "\u{A0}Pokemon",
+                "Ability / Item",
+                "Nature / Level",
+                "Moves",
+                "EVs / IVs",
+            ])
+            .style(Style::default().fg(Color::DarkGray)),
+        )
+        .block(Block::default().title(get_focusable_table_title("\u{A0}Members", true)))
+        .widths(&[
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(12),
+            Constraint::Percentage(32),
+            Constraint::Percentage(24),
+        ])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1)
+}
+
+fn get_renderable_move_learners_table<'a>(current_move: &ExtendedMoveInfo) -> Table<'a> {
+    Table::new(current_move.get_renderable_learners())
+        .block(Block::default().title(get_focusable_table_title("\u{A0}Learned By", true)))
+        .widths(&[Constraint::Percentage(100)])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1)
+}
+
+fn get_renderable_ability_pokemon_table<'a>(current_ability: &PokemonAbilityExt) -> Table<'a> {
+    Table::new(current_ability.get_renderable_pokemon())
+        .header(Row::new(vec!["\u{A0}Pokemon", "Slot"]).style(Style::default().fg(Color::Blue)))
+        .block(Block::default().title(get_focusable_table_title("\u{A0}Pokemon", true)))
+        .widths(&[Constraint::Percentage(70), Constraint::Percentage(30)])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1)
+}
+
+fn get_renderable_evolution_chain_table<'a>(
+    current_pokemon: &'a ExtendedPokemonInfo,
+    focus: &BasicInfoFocus,
+) -> (Table<'a>, usize) {
+    let evolution_stages = current_pokemon.get_renderable_evolution_stages();
+    let evolution_stages_count = evolution_stages.len();
+    let table = Table::new(evolution_stages)
+        .header(
+            Row::new(vec!["\u{A0}Species", "Condition"]).style(Style::default().fg(Color::Blue)),
+        )
+        .block(Block::default().title(get_focusable_table_title(
+            "\u{A0}Evolution Chain",
+            matches!(focus, BasicInfoFocus::EvolutionChain),
+        )))
+        .widths(&[Constraint::Percentage(30), Constraint::Percentage(70)])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1);
+
+    (table, evolution_stages_count)
+}
+
+/// Empty when the species has a single variety and the Pokemon a single form.
+fn get_renderable_pokemon_forms_table<'a>(
+    current_pokemon: &ExtendedPokemonInfo,
+    focus: &BasicInfoFocus,
+) -> (Table<'a>, usize) {
+    let forms = current_pokemon.get_renderable_form_choices();
+    let forms_count = forms.len();
+    let table = Table::new(forms)
+        .block(Block::default().title(get_focusable_table_title(
+            "\u{A0}Forms",
+            matches!(focus, BasicInfoFocus::Forms),
+        )))
+        .widths(&[Constraint::Percentage(70), Constraint::Percentage(30)])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .column_spacing(1);
+
+    (table, forms_count)
+}
+
+/// Tab moves the focus between tables, the focused one gets a highlighted title.
+fn get_focusable_table_title(title: &str, is_focused: bool) -> Spans<'_> {
+    let mut style = Style::default().add_modifier(Modifier::BOLD);
+    if is_focused {
+        style = style.fg(Color::Blue);
+    }
+
+    Spans::from(Span::styled(title, style))
+}
+
 fn get_renderable_pokemon_moves_table<'a>(
     current_pokemon: &'a ExtendedPokemonInfo,
     selected_version_group: &str,
+    focus: &BasicInfoFocus,
 ) -> (Table<'a>, usize) {
     let moves = current_pokemon.get_renderable_moves(selected_version_group);
     let moves_len = &moves.len();
@@ -388,10 +1554,10 @@ fn get_renderable_pokemon_moves_table<'a>(
             ])
             .style(Style::default().fg(Color::Blue)),
         )
-        .block(Block::default().title(Spans::from(Span::styled(
+        .block(Block::default().title(get_focusable_table_title(
             "\u{A0}Moves",
-            Style::default().add_modifier(Modifier::BOLD),
-        ))))
+            matches!(focus, BasicInfoFocus::Moves),
+        )))
         .widths(&[
             Constraint::Length(16),
             Constraint::Length(5),
@@ -426,15 +1592,38 @@ fn get_main_block_text<'a>(frame: &CrosstermFrame, app: &App) -> Vec<Spans<'a>>
         Spans::from("Hello! Welcome to Pokerust."),
         Spans::from("\n"),
         Spans::from("Use \"Up\" and \"Down\" to navigate through Pokemon/Moves list."),
+        Spans::from(
+            "Press \"Tab\" on the list to switch between Pokemon, Types, Items, Locations and Teams.",
+        ),
         Spans::from("Press \"Enter\" to see information about needed Pokemon."),
         Spans::from("Use \"Left\" and \"Right\" switch between Pokemon list and Pokemon Info."),
+        Spans::from(
+            "Press \"Tab\" to switch between Moves, Evolution Chain, Abilities, Held items, Encounters and Forms.",
+        ),
+        Spans::from(
+            "Press \"Enter\" on a stage, form, move, ability, item or location to open it, \"Esc\" goes back.",
+        ),
+        Spans::from("Locations are listed by region, press \"Enter\" on a region to see its locations."),
+        Spans::from("Press \"s\" to cycle through front, back, shiny and female sprites."),
+        Spans::from("Press \"c\" to work out the stats for a level, nature, IVs and EVs."),
+        Spans::from("Press \"m\" to mark up to 4 Pokemon and \"v\" to compare them side by side."),
+        Spans::from(
+            "Press \"d\" on a move to work out its damage, \"+\"/\"-\" pick the Pokemon, move and spreads.",
+        ),
+        Spans::from(
+            "Press \"t\" to put a Pokemon on a team and \"a\" to give it a move, ability or item.",
+        ),
         Spans::from("Press \"Esc\" to leave information screen."),
         Spans::from("Press \"q\" to leave Pokedex."),
     ];
-    let loading_text = vec![Spans::from(Span::styled(
-        "Loading...",
-        Style::default().add_modifier(Modifier::BOLD),
-    ))];
+    let loading_text = vec![
+        Spans::from(Span::styled(
+            "Loading...",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Spans::from(""),
+        Spans::from("Press \"Esc\" to cancel."),
+    ];
 
     let current_text = if app.loading {
         loading_text
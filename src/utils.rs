@@ -12,6 +12,38 @@ pub fn uppercase_first_letter(s: &str) -> String {
     }
 }
 
+/// PokéAPI names generations with roman numerals, like `generation-iv`.
+pub fn parse_generation(name: &str) -> Option<i32> {
+    let digits = name
+        .strip_prefix("generation-")?
+        .chars()
+        .map(|digit| match digit {
+            'i' => Some(1),
+            'v' => Some(5),
+            'x' => Some(10),
+            _ => None,
+        })
+        .collect::<Option<Vec<i32>>>()?;
+
+    Some(digits.iter().enumerate().fold(
+        0,
+        |generation, (index, digit)| match digits.get(index + 1) {
+            Some(next_digit) if next_digit > digit => generation - digit,
+            _ => generation + digit,
+        },
+    ))
+}
+
+/// `generation-iv` as "Generation IV", keeping the numeral in capitals.
+pub fn get_renderable_generation(name: &str) -> String {
+    match name.strip_prefix("generation-") {
+        Some(numeral) if parse_generation(name).is_some() => {
+            format!("Generation {}", numeral.to_uppercase())
+        }
+        _ => name.to_string().split_capitalize(),
+    }
+}
+
 pub fn get_styled_pokemon_type(name: String) -> Span<'static> {
     match name.as_str() {
         "normal" => Span::styled(